@@ -522,8 +522,9 @@ def padronizar_numeros_por_extenso(valor: str) -> str:
     """
     Converte sequências de dígitos em uma string para seus equivalentes por extenso em português.
 
-    A função percorre a string de entrada e, ao encontrar números inteiros (em formato ASCII),
-    os substitui pelo nome completo do número (ex: "2" → "dois"), utilizando a função `numero_por_extenso`.
+    A função percorre a string de entrada e, ao encontrar números (em formato ASCII), os
+    substitui pelo nome completo do número (ex: "2" → "DOIS"), incluindo o sinal de menos,
+    os separadores de milhar e as casas decimais.
 
     Parameters
     ----------
@@ -537,8 +538,12 @@ def padronizar_numeros_por_extenso(valor: str) -> str:
 
     Notes
     -----
-    - Números muito grandes ou inválidos (ex: overflow no parse para `i32`) são deixados inalterados.
-    - Não trata números negativos ou decimais.
+    - Os pontos seguidos de exatamente três dígitos são separadores de milhar ("1.500"). Os demais
+      pontos e as vírgulas entre dígitos são separadores decimais ("2,5" e "2.5").
+    - Valores mal formados, com mais de três dígitos após o ponto ("1.5000"), são deixados inalterados.
+    - O sinal de menos só é considerado no início do texto ou após um espaço, já que hífens como
+      em "5-A" são comuns em endereços.
+    - Números muito grandes (acima de 64 bits) são deixados inalterados.
     - Se a string de entrada não contém nenhum dígito ASCII, retorna a string original.
 
     Examples
@@ -546,9 +551,11 @@ def padronizar_numeros_por_extenso(valor: str) -> str:
     >>> enderecobr.padronizar_numeros_por_extenso("RUA 2")
     'RUA DOIS'
     >>> enderecobr.padronizar_numeros_por_extenso("RUA -2")
-    'RUA -DOIS'
+    'RUA MENOS DOIS'
     >>> enderecobr.padronizar_numeros_por_extenso("RUA -2.2")
-    'RUA -DOIS.DOIS'
+    'RUA MENOS DOIS VIRGULA DOIS'
+    >>> enderecobr.padronizar_numeros_por_extenso("RUA 1.500")
+    'RUA MIL E QUINHENTOS'
     >>> enderecobr.padronizar_numeros_por_extenso("Sem números")
     'Sem números'
 
@@ -0,0 +1,676 @@
+use std::sync::{LazyLock, OnceLock};
+
+use itertools::Itertools;
+use regex::Regex;
+
+use crate::{
+    bairro::{criar_padronizador_bairros_com, padronizar_bairros_usando},
+    datas::OpcoesData,
+    logradouro::{criar_padronizador_logradouros_com, padronizar_logradouros_usando},
+    nome_proprio::criar_padronizador_nome_proprio_com,
+    normalizar,
+    tipo_logradouro::criar_padronizador_tipo_logradouro_com,
+    Padronizador,
+};
+
+/// Posição do texto em que uma abreviação pode ser expandida.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Contexto {
+    /// Somente na posição do tipo, no início do texto, como "LOT" em "LOT. SAO JOSE". A abreviação
+    /// também é expandida logo após outra palavra do dicionário, como em "PQ. RES. SAO JOSE".
+    Inicio,
+    /// Somente depois da primeira palavra do texto.
+    Meio,
+    /// Em qualquer posição do texto.
+    Qualquer,
+}
+
+/// Uma entrada do dicionário de abreviações.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Abreviacao {
+    /// Expressão regular que reconhece as variações da abreviação, sem o ponto final.
+    pub padrao: String,
+    /// Forma expandida, em caixa alta e sem acentos.
+    pub expansao: String,
+    pub contexto: Contexto,
+}
+
+// Abreviações compartilhadas pelos padronizadores de logradouros, bairros e tipos de logradouro.
+// Antes, cada um tinha a sua própria versão destas regras, com pequenas diferenças entre si.
+// As abreviações de início são as que, no meio de um logradouro, costumam ter outro significado
+// ("QD 5 LOT 3", em que LOT é lote) ou fazer parte do nome ("RUA RES DAS FLORES").
+const ABREVIACOES: [(&str, &str, Contexto); 11] = [
+    (r"J(D(I?M)?|A?RD|AR(DIN)?)", "JARDIM", Contexto::Qualquer),
+    (r"C(ON?)?J", "CONJUNTO", Contexto::Qualquer),
+    (r"DISTR?", "DISTRITO", Contexto::Qualquer),
+    (r"COND", "CONDOMINIO", Contexto::Qualquer),
+    (r"FAZ(EN?)?", "FAZENDA", Contexto::Qualquer),
+    (r"P((A?R)?Q|QU?E)", "PARQUE", Contexto::Inicio),
+    (r"RES(I?D)?", "RESIDENCIAL", Contexto::Inicio),
+    (r"DIS", "DISTRITO", Contexto::Inicio), // DIS no meio do texto pode ser outra coisa
+    (r"LOT(EAME?)?", "LOTEAMENTO", Contexto::Inicio),
+    (r"NUCL?", "NUCLEO", Contexto::Inicio),
+    (r"CHA?C", "CHACARA", Contexto::Inicio), // CHA pode ser CHAPADAO
+];
+
+/// Lista as abreviações embutidas na biblioteca.
+pub(crate) fn abreviacoes_padrao() -> Vec<Abreviacao> {
+    ABREVIACOES
+        .iter()
+        .map(|(padrao, expansao, contexto)| Abreviacao {
+            padrao: padrao.to_string(),
+            expansao: expansao.to_string(),
+            contexto: *contexto,
+        })
+        .collect()
+}
+
+// O "$" tem significado especial na substituição.
+fn substituicao(abreviacao: &Abreviacao) -> String {
+    abreviacao.expansao.replace('$', "$$")
+}
+
+/// Adiciona ao padronizador as regras de expansão das abreviações, na ordem em que aparecem.
+pub(crate) fn adicionar_abreviacoes(padronizador: &mut Padronizador, abreviacoes: &[Abreviacao]) {
+    // Palavras que podem anteceder uma abreviação de início, como "PARQUE" em "PARQUE RES.".
+    let anteriores = abreviacoes
+        .iter()
+        .map(|a| regex::escape(&a.expansao))
+        .unique()
+        .join("|");
+
+    for abreviacao in abreviacoes {
+        let padrao = &abreviacao.padrao;
+        let expansao = substituicao(abreviacao);
+        match abreviacao.contexto {
+            Contexto::Inicio => padronizador.adicionar(
+                &format!(r"^((?:{anteriores}) )?(?:{padrao})\b\.?"),
+                &format!("${{1}}{expansao}"),
+            ),
+            Contexto::Meio => {
+                padronizador.adicionar(&format!(r" (?:{padrao})\b\.?"), &format!(" {expansao}"))
+            }
+            Contexto::Qualquer => {
+                padronizador.adicionar(&format!(r"\b(?:{padrao})\b\.?"), &expansao)
+            }
+        };
+    }
+}
+
+/// Adiciona ao padronizador de logradouros as regras das abreviações que podem ser o tipo do
+/// logradouro: a expansão no início do texto, a remoção do tipo repetido ("PARQUE PQ") e a
+/// separação do tipo por espaço ("PARQUE-X").
+pub(crate) fn adicionar_tipos_abreviados(
+    padronizador: &mut Padronizador,
+    abreviacoes: &[Abreviacao],
+) {
+    for abreviacao in abreviacoes.iter().filter(|a| a.contexto != Contexto::Meio) {
+        let padrao = &abreviacao.padrao;
+        let expansao = substituicao(abreviacao);
+        let tipo = regex::escape(&abreviacao.expansao);
+        // "RUA PARQUE-1" é um parque, mas "RUA JARDIM-1" pode ser mesmo uma rua.
+        let anteriores = match abreviacao.contexto {
+            Contexto::Inicio => "RUA|RODOVIA",
+            _ => "RODOVIA",
+        };
+        padronizador
+            .adicionar(&format!(r"^(?:{padrao})\b(\.|,)?"), &expansao)
+            .adicionar(
+                &format!(r"^(?:{tipo}|RODOVIA) (?:{tipo}|{padrao})\b(\.|,)?"),
+                &expansao,
+            )
+            .adicionar(&format!(r"^{tipo}\b(-|,|\.) *"), &format!("{expansao} "))
+            .adicionar(
+                &format!(r"^(?:{tipo}|{anteriores}) (?:{tipo}|{padrao})\b- *"),
+                &format!("{expansao} "),
+            );
+    }
+}
+
+// Padronizadores gerados com as abreviações de um dicionário.
+struct Padronizadores {
+    logradouros: Padronizador,
+    bairros: Padronizador,
+    tipos_logradouro: Padronizador,
+    nomes_proprios: Padronizador,
+}
+
+/// Dicionário de abreviações com entradas adicionadas pelo usuário, aplicadas depois das
+/// abreviações embutidas nos padronizadores de logradouros, bairros, tipos de logradouro e nomes
+/// próprios.
+///
+/// As entradas do usuário geram as mesmas regras que as embutidas, em cada um dos
+/// padronizadores. Cada dicionário é independente: as entradas adicionadas só valem para os
+/// métodos de padronização do próprio dicionário, e as funções de padronização do crate
+/// continuam usando apenas as abreviações embutidas.
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::abreviacao::{Contexto, DicionarioAbreviacoes};
+/// use enderecobr_rs::padronizar_bairros;
+///
+/// let mut dicionario = DicionarioAbreviacoes::default();
+/// dicionario
+///     .adicionar("CJTO", "conjunto", Contexto::Qualquer)
+///     .adicionar("BALN", "balneario", Contexto::Inicio);
+///
+/// assert_eq!(dicionario.padronizar_bairros("CJTO. BELO"), "CONJUNTO BELO");
+/// assert_eq!(dicionario.padronizar_logradouros("BALN. AZUL"), "BALNEARIO AZUL");
+/// assert_eq!(dicionario.padronizar_logradouros("RUA BALN AZUL"), "RUA BALN AZUL");
+/// assert_eq!(dicionario.padronizar_tipo_logradouro("CJTO"), "CONJUNTO");
+/// assert_eq!(padronizar_bairros("CJTO. BELO"), "CJTO. BELO");
+/// ```
+///
+#[derive(Default)]
+pub struct DicionarioAbreviacoes {
+    usuario: Vec<Abreviacao>,
+    // Gerados na primeira padronização após a última entrada adicionada.
+    padronizadores: OnceLock<Padronizadores>,
+}
+
+impl DicionarioAbreviacoes {
+    /// Adiciona uma abreviação ao dicionário.
+    ///
+    /// A abreviação é interpretada literalmente (não como expressão regular), e tanto ela quanto a
+    /// expansão são normalizadas para caixa alta e sem acentos. O ponto final da abreviação é
+    /// opcional, como nas demais regras. Abreviações vazias são ignoradas.
+    ///
+    /// Retorna uma referência mutável para encadeamento (padrão builder).
+    pub fn adicionar(&mut self, abreviacao: &str, expansao: &str, contexto: Contexto) -> &mut Self {
+        let abreviacao = normalizar(abreviacao);
+        // O ponto final é opcional nas regras geradas.
+        let abreviacao = abreviacao.trim_end_matches('.');
+        if abreviacao.is_empty() {
+            return self;
+        }
+
+        self.usuario.push(Abreviacao {
+            padrao: regex::escape(abreviacao),
+            expansao: normalizar(expansao).into_owned(),
+            contexto,
+        });
+        self.padronizadores = OnceLock::new();
+        self
+    }
+
+    /// Lista todas as abreviações do dicionário: as embutidas na biblioteca, seguidas das
+    /// adicionadas com [DicionarioAbreviacoes::adicionar].
+    pub fn abreviacoes(&self) -> Vec<Abreviacao> {
+        let mut abreviacoes = abreviacoes_padrao();
+        abreviacoes.extend(self.usuario.iter().cloned());
+        abreviacoes
+    }
+
+    /// Padroniza um logradouro como [crate::padronizar_logradouros], usando também as
+    /// abreviações adicionadas ao dicionário.
+    pub fn padronizar_logradouros(&self, valor: &str) -> String {
+        match self.padronizadores() {
+            Some(p) => padronizar_logradouros_usando(&p.logradouros, valor, &OpcoesData::default()),
+            None => crate::padronizar_logradouros(valor),
+        }
+    }
+
+    /// Padroniza um bairro como [crate::padronizar_bairros], usando também as abreviações
+    /// adicionadas ao dicionário.
+    pub fn padronizar_bairros(&self, valor: &str) -> String {
+        match self.padronizadores() {
+            Some(p) => padronizar_bairros_usando(&p.bairros, valor, &OpcoesData::default()),
+            None => crate::padronizar_bairros(valor),
+        }
+    }
+
+    /// Padroniza um tipo de logradouro como [crate::padronizar_tipo_logradouro], usando também
+    /// as abreviações adicionadas ao dicionário.
+    pub fn padronizar_tipo_logradouro(&self, valor: &str) -> String {
+        match self.padronizadores() {
+            Some(p) => p.tipos_logradouro.padronizar(valor),
+            None => crate::padronizar_tipo_logradouro(valor),
+        }
+    }
+
+    /// Padroniza um nome próprio como [crate::nome_proprio::padronizar_nome_proprio], usando
+    /// também as abreviações adicionadas ao dicionário.
+    pub fn padronizar_nome_proprio(&self, valor: &str) -> String {
+        match self.padronizadores() {
+            Some(p) => p.nomes_proprios.padronizar(valor),
+            None => crate::nome_proprio::padronizar_nome_proprio(valor),
+        }
+    }
+
+    // Sem entradas do usuário, os padronizadores do crate são usados diretamente.
+    fn padronizadores(&self) -> Option<&Padronizadores> {
+        if self.usuario.is_empty() {
+            return None;
+        }
+        Some(self.padronizadores.get_or_init(|| {
+            let abreviacoes = self.abreviacoes();
+            Padronizadores {
+                logradouros: criar_padronizador_logradouros_com(&abreviacoes),
+                bairros: criar_padronizador_bairros_com(&abreviacoes),
+                tipos_logradouro: criar_padronizador_tipo_logradouro_com(&abreviacoes),
+                nomes_proprios: criar_padronizador_nome_proprio_com(&abreviacoes),
+            }
+        }))
+    }
+}
+
+// ============ Abreviação de endereços padronizados ============
+
+// Tabelas de abreviação no sentido inverso (forma expandida → abreviação), no estilo usado pelos
+// Correios. A ordem das entradas define a prioridade no modo com tamanho máximo: as primeiras são
+// abreviadas antes.
+
+const ABREVIAR_TIPOS: [(&str, &str); 14] = [
+    ("RUA", "R."),
+    ("AVENIDA", "AV."),
+    ("TRAVESSA", "TV."),
+    ("PRACA", "PC."),
+    ("ALAMEDA", "AL."),
+    ("ESTRADA", "EST."),
+    ("RODOVIA", "ROD."),
+    ("LARGO", "LGO."),
+    ("LADEIRA", "LD."),
+    ("VIADUTO", "VD."),
+    ("LOTEAMENTO", "LOT."),
+    ("CONDOMINIO", "COND."),
+    ("PARQUE", "PQ."),
+    ("VILA", "VL."),
+];
+
+const ABREVIAR_TITULOS: [(&str, &str); 33] = [
+    ("NOSSA SENHORA", "N. SRA."),
+    ("NOSSO SENHOR", "N. SR."),
+    ("TENENTE-CORONEL", "TEN.-CEL."),
+    ("PRIMEIRO-TENENTE", "PRIM.-TEN."),
+    ("SEGUNDO-TENENTE", "SEG.-TEN."),
+    ("PRESIDENTE", "PRES."),
+    ("GOVERNADOR", "GOV."),
+    ("GENERAL", "GEN."),
+    ("MARECHAL", "MAL."),
+    ("ALMIRANTE", "ALM."),
+    ("BRIGADEIRO", "BRIG."),
+    ("CORONEL", "CEL."),
+    ("TENENTE", "TEN."),
+    ("CAPITAO", "CAP."),
+    ("MAJOR", "MAJ."),
+    ("SARGENTO", "SGT."),
+    ("SOLDADO", "SD."),
+    ("DOUTORA", "DRA."),
+    ("DOUTOR", "DR."),
+    ("PROFESSORA", "PROFA."),
+    ("PROFESSOR", "PROF."),
+    ("ENGENHEIRO", "ENG."),
+    ("DESEMBARGADOR", "DES."),
+    ("COMENDADOR", "COM."),
+    ("SENADOR", "SEN."),
+    ("DEPUTADO", "DEP."),
+    ("VEREADOR", "VER."),
+    ("PREFEITO", "PREF."),
+    ("MINISTRO", "MIN."),
+    ("MONSENHOR", "MONS."),
+    ("VISCONDE", "VISC."),
+    ("SANTA", "STA."),
+    ("SANTO", "STO."),
+];
+
+const ABREVIAR_LOCALIDADES: [(&str, &str); 12] = [
+    ("JARDIM", "JD."),
+    ("PARQUE", "PQ."),
+    ("VILA", "VL."),
+    ("CONJUNTO", "CJ."),
+    ("RESIDENCIAL", "RES."),
+    ("CONDOMINIO", "COND."),
+    ("LOTEAMENTO", "LOT."),
+    ("DISTRITO", "DIST."),
+    ("NUCLEO", "NUC."),
+    ("CHACARA", "CHAC."),
+    ("FAZENDA", "FAZ."),
+    ("HABITACIONAL", "HAB."),
+];
+
+const ABREVIAR_COMPLEMENTOS: [(&str, &str); 15] = [
+    ("APARTAMENTO", "AP."),
+    ("BLOCO", "BL."),
+    ("QUADRA", "QD."),
+    ("LOTE", "LT."),
+    ("CASA", "CS."),
+    ("CONJUNTO", "CJ."),
+    ("CONDOMINIO", "COND."),
+    ("EDIFICIO", "ED."),
+    ("COBERTURA", "COB."),
+    ("ANDAR", "AND."),
+    ("FUNDOS", "FDS."),
+    ("SALA", "SL."),
+    ("LOJA", "LJ."),
+    ("TORRE", "TR."),
+    ("CAIXA POSTAL", "CX. POSTAL"),
+];
+
+// Em Rust, a constant é criada durante a compilação, então só posso chamar funções muito restritas
+// quando uso `const`.
+static ABREVIADOR_LOGRADOUROS: LazyLock<Vec<(Regex, &str)>> = LazyLock::new(|| {
+    criar_abreviador(&[
+        (&ABREVIAR_TIPOS, Contexto::Inicio),
+        (&ABREVIAR_TITULOS, Contexto::Qualquer),
+        (&ABREVIAR_LOCALIDADES, Contexto::Qualquer),
+    ])
+});
+static ABREVIADOR_BAIRROS: LazyLock<Vec<(Regex, &str)>> = LazyLock::new(|| {
+    criar_abreviador(&[
+        (&ABREVIAR_LOCALIDADES, Contexto::Qualquer),
+        (&ABREVIAR_TITULOS, Contexto::Qualquer),
+    ])
+});
+static ABREVIADOR_COMPLEMENTOS: LazyLock<Vec<(Regex, &str)>> =
+    LazyLock::new(|| criar_abreviador(&[(&ABREVIAR_COMPLEMENTOS, Contexto::Qualquer)]));
+
+#[allow(clippy::expect_used)]
+fn criar_abreviador(tabelas: &[(&[(&str, &'static str)], Contexto)]) -> Vec<(Regex, &'static str)> {
+    tabelas
+        .iter()
+        .flat_map(|(tabela, contexto)| {
+            tabela.iter().map(move |(expansao, abreviacao)| {
+                let regex = match contexto {
+                    Contexto::Inicio => format!(r"^({})\b", expansao),
+                    Contexto::Meio => format!(r" ({})\b", expansao),
+                    Contexto::Qualquer => format!(r"\b({})\b", expansao),
+                };
+                let regex = Regex::new(&regex).expect("Regex de abreviação inválida (bug interno)");
+                (regex, *abreviacao)
+            })
+        })
+        .collect()
+}
+
+fn abreviar(abreviador: &[(Regex, &str)], valor: &str, tamanho_maximo: Option<usize>) -> String {
+    let mut texto = normalizar(valor).into_owned();
+    let cabe = |texto: &str| tamanho_maximo.is_some_and(|max| texto.len() <= max);
+
+    for (regex, abreviacao) in abreviador {
+        // Uma ocorrência por vez, para parar assim que o texto couber no tamanho máximo.
+        while !cabe(&texto) {
+            match regex.captures(&texto).and_then(|c| c.get(1)) {
+                Some(m) => texto.replace_range(m.range(), abreviacao),
+                None => break,
+            }
+        }
+    }
+
+    texto
+}
+
+/// Abrevia um logradouro já padronizado, como em "R. GEN. GLICERIO".
+///
+/// Com `tamanho_maximo`, as abreviações são aplicadas progressivamente (tipo do logradouro,
+/// títulos e, por fim, nomes de localidades) até que o texto caiba no tamanho informado. Se
+/// nem o texto totalmente abreviado couber, ele é retornado assim mesmo, sem truncamento.
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::abreviar_logradouro;
+/// assert_eq!(abreviar_logradouro("RUA GENERAL GLICERIO", None), "R. GEN. GLICERIO");
+/// assert_eq!(abreviar_logradouro("AVENIDA PRESIDENTE VARGAS", Some(22)), "AV. PRESIDENTE VARGAS");
+/// assert_eq!(abreviar_logradouro("AVENIDA PRESIDENTE VARGAS", Some(30)), "AVENIDA PRESIDENTE VARGAS");
+/// assert_eq!(abreviar_logradouro("AVENIDA PRESIDENTE VARGAS", Some(5)), "AV. PRES. VARGAS");
+/// ```
+///
+/// # Detalhes
+/// A função espera o resultado de [crate::padronizar_logradouros] e usa uma tabela fixa de
+/// abreviações, no estilo dos Correios. Os tipos de logradouro só são abreviados no início do
+/// texto.
+///
+pub fn abreviar_logradouro(valor: &str, tamanho_maximo: Option<usize>) -> String {
+    abreviar(&ABREVIADOR_LOGRADOUROS, valor, tamanho_maximo)
+}
+
+/// Abrevia um complemento já padronizado, como em "AP. 302 BL. B".
+///
+/// O parâmetro `tamanho_maximo` funciona como em [abreviar_logradouro].
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::abreviar_complemento;
+/// assert_eq!(abreviar_complemento("APARTAMENTO 302 BLOCO B", None), "AP. 302 BL. B");
+/// assert_eq!(abreviar_complemento("APARTAMENTO 302 BLOCO B", Some(18)), "AP. 302 BLOCO B");
+/// ```
+///
+pub fn abreviar_complemento(valor: &str, tamanho_maximo: Option<usize>) -> String {
+    abreviar(&ABREVIADOR_COMPLEMENTOS, valor, tamanho_maximo)
+}
+
+/// Abrevia um bairro já padronizado, como em "JD. STA. MARIA".
+///
+/// O parâmetro `tamanho_maximo` funciona como em [abreviar_logradouro].
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::abreviar_bairro;
+/// assert_eq!(abreviar_bairro("JARDIM SANTA MARIA", None), "JD. STA. MARIA");
+/// assert_eq!(abreviar_bairro("CONJUNTO HABITACIONAL SAO JOSE", Some(25)), "CJ. HABITACIONAL SAO JOSE");
+/// ```
+///
+pub fn abreviar_bairro(valor: &str, tamanho_maximo: Option<usize>) -> String {
+    abreviar(&ABREVIADOR_BAIRROS, valor, tamanho_maximo)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{padronizar_bairros, padronizar_logradouros, padronizar_tipo_logradouro};
+
+    fn padronizar(abreviacoes: &[Abreviacao], valor: &str) -> String {
+        let mut padronizador = Padronizador::default();
+        adicionar_abreviacoes(&mut padronizador, abreviacoes);
+        padronizador.preparar();
+        padronizador.padronizar(valor)
+    }
+
+    #[test]
+    fn respeita_contexto() {
+        let abreviacao = |contexto| Abreviacao {
+            padrao: "VL".to_string(),
+            expansao: "VILA".to_string(),
+            contexto,
+        };
+
+        let inicio = [abreviacao(Contexto::Inicio)];
+        assert_eq!(padronizar(&inicio, "VL X VL"), "VILA X VL");
+        assert_eq!(padronizar(&inicio, "VILA VL. X"), "VILA VILA X");
+
+        let meio = [abreviacao(Contexto::Meio)];
+        assert_eq!(padronizar(&meio, "VL X VL. Y VL"), "VL X VILA Y VILA");
+
+        let qualquer = [abreviacao(Contexto::Qualquer)];
+        assert_eq!(padronizar(&qualquer, "VL X VL. Y VL"), "VILA X VILA Y VILA");
+    }
+
+    #[test]
+    fn abreviacoes_compartilhadas() {
+        // As mesmas abreviações valem para os três padronizadores.
+        for (entrada, esperado) in [("JD", "JARDIM"), ("CONJ", "CONJUNTO"), ("FAZ", "FAZENDA")] {
+            assert_eq!(padronizar_tipo_logradouro(entrada), esperado);
+            assert_eq!(
+                padronizar_bairros(&format!("{} AZUL", entrada)),
+                format!("{} AZUL", esperado)
+            );
+            assert_eq!(
+                padronizar_logradouros(&format!("RUA {} AZUL", entrada)),
+                format!("RUA {} AZUL", esperado)
+            );
+        }
+    }
+
+    #[test]
+    fn unifica_regras_dos_padronizadores() {
+        // Casos em que as regras compartilhadas mudaram o resultado de algum padronizador em
+        // relação às regras que cada um tinha antes.
+        let logradouros = [
+            ("RUA COJ X", "RUA CONJUNTO X"),
+            ("JD. X", "JARDIM X"),
+            ("CONJUNTO- X", "CONJUNTO X"),
+            ("PQ RES. X", "PARQUE RESIDENCIAL X"),
+        ];
+        for (entrada, esperado) in logradouros {
+            assert_eq!(padronizar_logradouros(entrada), esperado);
+        }
+
+        // Antes, LOT só era expandido em bairros quando seguido de outro caractere.
+        let bairros = [
+            ("LOT", "LOTEAMENTO"),
+            ("JARDIM LOT", "JARDIM LOTEAMENTO"),
+            // PQ, RES, LOT, NUC e CHAC agora só são expandidos no início do nome.
+            ("VILA RES. X", "VILA RES. X"),
+        ];
+        for (entrada, esperado) in bairros {
+            assert_eq!(padronizar_bairros(entrada), esperado);
+        }
+
+        let tipos = [
+            ("LOTEAME", "LOTEAMENTO"),
+            ("NUCL", "NUCLEO"),
+            ("CHC", "CHACARA"),
+            ("RESD", "RESIDENCIAL"),
+            ("COJ", "CONJUNTO"),
+            // DIS só é expandido no início do texto, como já acontecia em bairros.
+            ("X DIS", "X DIS"),
+        ];
+        for (entrada, esperado) in tipos {
+            assert_eq!(padronizar_tipo_logradouro(entrada), esperado);
+        }
+    }
+
+    #[test]
+    fn abreviacoes_de_inicio_nao_mudam_o_meio_do_logradouro() {
+        let casos = [
+            ("RUA 10 QD 5 LOT 3", "RUA 10 QUADRA 5 LOT 3"),
+            ("RUA RES DAS FLORES", "RUA RES DAS FLORES"),
+            ("RUA X NUC. Y", "RUA X NUC. Y"),
+            (
+                "LOT. SAO JOSE QD 5 LOT 3",
+                "LOTEAMENTO SAO JOSE QUADRA 5 LOT 3",
+            ),
+            ("PQ. DAS FLORES", "PARQUE DAS FLORES"),
+            ("PARQUE PQ DAS FLORES", "PARQUE DAS FLORES"),
+            ("RUA LOT. X", "LOTEAMENTO X"),
+        ];
+        for (entrada, esperado) in casos {
+            assert_eq!(padronizar_logradouros(entrada), esperado);
+        }
+    }
+
+    #[test]
+    fn adiciona_abreviacao_de_usuario() {
+        let mut dicionario = DicionarioAbreviacoes::default();
+        dicionario
+            .adicionar("XPTQ.", "exemplo", Contexto::Qualquer)
+            .adicionar("", "vazio", Contexto::Qualquer);
+
+        assert_eq!(dicionario.padronizar_bairros("XPTQ. AZUL"), "EXEMPLO AZUL");
+        assert_eq!(
+            dicionario.padronizar_logradouros("RUA XPTQ. AZUL"),
+            "RUA EXEMPLO AZUL"
+        );
+        assert_eq!(dicionario.padronizar_tipo_logradouro("XPTQ."), "EXEMPLO");
+        // As entradas do usuário também geram as regras de tipo do logradouro.
+        assert_eq!(
+            dicionario.padronizar_logradouros("XPTQ-AZUL"),
+            "EXEMPLO AZUL"
+        );
+        assert_eq!(
+            dicionario.padronizar_nome_proprio("XPTQ AZUL"),
+            "EXEMPLO AZUL"
+        );
+        assert!(dicionario
+            .abreviacoes()
+            .iter()
+            .any(|a| a.expansao == "EXEMPLO"));
+        assert!(!dicionario
+            .abreviacoes()
+            .iter()
+            .any(|a| a.expansao == "VAZIO"));
+
+        // As funções do crate e os outros dicionários não são afetados.
+        assert_eq!(padronizar_bairros("XPTQ. AZUL"), "XPTQ. AZUL");
+        let outro = DicionarioAbreviacoes::default();
+        assert_eq!(outro.padronizar_bairros("XPTQ AZUL"), "XPTQ AZUL");
+        assert_eq!(outro.abreviacoes().len(), ABREVIACOES.len());
+    }
+
+    #[test]
+    fn abreviacao_de_usuario_no_inicio() {
+        let mut dicionario = DicionarioAbreviacoes::default();
+        dicionario.adicionar("BALN", "balneário", Contexto::Inicio);
+
+        assert_eq!(
+            dicionario.padronizar_logradouros("BALN. AZUL"),
+            "BALNEARIO AZUL"
+        );
+        assert_eq!(
+            dicionario.padronizar_logradouros("RUA BALN AZUL"),
+            "RUA BALN AZUL"
+        );
+        assert_eq!(dicionario.padronizar_bairros("BALN AZUL"), "BALNEARIO AZUL");
+
+        // Uma nova entrada refaz os padronizadores.
+        dicionario.adicionar("AZ", "azul", Contexto::Meio);
+        assert_eq!(dicionario.padronizar_bairros("BALN AZ"), "BALNEARIO AZUL");
+    }
+
+    #[test]
+    fn abrevia_logradouros() {
+        let casos = [
+            ("RUA GENERAL GLICERIO", "R. GEN. GLICERIO"),
+            (
+                "AVENIDA NOSSA SENHORA DE COPACABANA",
+                "AV. N. SRA. DE COPACABANA",
+            ),
+            ("TRAVESSA DOUTOR JOAO", "TV. DR. JOAO"),
+            ("RUA DA PRACA", "R. DA PRACA"),
+            ("RUARIA", "RUARIA"),
+            ("", ""),
+        ];
+
+        for (entrada, esperado) in casos {
+            assert_eq!(abreviar_logradouro(entrada, None), esperado);
+        }
+    }
+
+    #[test]
+    fn abrevia_progressivamente() {
+        let entrada = "RUA PROFESSOR DOUTOR JOAO DO PARQUE";
+        assert_eq!(abreviar_logradouro(entrada, Some(100)), entrada);
+        assert_eq!(
+            abreviar_logradouro(entrada, Some(34)),
+            "R. PROFESSOR DOUTOR JOAO DO PARQUE"
+        );
+        assert_eq!(
+            abreviar_logradouro(entrada, Some(31)),
+            "R. PROFESSOR DR. JOAO DO PARQUE"
+        );
+        assert_eq!(
+            abreviar_logradouro(entrada, Some(27)),
+            "R. PROF. DR. JOAO DO PARQUE"
+        );
+        assert_eq!(
+            abreviar_logradouro(entrada, Some(1)),
+            "R. PROF. DR. JOAO DO PQ."
+        );
+    }
+
+    #[test]
+    fn abrevia_complementos_e_bairros() {
+        assert_eq!(
+            abreviar_complemento("QUADRA 1 LOTE 2 CASA 3", None),
+            "QD. 1 LT. 2 CS. 3"
+        );
+        assert_eq!(
+            abreviar_complemento("CAIXA POSTAL 10", None),
+            "CX. POSTAL 10"
+        );
+        assert_eq!(
+            abreviar_bairro("PARQUE RESIDENCIAL SAO JOSE", None),
+            "PQ. RES. SAO JOSE"
+        );
+        assert_eq!(abreviar_bairro("VILA NOVA", Some(9)), "VILA NOVA");
+    }
+}
@@ -1,8 +1,19 @@
 use std::sync::LazyLock;
 
-use crate::Padronizador;
+use crate::{
+    abreviacao::{abreviacoes_padrao, adicionar_abreviacoes, Abreviacao},
+    datas::{padronizar_datas_com, OpcoesData},
+    nome_proprio::{adicionar_nomes, adicionar_titulos},
+    Padronizador,
+};
 
 pub fn criar_padronizador_bairros() -> Padronizador {
+    criar_padronizador_bairros_com(&abreviacoes_padrao())
+}
+
+/// Cria o padronizador de bairros com as abreviações informadas, vide
+/// [crate::abreviacao::DicionarioAbreviacoes].
+pub(crate) fn criar_padronizador_bairros_com(abreviacoes: &[Abreviacao]) -> Padronizador {
     let mut padronizador = Padronizador::default();
     padronizador
         // Substituição nova
@@ -31,25 +42,26 @@ pub fn criar_padronizador_bairros() -> Padronizador {
         .adicionar(r"^X{3,}$", "") // XXX+
 
         // localidades
-        .adicionar(r"\bRES(I?D)?\b\.?", "RESIDENCIAL")
-        .adicionar(r"\bJAR DIM\b", "JARDIM")
-        .adicionar(r"\bJ(D(I?M)?|A?RD|AR(DIN)?)\b\.?", "JARDIM")
+        .adicionar(r"\bJAR DIM\b", "JARDIM");
+
+    // Abreviações compartilhadas com os outros padronizadores (JD, PQ, RES, CONJ, etc.)
+    adicionar_abreviacoes(&mut padronizador, abreviacoes);
+
+    padronizador
         .adicionar(r"^JR\b\.?", "JARDIM")
         .adicionar(r"\b(PCA|PRC)\b\.?", "PRACA")
-        .adicionar(r"\bP((A?R)?Q|QU?E)\b\.?", "PARQUE")
         .adicionar(r"\bP\.? RESIDENCIAL\b", "PARQUE RESIDENCIAL")
         .adicionar(r"^VL?\b\.?", "VILA") // melhor restringir ao comeco dos nomes, caso contrario pode ser algarismo romano ou nome abreviado
         .adicionar(r"\bCID\b\.?", "CIDADE")
         .adicionar(r"\bCIDADE UNI(V(ERS)?)?\b\.?", "CIDADE UNIVERSITARIA")
         .adicionar(r"\bC\.? UNIVERSITARIA\b", "CIDADE UNIVERSITARIA")
         .adicionar(r"\bCTO\b\.?", "CENTRO")
-        .adicionar(r"\bDISTR?\b\.?", "DISTRITO")
-        .adicionar(r"^DIS\b\.?", "DISTRITO")
-        .adicionar(r"\bCHA?C\b\.?", "CHACARA")
         .adicionar(r"^CH\b\.?", "CHACARA")
-        .adicionar(r"\bC(ON?)?J\b\.?", "CONJUNTO")
         .adicionar(r"^C\.? J\b\.?", "CONJUNTO")
-        .adicionar(r"\bC(ONJUNTO)? (H(B|AB(IT)?)?)\b\.?", "CONJUNTO HABITACIONAL")
+        .adicionar(
+            r"\bC(ONJUNTO)? (H(B|AB(IT)?)?)\b\.?",
+            "CONJUNTO HABITACIONAL",
+        )
         .adicionar(r"\bSTR\b\.?", "SETOR") // ST pode ser setor, santo/santa ou sitio. talvez melhor manter só STR mesmo e fazer mudanças mais específicas com ST
         .adicionar(r"^SET\b\.?", "SETOR")
         .adicionar(r"\b(DAS|DE) IND(L|TRL|US(TR?)?)?\b\.?", "$1 INDUSTRIAS")
@@ -57,27 +69,29 @@ pub fn criar_padronizador_bairros() -> Padronizador {
         .adicionar(r"\bD\.? INDUSTRIAL\b", "DISTRITO INDUSTRIAL")
         .adicionar(r"\bS\.? INDUSTRIAL\b", "SETOR INDUSTRIAL")
         .adicionar(r"\b(P\.? INDUSTRIAL|PARQUE IN)\b\.?", "PARQUE INDUSTRIAL")
-        .adicionar(r"\bLOT(EAME?)?\b\.?(.)", "LOTEAMENTO$2")
         .adicionar(r"^LT\b\.?", "LOTEAMENTO")
         .adicionar(r"\bZN\b\.?", "ZONA")
         .adicionar(r"^Z\b\.?", "ZONA")
         .adicionar(r"\bZONA R(UR?)?\b\.?", "ZONAL RURAL")
         .adicionar(r"^POV\b\.?", "POVOADO")
-        .adicionar(r"\bNUCL?\b\.?", "NUCLEO")
         .adicionar(r"\b(NUCLEO|N\.?) H(AB)?\b\.?", "NUCLEO HABITACIONAL")
         .adicionar(r"\b(NUCLEO|N\.?) C(OL)?\b\.?", "NUCLEO COLONIAL")
         .adicionar(r"\bN\.? INDUSTRIAL\b", "NUCLEO INDUSTRIAL")
         .adicionar(r"\bN\.? RESIDENCIAL\b", "NUCLEO RESIDENCIAL")
         .adicionar(r"\bBALN?\b\.?", "BALNEARIO")
-        .adicionar(r"\bFAZ(EN?)?\b\.?", "FAZENDA")
         .adicionar(r"\bBS?Q\b\.?", "BOSQUE")
         .adicionar(r"\bCACH\b\.?", "CACHOEIRA")
         .adicionar(r"\bTAB\b\.?", "TABULEIRO")
-        .adicionar(r"\bCOND\b\.?", "CONDOMINIO")
-        .adicionar(r"\bRECR?\.? (DOS? )?BAND.*\b\.?", "RECREIO DOS BANDEIRANTES")
+        .adicionar(
+            r"\bRECR?\.? (DOS? )?BAND.*\b\.?",
+            "RECREIO DOS BANDEIRANTES",
+        )
         .adicionar(r"\bREC\b\.?", "RECANTO")
         .adicionar(r"^COR\b\.?", "CORREGO")
-        .adicionar(r"\bENG\.? (D(A|E|O)|V(LH?|ELHO)?|NOVO|CACHOEIRINHA|GRANDE)\b", "ENGENHO $1")
+        .adicionar(
+            r"\bENG\.? (D(A|E|O)|V(LH?|ELHO)?|NOVO|CACHOEIRINHA|GRANDE)\b",
+            "ENGENHO $1",
+        )
         .adicionar(r"^TAG\b\.?", "TAGUATINGA")
         .adicionar(r"^ASS(ENT)?\b\.?", "ASSENTAMENTO")
         .adicionar(r"^SIT\b\.?", "SITIO")
@@ -85,72 +99,25 @@ pub fn criar_padronizador_bairros() -> Padronizador {
         .adicionar(r"\bCERQ\b\.?", "CERQUEIRA")
         .adicionar(r"\bCONS\b\.?(.)", "CONSELHEIRO$1") // CONS COMUN => CONSELHO COMUNITARIO, provavelment)
         .adicionar(r"\bPROL\b\.?(.)", "PROLONGAMENTO$1")
-
-        // titulos
-        .adicionar(r"\bSTO\b\.?", "SANTO")
         .adicionar(r"\bSTOS\b\.?", "SANTOS")
-        .adicionar(r"\bSTA\b\.?", "SANTA")
         .adicionar(r"\bSRA\b\.?", "SENHORA")
-        .adicionar(r"\b(N(OS|SS?A?)?\.? S(RA|ENHORA)|(NOSSA|NSA\.?) (S(RA?)?|SEN(H(OR)?)?))\b\.?", "NOSSA SENHORA")
-        .adicionar(r"\b(N(O?S)?\.? S(R|EN(H(OR)?)?)?\.?( DE?)?|NOSSA SENHORA|NS) (FAT.*|LO?UR.*|SANTANA|GUADALUPE|NAZ.*|COP*)\b", "NOSSA SENHORA DE $7")
-        .adicionar(r"\b(N(O?S)?\.? S(R|EN(H(OR)?)?)?\.?( D(A|E)?)?|NOSSA SENHORA|NS) (GRACA|VITORIA|PENHA|CONCEICAO|PAZ|GUIA|AJUDA|CANDELARIA|PURIFICACAO|SAUDE|PIEDADE|ABADIA|GLORIA|SALETE|APRESENTACAO)\b", "NOSSA SENHORA DA $8")
-        .adicionar(r"\b(N(O?S)?\.? S(R|EN(H(OR)?)?)?\.?( D(A|E)?)?|NOSSA SENHORA D(A|E)|NS) (APA.*|AUX.*|MEDIANEIRA|CONSOLADORA)\b", "NOSSA SENHORA $9")
-        .adicionar(r"\b(N(O?S)?\.? S(R|EN(H(OR)?)?)?\.?( D(OS?)?)?|NOSSA SENHORA|NS) (NAVEGANTES)\b", "NOSSA SENHORA DOS $8")
-        .adicionar(r"\b(N(O?S)?\.? S(R|EN(H(OR)?)?)?\.?( DO?)?|NOSSA SENHORA|NS) (CARMO|LIVRAMENTO|RETIRO|SION|ROSARIO|PILAR|ROCIO|CAMINHO|DESTERRO|BOM CONSELHO|AMPARO|PERP.*|P.* S.*)\b", "NOSSA SENHORA DO $7")
-        .adicionar(r"\b(N(O?S)?\.? S(R|EN(H(OR)?)?)?\.?( D(AS?)?)?|NOSSA SENHORA|NS) (GRACAS|DORES)\b", "NOSSA SENHORA DAS $8")
-        .adicionar(r"\b(S(R|ENH?)\.?( D(OS?)?)?|SENHOR( D(OS)?)?) (BON\w*)\b", "SENHOR DO BONFIM")
-        .adicionar(r"\b(S(R|ENH?)\.?( D(OS?)?)?|SENHOR( D(OS?)?)?) (BOM ?F\w*)\b", "SENHOR DO BONFIM")
-        .adicionar(r"\b(S(R|ENH?)\.?( D(OS?)?)?|SENHOR) (PASS\w*|MONT\w*)\b", "SENHOR DOS $5")
-        .adicionar(r"\bS(R|ENH?)\.? (BOM J\w*)\b", "SENHOR BOM JESUS")
-        .adicionar(r"\b(N(O?S)?\.? S(R|EN(H(OR)?)?)?\.?( D(OS?)?)?|NOSSO SENHOR|NS) (BONF\w*|BOM ?F\w*)\b", "NOSSO SENHOR DO BONFIM")
-        .adicionar(r"\b(N(O?S)?\.? S(R|EN(H(OR)?)?)?\.?( D(OS?)?)?|NOSSO SENHOR|NS) (PASS\w*|MONT\w*)\b", "NOSSO SENHOR DOS $8")
         .adicionar(r"\bESP?\.? SANTO", "ESPIRITO SANTO")
-        .adicionar(r"\bDIV\.? ESPIRITO SANTO\b", "DIVINO ESPIRITO SANTO")
-        .adicionar(r"\bS\.? (PAULO|VICENTE|FRANCISCO|DOMINGOS?|CRISTOVAO)\b", "SAO $1")
-
-        .adicionar(r"\bALMTE\b\.?", "ALMIRANTE")
-        .adicionar(r"\bMAL\b\.?(.)", "MARECHAL$1")
-        .adicionar(r"\bSGTO?\b\.?", "SARGENTO")
-        .adicionar(r"\bCEL\b\.?", "CORONEL")
-        .adicionar(r"\bBRIG\b\.?", "BRIGADEIRO")
-        .adicionar(r"\bTEN\b\.?", "TENENTE")
-        .adicionar(r"\bBRIGADEIRO (F\.?|FARIA) (L|LIMA)\b\.?", "BRIGADEIRO FARIA LIMA")
-
-        // Erros de digitação comuns para presidente. => Sem caso de teste no snapshot
-        .adicionar(r"\b(PREISI|PREZI|PRSI|PERSI|PESI)DENTE\b", "PRESIDENTE")
-
-        // consertar esse presidente
-        .adicionar(r"\bPRES(ID)?\b\.?(.)", "PRESIDENTE$2")
-        .adicionar(r"\bGOV\b\.?", "GOVERNADOR") // pode acabar com GOV. - e.g. ilha do gov
-        .adicionar(r"\bPREF\b\.?(.)", "PREFEITO$1")
-        .adicionar(r"\bDEP\b\.?(.)", "DEPUTADO$1")
-
-        .adicionar(r"\bDR\b\.?", "DOUTOR")
-        .adicionar(r"\bDRA\b\.?", "DOUTORA")
-        .adicionar(r"\bPROF\b\.?", "PROFESSOR")
-        .adicionar(r"\bPROFA\b\.?", "PROFESSORA")
-        .adicionar(r"\bPE\b\.(.)", "PADRE$1")
-
-        .adicionar(r"\bD\b\.? (PEDRO|JOAO|HENRIQUE)", "DOM $1")
-        .adicionar(r"\bI(NF)?\.? DOM\b", "INFANTE DOM")
+        .adicionar(r"\bDIV\.? ESPIRITO SANTO\b", "DIVINO ESPIRITO SANTO");
+
+    // Títulos e nomes próprios (CEL, DR, N. SRA, D. PEDRO, S. JORGE, etc.), compartilhados com
+    // os logradouros. Vide [crate::nome_proprio].
+    adicionar_titulos(&mut padronizador);
+    adicionar_nomes(&mut padronizador);
 
+    padronizador
+        .adicionar(
+            r"\bBRIGADEIRO (F\.?|FARIA) (L|LIMA)\b\.?",
+            "BRIGADEIRO FARIA LIMA",
+        )
+        // correção de problema ocasionado pelos títulos acima
+        .adicionar(r"\bVEREADOR O PESO\b", "VER O PESO")
         // Unifica a grafia, mesmo que o nome oficial realmente seja diferente.
-        .adicionar(r"\bCAMPOS? H?ELI(Z|S)I?E?(O|U)(S|Z)?\b", "CAMPOS ELISIOS")
-
-        // datas
-
-        .adicionar(r"\b(\d+) DE? JAN(EIRO)?\b", "$1 DE JANEIRO")
-        .adicionar(r"\b(\d+) DE? FEV(EREIRO)?\b", "$1 DE FEVEREIRO")
-        .adicionar(r"\b(\d+) DE? MAR(CO)?\b", "$1 DE MARCO")
-        .adicionar(r"\b(\d+) DE? ABR(IL)?\b", "$1 DE ABRIL")
-        .adicionar(r"\b(\d+) DE? MAI(O)?\b", "$1 DE MAIO")
-        .adicionar(r"\b(\d+) DE? JUN(HO)?\b", "$1 DE JUNHO")
-        .adicionar(r"\b(\d+) DE? JUL(HO)?\b", "$1 DE JULHO")
-        .adicionar(r"\b(\d+) DE? AGO(STO)?\b", "$1 DE AGOSTO")
-        .adicionar(r"\b(\d+) DE? SET(EMBRO)?\b", "$1 DE SETEMBRO")
-        .adicionar(r"\b(\d+) DE? OUT(UBRO)?\b", "$1 DE OUTUBRO")
-        .adicionar(r"\b(\d+) DE? NOV(EMBRO)?\b", "$1 DE NOVEMBRO")
-        .adicionar(r"\b(\d+) DE? DEZ(EMBRO)?\b", "$1 DE DEZEMBRO");
+        .adicionar(r"\bCAMPOS? H?ELI(Z|S)I?E?(O|U)(S|Z)?\b", "CAMPOS ELISIOS");
 
     padronizador.preparar();
     padronizador
@@ -178,15 +145,42 @@ static PADRONIZADOR_BAIRROS: LazyLock<Padronizador> = LazyLock::new(criar_padron
 /// - remoção de acentos e caracteres não ASCII;
 /// - adição de espaços após abreviações sinalizadas por pontos;
 /// - expansão de abreviações frequentemente utilizadas através de diversas expressões regulares (regexes);
-/// - correção de alguns pequenos erros ortográficos.
+/// - correção de alguns pequenos erros ortográficos;
+/// - padronização de datas para a forma "7 DE SETEMBRO", vide [crate::datas::padronizar_datas_com].
 ///
 /// Note que existe uma etapa de compilação das expressões regulares utilizadas,
 /// logo a primeira execução desta função pode demorar um pouco a mais.
 ///
 pub fn padronizar_bairros(valor: &str) -> String {
     // Forma de obter a variável lazy
-    let padronizador = &*PADRONIZADOR_BAIRROS;
-    padronizador.padronizar(valor)
+    padronizar_bairros_com(valor, &OpcoesData::default())
+}
+
+/// Padroniza como [padronizar_bairros], com as opções de padronização das datas presentes no nome.
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::bairro::padronizar_bairros_com;
+/// use enderecobr_rs::datas::OpcoesData;
+/// let opcoes = OpcoesData {
+///     numericas: true,
+///     ..Default::default()
+/// };
+/// assert_eq!(padronizar_bairros_com("VILA 07/09", &opcoes), "VILA 7 DE SETEMBRO");
+/// assert_eq!(padronizar_bairros_com("VILA 07/09", &OpcoesData::default()), "VILA 07/09");
+/// ```
+///
+pub fn padronizar_bairros_com(valor: &str, opcoes: &OpcoesData) -> String {
+    padronizar_bairros_usando(&PADRONIZADOR_BAIRROS, valor, opcoes)
+}
+
+pub(crate) fn padronizar_bairros_usando(
+    padronizador: &Padronizador,
+    valor: &str,
+    opcoes: &OpcoesData,
+) -> String {
+    let padronizado = padronizador.padronizar(valor);
+    padronizar_datas_com(&padronizado, opcoes)
 }
 
 #[cfg(test)]
@@ -200,4 +194,20 @@ mod tests {
         assert_eq!(padronizar_bairros("jd..botanico"), "JARDIM BOTANICO");
         assert_eq!(padronizar_bairros(""), ""); // substitui NA
     }
+
+    #[test]
+    fn padroniza_titulos_como_logradouros() {
+        let casos = [
+            ("DES. MOTTA", "DESEMBARGADOR MOTTA"),
+            ("S. JORGE", "SAO JORGE"),
+            ("D PEDRO II", "DOM PEDRO II"),
+            ("N SRA DE FATIMA", "NOSSA SENHORA DE FATIMA"),
+            ("STOS DUMONT", "SANTOS DUMONT"),
+            ("VER O PESO", "VER O PESO"),
+        ];
+
+        for (entrada, esperado) in casos {
+            assert_eq!(padronizar_bairros(entrada), esperado);
+        }
+    }
 }
@@ -0,0 +1,111 @@
+use clap::Parser;
+use enderecobr_rs::{
+    blocagem::ColunasEndereco,
+    deduplicacao::{deduplicar_para_parquet, OpcoesDeduplicacao},
+};
+use polars::prelude::{LazyFrame, PlPath, PolarsResult, ScanArgsParquet};
+
+/// Utilitário que agrupa os endereços de um arquivo parquet que se referem ao mesmo lugar.
+///
+/// O arquivo de saída tem as colunas do arquivo de entrada, as chaves de blocagem e a coluna
+/// "grupo", com o índice da primeira linha do grupo de cada linha.
+#[derive(Debug, Parser)]
+#[clap(author, version)]
+struct Args {
+    /// Caminho do arquivo PARQUET de entrada.
+    arquivo_entrada: String,
+
+    /// Caminho do arquivo PARQUET de saída.
+    #[arg(short('o'), long, default_value = "./resultado.parquet")]
+    arquivo_saida: String,
+
+    /// Coluna com o logradouro.
+    #[arg(long)]
+    logradouro: Option<String>,
+
+    /// Coluna com o número.
+    #[arg(long)]
+    numero: Option<String>,
+
+    /// Coluna com o complemento.
+    #[arg(long)]
+    complemento: Option<String>,
+
+    /// Coluna com a localidade ou bairro.
+    #[arg(long)]
+    localidade: Option<String>,
+
+    /// Coluna com o estado.
+    #[arg(long)]
+    estado: Option<String>,
+
+    /// Coluna com o município.
+    #[arg(long)]
+    municipio: Option<String>,
+
+    /// Coluna com o CEP.
+    #[arg(long)]
+    cep: Option<String>,
+
+    /// Similaridade total mínima para que dois endereços sejam agrupados.
+    #[arg(long, default_value = "0.9")]
+    limiar: f64,
+
+    /// Similaridade mínima entre os logradouros para que dois endereços sejam agrupados.
+    #[arg(long, default_value = "0.85")]
+    limiar_logradouro: f64,
+
+    /// Blocos maiores que este tamanho não são comparados.
+    #[arg(long, default_value = "1000")]
+    tamanho_maximo_bloco: usize,
+
+    /// Limite de linhas a serem processadas. Usado para execuções de teste.
+    #[arg(short('n'), long)]
+    limite: Option<u32>,
+}
+
+fn processar(args: &Args) -> PolarsResult<usize> {
+    let mut df = LazyFrame::scan_parquet(
+        PlPath::new(&args.arquivo_entrada),
+        ScanArgsParquet {
+            low_memory: true,
+            rechunk: true,
+            ..Default::default()
+        },
+    )?;
+
+    if let Some(limite) = args.limite {
+        df = df.limit(limite);
+    }
+
+    let colunas = ColunasEndereco {
+        logradouro: args.logradouro.clone(),
+        numero: args.numero.clone(),
+        complemento: args.complemento.clone(),
+        localidade: args.localidade.clone(),
+        estado: args.estado.clone(),
+        municipio: args.municipio.clone(),
+        cep: args.cep.clone(),
+    };
+    let opcoes = OpcoesDeduplicacao {
+        limiar: args.limiar,
+        limiar_logradouro: args.limiar_logradouro,
+        tamanho_maximo_bloco: args.tamanho_maximo_bloco,
+        ..Default::default()
+    };
+
+    let resultado = deduplicar_para_parquet(df, &args.arquivo_saida, &colunas, &opcoes)?;
+    resultado.column("grupo")?.n_unique()
+}
+
+fn main() {
+    let args = Args::parse();
+
+    match processar(&args) {
+        Ok(grupos) => println!(
+            "Arquivo \"{}\" foi processado com sucesso ({} grupos) e salvo em \"{}\".",
+            args.arquivo_entrada, grupos, args.arquivo_saida
+        ),
+        Err(err) => println!("{}", err),
+    }
+}
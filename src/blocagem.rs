@@ -0,0 +1,550 @@
+use itertools::Itertools;
+
+use crate::{
+    metaphone::metaphone, municipio::codigo_municipio, numero::NumeroEndereco,
+    padronizar_cep_leniente, padronizar_estados_para_sigla, padronizar_municipios, separar_numero,
+    separar_tipo_logradouro, Endereco,
+};
+
+/// Endereço acompanhado dos campos que o localizam no país, usados na blocagem.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RegistroEndereco {
+    pub endereco: Endereco,
+    /// Estado, em qualquer formato aceito por [padronizar_estados_para_sigla].
+    pub estado: Option<String>,
+    /// Município, pelo nome ou pelo código do IBGE.
+    pub municipio: Option<String>,
+    pub cep: Option<String>,
+}
+
+/// Componente de uma chave de blocagem, vide [ReceitaBlocagem].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComponenteBlocagem {
+    /// Sigla do estado.
+    Estado,
+    /// Código do IBGE do município, ou o nome padronizado quando o código não pode ser
+    /// determinado (vide [codigo_municipio]).
+    Municipio,
+    /// Prefixo do CEP padronizado com a quantidade de dígitos informada.
+    Cep(usize),
+    /// Código [metaphone] da primeira palavra significativa do nome do logradouro, isto é,
+    /// ignorando o tipo de logradouro e as preposições. Números são mantidos como estão.
+    FoneticaLogradouro,
+    /// Faixa do número do endereço, de acordo com o tamanho informado: com faixas de 100, os
+    /// números 100 a 199 têm a chave "100". Endereços sem número têm a chave "S/N".
+    FaixaNumero(u32),
+    /// Código [metaphone] da localidade (bairro) padronizada.
+    FoneticaLocalidade,
+}
+
+/// Receita de uma chave de blocagem: a concatenação de alguns componentes do endereço.
+///
+/// Os registros com a mesma chave formam um bloco, e só os pares dentro de um mesmo bloco são
+/// comparados. Combinando várias receitas, um par de registros só deixa de ser comparado se
+/// diferir em todas elas.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReceitaBlocagem {
+    /// Nome da receita, usado como nome da coluna na API do Polars.
+    pub nome: String,
+    pub componentes: Vec<ComponenteBlocagem>,
+}
+
+impl ReceitaBlocagem {
+    pub fn new(nome: &str, componentes: &[ComponenteBlocagem]) -> Self {
+        ReceitaBlocagem {
+            nome: nome.to_string(),
+            componentes: componentes.to_vec(),
+        }
+    }
+
+    /// Estado, município, fonética do logradouro e faixa de 100 números.
+    pub fn logradouro() -> Self {
+        ReceitaBlocagem::new(
+            "chave_logradouro",
+            &[
+                ComponenteBlocagem::Estado,
+                ComponenteBlocagem::Municipio,
+                ComponenteBlocagem::FoneticaLogradouro,
+                ComponenteBlocagem::FaixaNumero(100),
+            ],
+        )
+    }
+
+    /// Os cinco primeiros dígitos do CEP, que identificam o sub-setor postal.
+    pub fn cep() -> Self {
+        ReceitaBlocagem::new("chave_cep", &[ComponenteBlocagem::Cep(5)])
+    }
+
+    /// Receitas padrão: [ReceitaBlocagem::logradouro] e [ReceitaBlocagem::cep].
+    pub fn padrao() -> Vec<Self> {
+        vec![ReceitaBlocagem::logradouro(), ReceitaBlocagem::cep()]
+    }
+}
+
+const PREPOSICOES: [&str; 5] = ["DE", "DA", "DO", "DAS", "DOS"];
+
+pub(crate) fn fonetica_logradouro(logradouro: &str) -> Option<String> {
+    let (_, nome) = separar_tipo_logradouro(logradouro);
+    let palavra = nome.split_whitespace().find(|p| !PREPOSICOES.contains(p))?;
+
+    if palavra.bytes().any(|c| c.is_ascii_alphabetic()) {
+        Some(metaphone(palavra)).filter(|m| !m.is_empty())
+    } else {
+        Some(palavra.to_string())
+    }
+}
+
+fn faixa_numero(numero: &str, tamanho: u32) -> Option<String> {
+    match separar_numero(numero) {
+        NumeroEndereco::SemNumero => Some("S/N".to_string()),
+        numero => numero
+            .numero()
+            .map(|n| (n / tamanho.max(1) * tamanho.max(1)).to_string()),
+    }
+}
+
+pub(crate) fn nao_vazio(valor: &Option<String>) -> Option<&str> {
+    valor.as_deref().filter(|x| !x.trim().is_empty())
+}
+
+// Código do IBGE do município, ou o nome padronizado quando o código não pode ser determinado.
+pub(crate) fn chave_municipio(municipio: &str, estado: Option<&str>) -> String {
+    match codigo_municipio(municipio, estado) {
+        Some(codigo) => codigo.to_string(),
+        None => padronizar_municipios(municipio),
+    }
+}
+
+// Município pelo código do IBGE e pelo nome padronizado. Nomes repetidos em mais de um estado,
+// como "CAMPO GRANDE", só têm código quando o estado é conhecido; nesse caso, a comparação é
+// feita pelo nome.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct ChaveMunicipio {
+    pub(crate) codigo: Option<&'static str>,
+    pub(crate) nome: String,
+}
+
+impl ChaveMunicipio {
+    pub(crate) fn new(municipio: &str, estado: Option<&str>) -> Self {
+        let codigo = codigo_municipio(municipio, estado);
+        ChaveMunicipio {
+            codigo,
+            nome: padronizar_municipios(codigo.unwrap_or(municipio)),
+        }
+    }
+
+    pub(crate) fn compativel(&self, outra: &ChaveMunicipio) -> bool {
+        match (self.codigo, outra.codigo) {
+            (Some(a), Some(b)) => a == b,
+            _ => self.nome == outra.nome,
+        }
+    }
+}
+
+// CEP padronizado, só com os 8 dígitos.
+pub(crate) fn digitos_cep(cep: &str) -> String {
+    padronizar_cep_leniente(cep).replace('-', "")
+}
+
+fn componente(registro: &RegistroEndereco, componente: ComponenteBlocagem) -> Option<String> {
+    let endereco = &registro.endereco;
+
+    let valor = match componente {
+        ComponenteBlocagem::Estado => {
+            Some(padronizar_estados_para_sigla(nao_vazio(&registro.estado)?).to_string())
+        }
+        ComponenteBlocagem::Municipio => Some(chave_municipio(
+            nao_vazio(&registro.municipio)?,
+            nao_vazio(&registro.estado),
+        )),
+        ComponenteBlocagem::Cep(digitos) => {
+            let cep = digitos_cep(nao_vazio(&registro.cep)?);
+            cep.get(..digitos).map(str::to_string)
+        }
+        ComponenteBlocagem::FoneticaLogradouro => {
+            fonetica_logradouro(nao_vazio(&endereco.logradouro)?)
+        }
+        ComponenteBlocagem::FaixaNumero(tamanho) => {
+            faixa_numero(nao_vazio(&endereco.numero)?, tamanho)
+        }
+        ComponenteBlocagem::FoneticaLocalidade => {
+            Some(metaphone(&endereco.localidade_padronizada()?))
+        }
+    };
+
+    valor.filter(|v| !v.is_empty())
+}
+
+/// Gera a chave de blocagem de um registro, concatenando os componentes da receita com "|".
+///
+/// Retorna `None` se algum componente estiver ausente ou não puder ser determinado, para que
+/// registros incompletos não formem um único bloco gigante.
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::Endereco;
+/// use enderecobr_rs::blocagem::{chave_blocagem, ReceitaBlocagem, RegistroEndereco};
+///
+/// let registro = RegistroEndereco {
+///     endereco: Endereco {
+///         logradouro: Some("Av. N. Sra. de Copacabana".to_string()),
+///         numero: Some("0123".to_string()),
+///         ..Default::default()
+///     },
+///     estado: Some("Rio de Janeiro".to_string()),
+///     municipio: Some("Rio de Janeiro".to_string()),
+///     cep: Some("22020-001".to_string()),
+/// };
+///
+/// assert_eq!(
+///     chave_blocagem(&registro, &ReceitaBlocagem::logradouro()),
+///     Some("RJ|3304557|NOSA|100".to_string())
+/// );
+/// assert_eq!(
+///     chave_blocagem(&registro, &ReceitaBlocagem::cep()),
+///     Some("22020".to_string())
+/// );
+///
+/// let sem_cep = RegistroEndereco { cep: None, ..registro };
+/// assert_eq!(chave_blocagem(&sem_cep, &ReceitaBlocagem::cep()), None);
+/// ```
+pub fn chave_blocagem(registro: &RegistroEndereco, receita: &ReceitaBlocagem) -> Option<String> {
+    if receita.componentes.is_empty() {
+        return None;
+    }
+
+    receita
+        .componentes
+        .iter()
+        .map(|c| componente(registro, *c))
+        .collect::<Option<Vec<String>>>()
+        .map(|partes| partes.join("|"))
+}
+
+/// Gera as chaves de blocagem de um registro para cada receita, na mesma ordem.
+pub fn chaves_blocagem(
+    registro: &RegistroEndereco,
+    receitas: &[ReceitaBlocagem],
+) -> Vec<Option<String>> {
+    receitas
+        .iter()
+        .map(|receita| chave_blocagem(registro, receita))
+        .collect()
+}
+
+/// Gera as chaves de blocagem de um lote de registros. O resultado tem uma coluna por receita,
+/// cada uma com uma chave por registro.
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::blocagem::{chaves_blocagem_lote, ReceitaBlocagem, RegistroEndereco};
+///
+/// let registros = vec![
+///     RegistroEndereco { cep: Some("22020001".to_string()), ..Default::default() },
+///     RegistroEndereco::default(),
+/// ];
+/// let colunas = chaves_blocagem_lote(&registros, &ReceitaBlocagem::padrao());
+/// assert_eq!(colunas[0], vec![None, None]);
+/// assert_eq!(colunas[1], vec![Some("22020".to_string()), None]);
+/// ```
+pub fn chaves_blocagem_lote(
+    registros: &[RegistroEndereco],
+    receitas: &[ReceitaBlocagem],
+) -> Vec<Vec<Option<String>>> {
+    receitas
+        .iter()
+        .map(|receita| {
+            registros
+                .iter()
+                .map(|registro| chave_blocagem(registro, receita))
+                .collect()
+        })
+        .collect()
+}
+
+/// Agrupa os índices dos registros por chave de blocagem, descartando os registros sem chave e
+/// os blocos com um único registro, que não têm pares a comparar.
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::blocagem::agrupar_blocos;
+/// let chaves = vec![Some("A".to_string()), None, Some("B".to_string()), Some("A".to_string())];
+/// assert_eq!(agrupar_blocos(&chaves), vec![vec![0, 3]]);
+/// ```
+pub fn agrupar_blocos(chaves: &[Option<String>]) -> Vec<Vec<usize>> {
+    chaves
+        .iter()
+        .enumerate()
+        .filter_map(|(i, chave)| chave.as_deref().map(|c| (c, i)))
+        .into_group_map()
+        .into_iter()
+        .filter(|(_, indices)| indices.len() > 1)
+        .map(|(_, indices)| indices)
+        .sorted()
+        .collect()
+}
+
+#[cfg(feature = "polars")]
+pub use lote_polars::{expressoes_blocagem, registros_dataframe, ColunasEndereco};
+
+#[cfg(feature = "polars")]
+mod lote_polars {
+    use polars::prelude::{
+        as_struct, col, lit, Column, DataType, Expr, Field, IntoColumn, LazyFrame, NamedFrom,
+        PolarsResult, StringChunked, NULL,
+    };
+
+    use super::{chave_blocagem, ReceitaBlocagem, RegistroEndereco};
+    use crate::Endereco;
+
+    /// Nomes das colunas de um DataFrame com os campos de um [RegistroEndereco]. As colunas
+    /// ausentes são tratadas como nulas.
+    #[derive(Debug, Clone, Default, PartialEq, Eq)]
+    pub struct ColunasEndereco {
+        pub logradouro: Option<String>,
+        pub numero: Option<String>,
+        pub complemento: Option<String>,
+        pub localidade: Option<String>,
+        pub estado: Option<String>,
+        pub municipio: Option<String>,
+        pub cep: Option<String>,
+    }
+
+    impl ColunasEndereco {
+        fn expressoes(&self) -> Vec<Expr> {
+            [
+                &self.logradouro,
+                &self.numero,
+                &self.complemento,
+                &self.localidade,
+                &self.estado,
+                &self.municipio,
+                &self.cep,
+            ]
+            .iter()
+            .enumerate()
+            .map(|(i, coluna)| {
+                let expr = match coluna {
+                    Some(coluna) => col(coluna.as_str()),
+                    None => lit(NULL),
+                };
+                expr.cast(DataType::String).alias(format!("campo_{}", i))
+            })
+            .collect()
+        }
+    }
+
+    fn registros_struct(coluna: &Column) -> PolarsResult<Vec<RegistroEndereco>> {
+        let campos = coluna.struct_()?.fields_as_series();
+        let valores = campos
+            .iter()
+            .map(|serie| Ok(serie.str()?.iter().collect::<Vec<_>>()))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let registros = (0..coluna.len())
+            .map(|i| {
+                let campo = |c: usize| valores[c][i].map(str::to_string);
+                RegistroEndereco {
+                    endereco: Endereco {
+                        logradouro: campo(0),
+                        numero: campo(1),
+                        complemento: campo(2),
+                        localidade: campo(3),
+                    },
+                    estado: campo(4),
+                    municipio: campo(5),
+                    cep: campo(6),
+                }
+            })
+            .collect();
+        Ok(registros)
+    }
+
+    fn calcular_chaves(coluna: Column, receita: &ReceitaBlocagem) -> PolarsResult<Column> {
+        let chaves: Vec<Option<String>> = registros_struct(&coluna)?
+            .iter()
+            .map(|registro| chave_blocagem(registro, receita))
+            .collect();
+
+        Ok(StringChunked::new(receita.nome.as_str().into(), chaves).into_column())
+    }
+
+    /// Lê os registros de endereço de um DataFrame, na ordem das suas linhas.
+    ///
+    /// # Exemplo
+    /// ```
+    /// use enderecobr_rs::blocagem::{registros_dataframe, ColunasEndereco};
+    /// use polars::prelude::*;
+    ///
+    /// let df = df!("logr" => ["RUA A", "RUA B"], "cep" => [Some(22020001), None]).unwrap();
+    /// let colunas = ColunasEndereco {
+    ///     logradouro: Some("logr".to_string()),
+    ///     cep: Some("cep".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// let registros = registros_dataframe(df.lazy(), &colunas).unwrap();
+    /// assert_eq!(registros[0].endereco.logradouro.as_deref(), Some("RUA A"));
+    /// assert_eq!(registros[0].cep.as_deref(), Some("22020001"));
+    /// assert_eq!(registros[1].cep, None);
+    /// ```
+    pub fn registros_dataframe(
+        df: LazyFrame,
+        colunas: &ColunasEndereco,
+    ) -> PolarsResult<Vec<RegistroEndereco>> {
+        let df = df
+            .select([as_struct(colunas.expressoes()).alias("registro")])
+            .collect()?;
+        registros_struct(df.column("registro")?)
+    }
+
+    /// Cria as expressões do Polars que calculam as chaves de blocagem de cada receita, com o
+    /// nome da receita como nome da coluna.
+    ///
+    /// # Exemplo
+    /// ```
+    /// use enderecobr_rs::blocagem::{expressoes_blocagem, ColunasEndereco, ReceitaBlocagem};
+    /// use polars::prelude::*;
+    ///
+    /// let df = df!("cep" => ["22020-001", "22020-050", "01310-100"]).unwrap();
+    /// let colunas = ColunasEndereco { cep: Some("cep".to_string()), ..Default::default() };
+    /// let resultado = df
+    ///     .lazy()
+    ///     .with_columns(expressoes_blocagem(&colunas, &[ReceitaBlocagem::cep()]))
+    ///     .collect()
+    ///     .unwrap();
+    ///
+    /// let chaves: Vec<_> = resultado.column("chave_cep").unwrap().str().unwrap().iter().collect();
+    /// assert_eq!(chaves, [Some("22020"), Some("22020"), Some("01310")]);
+    /// ```
+    pub fn expressoes_blocagem(
+        colunas: &ColunasEndereco,
+        receitas: &[ReceitaBlocagem],
+    ) -> Vec<Expr> {
+        receitas
+            .iter()
+            .map(|receita| {
+                let receita = receita.clone();
+                let nome = receita.nome.clone();
+                let field = Field::new(nome.as_str().into(), DataType::String);
+                as_struct(colunas.expressoes())
+                    .map(
+                        move |coluna: Column| calcular_chaves(coluna, &receita),
+                        move |_, _| Ok(field.clone()),
+                    )
+                    .alias(nome.as_str())
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn variacoes_tem_a_mesma_chave() {
+        let receita = ReceitaBlocagem::logradouro();
+        let a = RegistroEndereco {
+            endereco: Endereco {
+                logradouro: Some("R. SOUZA LIMA".to_string()),
+                numero: Some("210".to_string()),
+                ..Default::default()
+            },
+            estado: Some("RJ".to_string()),
+            municipio: Some("3304557".to_string()),
+            cep: Some("22081-010".to_string()),
+        };
+        let b = RegistroEndereco {
+            endereco: Endereco {
+                logradouro: Some("Rua Sousa Lima".to_string()),
+                numero: Some("0250".to_string()),
+                ..Default::default()
+            },
+            estado: Some("33".to_string()),
+            municipio: Some("Rio de Janeiro".to_string()),
+            cep: Some("22081010".to_string()),
+        };
+        assert_eq!(chave_blocagem(&a, &receita), chave_blocagem(&b, &receita));
+        assert_eq!(
+            chave_blocagem(&a, &ReceitaBlocagem::cep()),
+            chave_blocagem(&b, &ReceitaBlocagem::cep())
+        );
+
+        let c = RegistroEndereco {
+            endereco: Endereco {
+                logradouro: Some("RUA SOUZA LIMA".to_string()),
+                numero: Some("310".to_string()),
+                ..Default::default()
+            },
+            estado: Some("RJ".to_string()),
+            municipio: Some("RIO DE JANEIRO".to_string()),
+            cep: None,
+        };
+        assert_ne!(chave_blocagem(&a, &receita), chave_blocagem(&c, &receita));
+    }
+
+    #[test]
+    fn compara_municipios() {
+        let ms = ChaveMunicipio::new("CAMPO GRANDE", Some("MS"));
+        let rn = ChaveMunicipio::new("CAMPO GRANDE", Some("RN"));
+        let sem_estado = ChaveMunicipio::new("CAMPO GRANDE", None);
+        assert_eq!(ms.codigo, Some("5002704"));
+        assert_eq!(sem_estado.codigo, None);
+        assert!(!ms.compativel(&rn));
+        // Sem o código de um dos lados, a comparação é feita pelo nome.
+        assert!(ms.compativel(&sem_estado));
+        assert!(sem_estado.compativel(&rn));
+
+        assert!(ChaveMunicipio::new("3304557", None)
+            .compativel(&ChaveMunicipio::new("Rio de Janeiro", Some("RJ"))));
+    }
+
+    #[test]
+    fn gera_componentes() {
+        let r = RegistroEndereco {
+            endereco: Endereco {
+                logradouro: Some("RUA 7 DE SETEMBRO".to_string()),
+                numero: Some("S/N".to_string()),
+                ..Default::default()
+            },
+            estado: Some("SP".to_string()),
+            municipio: Some("CAMPO GRANDE".to_string()),
+            cep: None,
+        };
+        assert_eq!(
+            componente(&r, ComponenteBlocagem::FoneticaLogradouro),
+            Some("7".to_string())
+        );
+        assert_eq!(
+            componente(&r, ComponenteBlocagem::FaixaNumero(100)),
+            Some("S/N".to_string())
+        );
+        // Não existe Campo Grande em SP, então fica o nome.
+        assert_eq!(
+            componente(&r, ComponenteBlocagem::Municipio),
+            Some("CAMPO GRANDE".to_string())
+        );
+
+        let r = RegistroEndereco {
+            endereco: Endereco {
+                logradouro: Some("TRAVESSA DOS ANDRADAS".to_string()),
+                numero: Some("45A".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert_eq!(
+            componente(&r, ComponenteBlocagem::FoneticaLogradouro),
+            Some("ANDRADAS".to_string())
+        );
+        assert_eq!(
+            componente(&r, ComponenteBlocagem::FaixaNumero(10)),
+            Some("40".to_string())
+        );
+        assert_eq!(componente(&r, ComponenteBlocagem::Estado), None);
+        assert_eq!(
+            chave_blocagem(&r, &ReceitaBlocagem::new("vazia", &[])),
+            None
+        );
+    }
+}
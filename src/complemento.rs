@@ -1,6 +1,11 @@
-use std::sync::LazyLock;
+use std::{fmt, sync::LazyLock};
 
-use crate::Padronizador;
+use itertools::Itertools;
+
+use crate::{
+    numero_extenso::{padronizar_ordinais, FormatoOrdinal},
+    Padronizador,
+};
 
 pub fn criar_padronizador_complemento() -> Padronizador {
     let mut padronizador = Padronizador::default();
@@ -271,7 +276,12 @@ static PADRONIZADOR: LazyLock<Padronizador> = LazyLock::new(criar_padronizador_c
 /// - remoção de acentos e caracteres não ASCII;
 /// - adição de espaços após abreviações sinalizadas por pontos;
 /// - expansão de abreviações frequentemente utilizadas através de diversas expressões regulares (regexes);
-/// - correção de alguns pequenos erros ortográficos.
+/// - correção de alguns pequenos erros ortográficos;
+/// - padronização de números ordinais para a forma "2O ANDAR", vide
+///   [crate::numero_extenso::padronizar_ordinais].
+///
+/// A ordem dos componentes do complemento é mantida, exceto nas combinações mais comuns de
+/// quadra, lote e casa. Para uma ordem canônica, vide [padronizar_complementos_ordenados].
 ///
 /// Note que existe uma etapa de compilação das expressões regulares utilizadas,
 /// logo a primeira execução desta função pode demorar um pouco a mais.
@@ -279,7 +289,210 @@ static PADRONIZADOR: LazyLock<Padronizador> = LazyLock::new(criar_padronizador_c
 pub fn padronizar_complementos(valor: &str) -> String {
     // Forma de obter a variável lazy
     let padronizador = &*PADRONIZADOR;
-    padronizador.padronizar(valor)
+    // Os ordinais são padronizados antes da normalização, que removeria os símbolos "º" e "ª".
+    padronizador.padronizar(&padronizar_ordinais(valor, FormatoOrdinal::Numerico))
+}
+
+/// Tipos de unidade reconhecidos em um complemento.
+///
+/// A ordem de declaração é a ordem canônica usada por [padronizar_complementos_ordenados]:
+/// CONDOMINIO, CONJUNTO, TORRE, BLOCO, QUADRA, LOTE, CASA, ANDAR, APARTAMENTO, COBERTURA, SALA,
+/// LOJA, FUNDOS e, por fim, os trechos não reconhecidos.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TipoComplemento {
+    Condominio,
+    Conjunto,
+    Torre,
+    Bloco,
+    Quadra,
+    Lote,
+    Casa,
+    Andar,
+    Apartamento,
+    Cobertura,
+    Sala,
+    Loja,
+    Fundos,
+    /// Trecho do complemento que não corresponde a nenhuma unidade conhecida,
+    /// como "PROXIMO AO MERCADO".
+    Outro,
+}
+
+impl TipoComplemento {
+    /// Nome do tipo, na forma usada pela padronização de complementos.
+    pub fn nome(&self) -> &'static str {
+        match self {
+            TipoComplemento::Torre => "TORRE",
+            TipoComplemento::Bloco => "BLOCO",
+            TipoComplemento::Quadra => "QUADRA",
+            TipoComplemento::Lote => "LOTE",
+            TipoComplemento::Casa => "CASA",
+            TipoComplemento::Andar => "ANDAR",
+            TipoComplemento::Apartamento => "APARTAMENTO",
+            TipoComplemento::Sala => "SALA",
+            TipoComplemento::Loja => "LOJA",
+            TipoComplemento::Conjunto => "CONJUNTO",
+            TipoComplemento::Condominio => "CONDOMINIO",
+            TipoComplemento::Cobertura => "COBERTURA",
+            TipoComplemento::Fundos => "FUNDOS",
+            TipoComplemento::Outro => "",
+        }
+    }
+
+    fn de_nome(nome: &str) -> Option<TipoComplemento> {
+        match nome {
+            "TORRE" => Some(TipoComplemento::Torre),
+            "BLOCO" => Some(TipoComplemento::Bloco),
+            "QUADRA" => Some(TipoComplemento::Quadra),
+            "LOTE" => Some(TipoComplemento::Lote),
+            "CASA" => Some(TipoComplemento::Casa),
+            "ANDAR" => Some(TipoComplemento::Andar),
+            "APARTAMENTO" => Some(TipoComplemento::Apartamento),
+            "SALA" => Some(TipoComplemento::Sala),
+            "LOJA" => Some(TipoComplemento::Loja),
+            "CONJUNTO" => Some(TipoComplemento::Conjunto),
+            "CONDOMINIO" => Some(TipoComplemento::Condominio),
+            "COBERTURA" => Some(TipoComplemento::Cobertura),
+            "FUNDOS" => Some(TipoComplemento::Fundos),
+            _ => None,
+        }
+    }
+}
+
+/// Uma unidade de um complemento, como "QUADRA 1" ou "APARTAMENTO 302".
+///
+/// O valor é vazio para unidades que não são identificadas, como "FUNDOS" ou "COBERTURA".
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UnidadeComplemento {
+    pub tipo: TipoComplemento,
+    pub valor: String,
+}
+
+impl fmt::Display for UnidadeComplemento {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.tipo, self.valor.is_empty()) {
+            (TipoComplemento::Outro, _) => write!(f, "{}", self.valor),
+            (tipo, true) => write!(f, "{}", tipo.nome()),
+            (tipo, false) => write!(f, "{} {}", tipo.nome(), self.valor),
+        }
+    }
+}
+
+/// Separa um complemento em suas unidades (quadra, lote, bloco, apartamento, etc.).
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::complemento::{TipoComplemento, UnidadeComplemento};
+/// use enderecobr_rs::separar_complemento;
+/// assert_eq!(
+///     separar_complemento("QD1 LT2"),
+///     vec![
+///         UnidadeComplemento { tipo: TipoComplemento::Quadra, valor: "1".to_string() },
+///         UnidadeComplemento { tipo: TipoComplemento::Lote, valor: "2".to_string() },
+///     ]
+/// );
+///
+/// let unidades = separar_complemento("apto 302 bl b fundos");
+/// let tipos: Vec<_> = unidades.iter().map(|u| u.tipo).collect();
+/// assert_eq!(tipos, [TipoComplemento::Apartamento, TipoComplemento::Bloco, TipoComplemento::Fundos]);
+/// ```
+///
+/// # Detalhes
+/// O complemento é padronizado com [padronizar_complementos] e então percorrido palavra a
+/// palavra: cada tipo reconhecido recebe como valor a palavra seguinte (ou a anterior, como em
+/// "2O ANDAR"), sendo que o andar fica só com o número. Os trechos não reconhecidos são agrupados em unidades do tipo
+/// [TipoComplemento::Outro], na ordem em que aparecem.
+///
+pub fn separar_complemento(valor: &str) -> Vec<UnidadeComplemento> {
+    let padronizado = padronizar_complementos(valor);
+    let palavras: Vec<&str> = padronizado.split_whitespace().collect();
+
+    let mut unidades: Vec<UnidadeComplemento> = Vec::new();
+    let mut outros: Vec<&str> = Vec::new();
+    let mut i = 0;
+
+    while i < palavras.len() {
+        let Some(tipo) = TipoComplemento::de_nome(palavras[i]) else {
+            outros.push(palavras[i]);
+            i += 1;
+            continue;
+        };
+
+        let proxima = palavras
+            .get(i + 1)
+            .filter(|p| TipoComplemento::de_nome(p).is_none());
+
+        let valor = match proxima {
+            // Fundos e cobertura não costumam ser seguidos de um identificador.
+            Some(p) if !matches!(tipo, TipoComplemento::Fundos | TipoComplemento::Cobertura) => {
+                i += 1;
+                p.to_string()
+            }
+            // Valor antes do tipo, como em "2O ANDAR" ou "3 BLOCO".
+            _ if outros.len() == 1 && outros[0].starts_with(|c: char| c.is_ascii_digit()) => {
+                outros.pop().unwrap_or_default().to_string()
+            }
+            _ => String::new(),
+        };
+        // O andar é identificado só pelo número, seja em "ANDAR 2" ou em "2O ANDAR".
+        let valor = match tipo {
+            TipoComplemento::Andar if valor.starts_with(|c: char| c.is_ascii_digit()) => {
+                valor.trim_end_matches('O').to_string()
+            }
+            _ => valor,
+        };
+
+        if !outros.is_empty() {
+            unidades.push(UnidadeComplemento {
+                tipo: TipoComplemento::Outro,
+                valor: outros.join(" "),
+            });
+            outros.clear();
+        }
+
+        unidades.push(UnidadeComplemento { tipo, valor });
+        i += 1;
+    }
+
+    if !outros.is_empty() {
+        unidades.push(UnidadeComplemento {
+            tipo: TipoComplemento::Outro,
+            valor: outros.join(" "),
+        });
+    }
+
+    unidades
+}
+
+/// Padroniza uma string representando complementos de logradouros, reordenando suas unidades
+/// em uma ordem canônica.
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::complemento::padronizar_complementos_ordenados;
+/// assert_eq!(
+///     padronizar_complementos_ordenados("APTO 12 BLOCO B TORRE 2"),
+///     "TORRE 2 BLOCO B APARTAMENTO 12"
+/// );
+/// assert_eq!(padronizar_complementos_ordenados("sala 3 2o andar"), "ANDAR 2 SALA 3");
+/// assert_eq!(padronizar_complementos_ordenados("CS 3 LT 2 QD 1"), "QUADRA 1 LOTE 2 CASA 3");
+/// ```
+///
+/// # Detalhes
+/// Além das operações de [padronizar_complementos], as unidades obtidas com
+/// [separar_complemento] são ordenadas conforme a ordem de [TipoComplemento]: CONDOMINIO,
+/// CONJUNTO, TORRE, BLOCO, QUADRA, LOTE, CASA, ANDAR, APARTAMENTO, COBERTURA, SALA, LOJA e
+/// FUNDOS. Unidades do mesmo tipo mantêm a ordem original, e os trechos não reconhecidos vão
+/// para o fim, também na ordem original.
+///
+/// Assim, dois complementos que descrevem a mesma unidade com os componentes em ordens
+/// diferentes resultam no mesmo texto.
+///
+pub fn padronizar_complementos_ordenados(valor: &str) -> String {
+    let mut unidades = separar_complemento(valor);
+    // sort_by_key é estável, o que preserva a ordem original dentro de cada tipo.
+    unidades.sort_by_key(|u| u.tipo);
+    unidades.iter().map(|u| u.to_string()).join(" ")
 }
 
 #[cfg(test)]
@@ -289,6 +502,77 @@ mod tests {
     #[test]
     fn padroniza_corretamente() {
         assert_eq!(padronizar_complementos("qd 5 bl 7"), "QUADRA 5 BLOCO 7");
+        // SL pode ser sobreloja ou sala.
+        assert_eq!(padronizar_complementos("SL 3"), "SL 3");
+        assert_eq!(padronizar_complementos("2º ANDAR"), "2O ANDAR");
+        assert_eq!(padronizar_complementos("SEGUNDO ANDAR"), "2O ANDAR");
         assert_eq!(padronizar_complementos(""), "");
     }
+
+    #[test]
+    fn separa_complementos() {
+        use TipoComplemento::*;
+
+        let casos: Vec<(&str, Vec<(TipoComplemento, &str)>)> = vec![
+            ("QD1 LT2 CS3", vec![(Quadra, "1"), (Lote, "2"), (Casa, "3")]),
+            ("BL A APTO 101", vec![(Bloco, "A"), (Apartamento, "101")]),
+            (
+                "APTO 12 BLOCO B TORRE 2",
+                vec![(Apartamento, "12"), (Bloco, "B"), (Torre, "2")],
+            ),
+            ("SALA 3 2O ANDAR", vec![(Sala, "3"), (Andar, "2")]),
+            ("SALA 3 ANDAR 2", vec![(Sala, "3"), (Andar, "2")]),
+            ("2º ANDAR", vec![(Andar, "2")]),
+            ("LJ 4 FUNDOS", vec![(Loja, "4"), (Fundos, "")]),
+            (
+                "CASA 2 PROXIMO AO MERCADO",
+                vec![(Casa, "2"), (Outro, "PROXIMO AO MERCADO")],
+            ),
+            (
+                "EDIFICIO AURORA APTO 5",
+                vec![(Outro, "EDIFICIO AURORA"), (Apartamento, "5")],
+            ),
+            ("", vec![]),
+        ];
+
+        for (entrada, esperado) in casos {
+            let esperado: Vec<UnidadeComplemento> = esperado
+                .into_iter()
+                .map(|(tipo, valor)| UnidadeComplemento {
+                    tipo,
+                    valor: valor.to_string(),
+                })
+                .collect();
+            assert_eq!(separar_complemento(entrada), esperado, "{}", entrada);
+        }
+    }
+
+    #[test]
+    fn ordena_complementos() {
+        let casos = [
+            ("APTO 12 BLOCO B TORRE 2", "TORRE 2 BLOCO B APARTAMENTO 12"),
+            ("BLOCO B TORRE 2 APTO 12", "TORRE 2 BLOCO B APARTAMENTO 12"),
+            ("SALA 3 ANDAR 2", "ANDAR 2 SALA 3"),
+            ("2O ANDAR SALA 3", "ANDAR 2 SALA 3"),
+            ("LJ 4 FUNDOS CASA 1", "CASA 1 LOJA 4 FUNDOS"),
+            (
+                "AP 101 BL C LT 3 QD 7",
+                "BLOCO C QUADRA 7 LOTE 3 APARTAMENTO 101",
+            ),
+            ("PROXIMO AO MERCADO CASA 2", "CASA 2 PROXIMO AO MERCADO"),
+            ("QUADRA 1 LOTE 2", "QUADRA 1 LOTE 2"),
+            ("", ""),
+        ];
+
+        for (entrada, esperado) in casos {
+            assert_eq!(padronizar_complementos_ordenados(entrada), esperado);
+        }
+    }
+
+    #[test]
+    fn formata_unidades() {
+        let unidades = separar_complemento("qd 5 lt 7 fundos");
+        let texto: Vec<String> = unidades.iter().map(|u| u.to_string()).collect();
+        assert_eq!(texto, ["QUADRA 5", "LOTE 7", "FUNDOS"]);
+    }
 }
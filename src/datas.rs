@@ -0,0 +1,307 @@
+use std::{collections::HashMap, sync::LazyLock};
+
+use itertools::Itertools;
+use regex::{Captures, Regex};
+
+use crate::{
+    normalizar, numero_extenso::numero_por_extenso, tipo_logradouro::validar_tipo_logradouro,
+};
+
+/// Forma do dia nas datas usadas como nome de logradouros e bairros.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FormatoData {
+    /// Dia em algarismos, como "7 DE SETEMBRO".
+    #[default]
+    Numerico,
+    /// Dia por extenso, como "SETE DE SETEMBRO". O dia 1 é escrito "PRIMEIRO".
+    PorExtenso,
+}
+
+/// Opções da padronização de datas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct OpcoesData {
+    /// Forma do dia na data padronizada.
+    pub formato: FormatoData,
+    /// Reconhece a forma numérica "DD/MM", como em "RUA 07/09". Desativada por padrão, já que
+    /// "RUA 10/10" também pode ser uma numeração.
+    pub numericas: bool,
+}
+
+const MESES: [&str; 12] = [
+    "JANEIRO",
+    "FEVEREIRO",
+    "MARCO",
+    "ABRIL",
+    "MAIO",
+    "JUNHO",
+    "JULHO",
+    "AGOSTO",
+    "SETEMBRO",
+    "OUTUBRO",
+    "NOVEMBRO",
+    "DEZEMBRO",
+];
+
+// Tipos de logradouro em que "DD/MM" costuma ser uma numeração, e não uma data.
+const TIPOS_NUMERADOS: [&str; 6] = ["QUADRA", "BLOCO", "CONJUNTO", "MODULO", "SETOR", "UNIDADE"];
+
+// Em Rust, a constant é criada durante a compilação, então só posso chamar funções muito restritas
+// quando uso `const`. Nesse caso, tenho que usar static com inicialização Lazy.
+static DIAS_POR_EXTENSO: LazyLock<HashMap<String, u32>> = LazyLock::new(criar_dias_por_extenso);
+static REGEX_DATA: LazyLock<Regex> = LazyLock::new(criar_regex_data);
+static REGEX_DATA_NUMERICA: LazyLock<Regex> = LazyLock::new(criar_regex_data_numerica);
+
+/// Mapeia os dias por extenso ("UM", "SETE", "VINTE E UM", "PRIMEIRO") para o seu número.
+pub fn criar_dias_por_extenso() -> HashMap<String, u32> {
+    let mut dias: HashMap<String, u32> = (1..=31)
+        .map(|dia| (numero_por_extenso(dia as i32).into_owned(), dia))
+        .collect();
+    dias.insert("PRIMEIRO".to_string(), 1);
+    dias
+}
+
+#[allow(clippy::expect_used)]
+fn criar_regex_data() -> Regex {
+    // Os dias mais longos vêm primeiro, para que "VINTE E UM" não seja lido como "VINTE".
+    let dias = DIAS_POR_EXTENSO
+        .keys()
+        .sorted_by_key(|d| std::cmp::Reverse(d.len()))
+        .join("|");
+    // Abreviações dos meses: as três primeiras letras, seguidas opcionalmente do restante.
+    let meses = MESES
+        .iter()
+        .map(|m| format!("{}(?:{})?", &m[..3], &m[3..]))
+        .join("|");
+
+    Regex::new(&format!(
+        r"\b(?:(\d{{1,2}})O?|({dias}))\.? (?:(DE?) )?({meses})\b(\.)?"
+    ))
+    .expect("Regex de datas inválida (bug interno)")
+}
+
+#[allow(clippy::expect_used)]
+fn criar_regex_data_numerica() -> Regex {
+    Regex::new(r"^(?:([A-Z]+) )?(\d{1,2})/(\d{1,2})$")
+        .expect("Regex de datas inválida (bug interno)")
+}
+
+fn formatar_data(dia: u32, mes: u32, formato: FormatoData) -> Option<String> {
+    if !(1..=31).contains(&dia) {
+        return None;
+    }
+    let mes = MESES.get(mes.checked_sub(1)? as usize)?;
+
+    Some(match formato {
+        FormatoData::Numerico => format!("{} DE {}", dia, mes),
+        FormatoData::PorExtenso if dia == 1 => format!("PRIMEIRO DE {}", mes),
+        FormatoData::PorExtenso => format!("{} DE {}", numero_por_extenso(dia as i32), mes),
+    })
+}
+
+fn mes_por_abreviacao(abreviacao: &str) -> Option<u32> {
+    let posicao = MESES.iter().position(|m| abreviacao.starts_with(&m[..3]))?;
+    Some(posicao as u32 + 1)
+}
+
+/// Padroniza as datas presentes em nomes de logradouros e bairros, como "RUA 7 DE SETEMBRO",
+/// para uma forma canônica.
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::datas::{padronizar_datas, FormatoData};
+/// assert_eq!(padronizar_datas("R. 7 SET.", FormatoData::Numerico), "R. 7 DE SETEMBRO");
+/// assert_eq!(padronizar_datas("RUA SETE DE SETEMBRO", FormatoData::Numerico), "RUA 7 DE SETEMBRO");
+/// assert_eq!(padronizar_datas("RUA 7 DE SETEMBRO", FormatoData::PorExtenso), "RUA SETE DE SETEMBRO");
+/// assert_eq!(padronizar_datas("AV 1O DE MAIO", FormatoData::PorExtenso), "AV PRIMEIRO DE MAIO");
+/// assert_eq!(padronizar_datas("RUA 5 MAR", FormatoData::Numerico), "RUA 5 DE MARCO");
+/// assert_eq!(padronizar_datas("RUA 5 MAR AZUL", FormatoData::Numerico), "RUA 5 MAR AZUL");
+/// assert_eq!(padronizar_datas("RUA 10/10", FormatoData::Numerico), "RUA 10/10");
+/// ```
+///
+/// # Detalhes
+/// Equivale a [padronizar_datas_com] com o formato informado e sem reconhecer a forma numérica
+/// "DD/MM".
+///
+pub fn padronizar_datas(valor: &str, formato: FormatoData) -> String {
+    padronizar_datas_com(
+        valor,
+        &OpcoesData {
+            formato,
+            ..Default::default()
+        },
+    )
+}
+
+/// Padroniza as datas presentes em nomes de logradouros e bairros, como "RUA 7 DE SETEMBRO",
+/// para uma forma canônica, conforme as opções informadas.
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::datas::{padronizar_datas_com, OpcoesData};
+/// let opcoes = OpcoesData {
+///     numericas: true,
+///     ..Default::default()
+/// };
+/// assert_eq!(padronizar_datas_com("RUA 07/09", &opcoes), "RUA 7 DE SETEMBRO");
+/// assert_eq!(padronizar_datas_com("QUADRA 10/12", &opcoes), "QUADRA 10/12");
+/// ```
+///
+/// # Detalhes
+/// São reconhecidas as datas com o dia em algarismos ("7", "07", "1O") ou por extenso ("SETE",
+/// "VINTE E UM", "PRIMEIRO"), seguido do mês por extenso, com ou sem o "DE" entre eles. O mês
+/// abreviado ("SET") só é reconhecido após um "DE", seguido de ponto ("SET.") ou no fim do texto
+/// após um dia em algarismos ("R. 7 SET"), já que "MAR", "SET", "OUT" e "DEZ" também são
+/// palavras. Dias fora do intervalo de 1 a 31 ficam inalterados.
+///
+/// A forma numérica "DD/MM" só é reconhecida com `numericas` verdadeiro, e quando é o nome
+/// inteiro, opcionalmente precedido de um tipo de logradouro que não costuma ser numerado (como
+/// "QUADRA"), como em "RUA 07/09". Em outras posições ela pode ser, por exemplo, um número de
+/// quadra e lote.
+///
+pub fn padronizar_datas_com(valor: &str, opcoes: &OpcoesData) -> String {
+    let valor = normalizar(valor);
+    let formato = opcoes.formato;
+
+    if let Some(captura) = REGEX_DATA_NUMERICA
+        .captures(&valor)
+        .filter(|_| opcoes.numericas)
+    {
+        let tipo = captura.get(1).map(|m| m.as_str());
+        let dia = captura[2].parse().ok();
+        let mes = captura[3].parse().ok();
+
+        if tipo.map_or(true, |t| {
+            validar_tipo_logradouro(t) && !TIPOS_NUMERADOS.contains(&t)
+        }) {
+            if let Some(data) = dia.zip(mes).and_then(|(d, m)| formatar_data(d, m, formato)) {
+                return match tipo {
+                    Some(tipo) => format!("{} {}", tipo, data),
+                    None => data,
+                };
+            }
+        }
+    }
+
+    REGEX_DATA
+        .replace_all(&valor, |captura: &Captures| {
+            let inteira = captura.get(0).map_or(0..0, |m| m.range());
+            let dia = match (captura.get(1), captura.get(2)) {
+                (Some(numero), _) => numero.as_str().parse().ok(),
+                (_, Some(extenso)) => DIAS_POR_EXTENSO.get(extenso.as_str()).copied(),
+                _ => None,
+            };
+            // O mês abreviado precisa do "DE" antes, do ponto depois ou de um dia em algarismos no
+            // fim do nome, como em "R. 7 SET", fora dos tipos numerados ("QUADRA 3 SET").
+            let abreviado = !MESES.contains(&&captura[4]);
+            let no_fim = captura.get(1).is_some()
+                && inteira.end == valor.len()
+                && !valor[..inteira.start]
+                    .split_whitespace()
+                    .last()
+                    .is_some_and(|anterior| TIPOS_NUMERADOS.contains(&anterior));
+            if abreviado && captura.get(3).is_none() && captura.get(5).is_none() && !no_fim {
+                return captura[0].to_string();
+            }
+            let mes = mes_por_abreviacao(&captura[4]);
+
+            dia.zip(mes)
+                .and_then(|(d, m)| formatar_data(d, m, formato))
+                .unwrap_or_else(|| captura[0].to_string())
+        })
+        .into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn padroniza_forma_numerica() {
+        let casos = [
+            ("R. 7 SET.", "R. 7 DE SETEMBRO"),
+            ("RUA 7 DE SET", "RUA 7 DE SETEMBRO"),
+            ("RUA 7 SETEMBRO", "RUA 7 DE SETEMBRO"),
+            ("RUA 7 DE SETEMBRO", "RUA 7 DE SETEMBRO"),
+            ("RUA SETE DE SETEMBRO", "RUA 7 DE SETEMBRO"),
+            ("RUA 7 D SET.", "RUA 7 DE SETEMBRO"),
+            ("RUA VINTE E UM DE ABRIL", "RUA 21 DE ABRIL"),
+            ("AVENIDA PRIMEIRO DE MAIO", "AVENIDA 1 DE MAIO"),
+            ("AVENIDA 1O DE MAIO", "AVENIDA 1 DE MAIO"),
+            ("PRACA 15 NOV.", "PRACA 15 DE NOVEMBRO"),
+            ("R. 7 SET", "R. 7 DE SETEMBRO"),
+            ("RUA 5 MAR", "RUA 5 DE MARCO"),
+            ("rua 13 de maio", "RUA 13 DE MAIO"),
+        ];
+
+        for (entrada, esperado) in casos {
+            assert_eq!(padronizar_datas(entrada, FormatoData::Numerico), esperado);
+        }
+    }
+
+    #[test]
+    fn padroniza_forma_por_extenso() {
+        let casos = [
+            ("R. 7 SET.", "R. SETE DE SETEMBRO"),
+            ("RUA 21 DE ABRIL", "RUA VINTE E UM DE ABRIL"),
+            ("AVENIDA 1 DE MAIO", "AVENIDA PRIMEIRO DE MAIO"),
+            ("RUA PRIMEIRO DE MAIO", "RUA PRIMEIRO DE MAIO"),
+        ];
+
+        for (entrada, esperado) in casos {
+            assert_eq!(padronizar_datas(entrada, FormatoData::PorExtenso), esperado);
+        }
+    }
+
+    #[test]
+    fn padroniza_forma_barra() {
+        let opcoes = OpcoesData {
+            numericas: true,
+            ..Default::default()
+        };
+        let casos = [
+            ("RUA 07/09", "RUA 7 DE SETEMBRO"),
+            ("07/09", "7 DE SETEMBRO"),
+            ("RUA 10/10", "RUA 10 DE OUTUBRO"),
+            ("QUADRA 10/12", "QUADRA 10/12"),
+            ("RUA 10/13", "RUA 10/13"),
+        ];
+
+        for (entrada, esperado) in casos {
+            assert_eq!(padronizar_datas_com(entrada, &opcoes), esperado);
+        }
+
+        let opcoes = OpcoesData {
+            formato: FormatoData::PorExtenso,
+            numericas: true,
+        };
+        assert_eq!(
+            padronizar_datas_com("RUA 07/09", &opcoes),
+            "RUA SETE DE SETEMBRO"
+        );
+    }
+
+    #[test]
+    fn preserva_nao_datas() {
+        let casos = [
+            "RUA 32 DE MAIO",
+            "QUADRA 10/13",
+            "QUADRA 10/12",
+            "QUADRA 10/12 LOTE 5",
+            "RUA SETEMBRINO",
+            "RUA 100 SETEMBRO",
+            "RUA DEZ",
+            "SETOR 5 SETE",
+            "RUA 10/10",
+            "RUA 07/09",
+            "RUA 5 MAR AZUL",
+            "TRAVESSA 2 OUT X",
+            "QUADRA 3 SET",
+            "RUA 1 DEZ BAR",
+            "RUA SETE SET",
+        ];
+
+        for entrada in casos {
+            assert_eq!(padronizar_datas(entrada, FormatoData::Numerico), entrada);
+        }
+    }
+}
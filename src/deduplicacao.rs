@@ -0,0 +1,489 @@
+use crate::{
+    blocagem::{
+        agrupar_blocos, chave_municipio, chaves_blocagem_lote, digitos_cep, nao_vazio,
+        ReceitaBlocagem, RegistroEndereco,
+    },
+    padronizar_estados_para_sigla,
+    similaridade::{similaridade_padronizados, PesosSimilaridade, Similaridade},
+};
+
+/// Opções da deduplicação de endereços, vide [agrupar_enderecos].
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpcoesDeduplicacao {
+    /// Receitas das chaves de blocagem. Só os pares com alguma chave em comum são comparados.
+    pub receitas: Vec<ReceitaBlocagem>,
+    /// Pesos usados no cálculo da similaridade total entre dois endereços.
+    pub pesos: PesosSimilaridade,
+    /// Similaridade total mínima para que dois endereços sejam considerados o mesmo lugar.
+    pub limiar: f64,
+    /// Similaridade mínima entre os logradouros. Evita que endereços de logradouros diferentes
+    /// sejam unidos pela força dos demais campos. Registros sem logradouro nunca são unidos a
+    /// outros.
+    pub limiar_logradouro: f64,
+    /// Blocos maiores que este tamanho são ignorados, já que a quantidade de pares cresce com o
+    /// quadrado do tamanho do bloco e blocos muito grandes costumam vir de chaves pouco
+    /// informativas.
+    pub tamanho_maximo_bloco: usize,
+}
+
+impl Default for OpcoesDeduplicacao {
+    fn default() -> Self {
+        OpcoesDeduplicacao {
+            receitas: ReceitaBlocagem::padrao(),
+            pesos: PesosSimilaridade::default(),
+            limiar: 0.9,
+            limiar_logradouro: 0.85,
+            tamanho_maximo_bloco: 1_000,
+        }
+    }
+}
+
+impl OpcoesDeduplicacao {
+    fn mesmo_lugar(&self, similaridade: &Similaridade) -> bool {
+        similaridade.total >= self.limiar
+            && similaridade
+                .logradouro
+                .is_some_and(|l| l >= self.limiar_logradouro)
+    }
+}
+
+// Estado, município e CEP padronizados de um registro ou de um grupo. Dois grupos só podem ser
+// unidos se não divergirem em nenhum deles.
+#[derive(Debug, Default, PartialEq)]
+struct Localizacao {
+    estado: Option<String>,
+    municipio: Option<String>,
+    cep: Option<String>,
+}
+
+impl Localizacao {
+    fn new(registro: &RegistroEndereco) -> Self {
+        let estado = nao_vazio(&registro.estado);
+        Localizacao {
+            estado: estado.map(|e| padronizar_estados_para_sigla(e).to_string()),
+            municipio: nao_vazio(&registro.municipio).map(|m| chave_municipio(m, estado)),
+            cep: nao_vazio(&registro.cep).map(digitos_cep),
+        }
+    }
+
+    fn compativel(&self, outra: &Localizacao) -> bool {
+        let iguais = |a: &Option<String>, b: &Option<String>| match (a, b) {
+            (Some(a), Some(b)) => a == b,
+            _ => true,
+        };
+        iguais(&self.estado, &outra.estado)
+            && iguais(&self.municipio, &outra.municipio)
+            && iguais(&self.cep, &outra.cep)
+    }
+
+    // Completa os campos ausentes com os do outro grupo, para que um registro sem CEP, por
+    // exemplo, não sirva de ponte entre registros com CEPs diferentes.
+    fn completar(&mut self, outra: Localizacao) {
+        self.estado = self.estado.take().or(outra.estado);
+        self.municipio = self.municipio.take().or(outra.municipio);
+        self.cep = self.cep.take().or(outra.cep);
+    }
+}
+
+// Estrutura union-find, que une os pares de registros em grupos. A localização de cada grupo
+// fica na posição da sua raiz.
+struct Grupos {
+    pais: Vec<usize>,
+    localizacoes: Vec<Localizacao>,
+}
+
+impl Grupos {
+    fn new(localizacoes: Vec<Localizacao>) -> Self {
+        Grupos {
+            pais: (0..localizacoes.len()).collect(),
+            localizacoes,
+        }
+    }
+
+    fn compativeis(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.raiz(a), self.raiz(b));
+        a != b && self.localizacoes[a].compativel(&self.localizacoes[b])
+    }
+
+    fn raiz(&mut self, mut i: usize) -> usize {
+        while self.pais[i] != i {
+            self.pais[i] = self.pais[self.pais[i]];
+            i = self.pais[i];
+        }
+        i
+    }
+
+    fn unir(&mut self, a: usize, b: usize) {
+        let (a, b) = (self.raiz(a), self.raiz(b));
+        // O menor índice vira a raiz, para que o identificador do grupo seja determinístico.
+        let (raiz, filho) = if a < b { (a, b) } else { (b, a) };
+        self.pais[filho] = raiz;
+        let localizacao = std::mem::take(&mut self.localizacoes[filho]);
+        self.localizacoes[raiz].completar(localizacao);
+    }
+}
+
+/// Agrupa os registros que se referem ao mesmo lugar, retornando o identificador do grupo de
+/// cada registro: o índice do primeiro registro do grupo.
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::Endereco;
+/// use enderecobr_rs::blocagem::RegistroEndereco;
+/// use enderecobr_rs::deduplicacao::{agrupar_enderecos, OpcoesDeduplicacao};
+///
+/// let registro = |logradouro: &str, numero: &str, cep: &str| RegistroEndereco {
+///     endereco: Endereco {
+///         logradouro: Some(logradouro.to_string()),
+///         numero: Some(numero.to_string()),
+///         ..Default::default()
+///     },
+///     estado: Some("RJ".to_string()),
+///     municipio: Some("RIO DE JANEIRO".to_string()),
+///     cep: Some(cep.to_string()),
+/// };
+///
+/// let registros = vec![
+///     registro("R. Souza Lima", "210", "22081-010"),
+///     registro("AV ATLANTICA", "1702", "22021-001"),
+///     registro("RUA SOUSA LIMA", "0210", "22081010"),
+///     registro("RUA SOUZA LIMA", "212", "22081-010"),
+/// ];
+///
+/// let grupos = agrupar_enderecos(&registros, &OpcoesDeduplicacao::default());
+/// assert_eq!(grupos, vec![0, 1, 0, 3]);
+/// ```
+///
+/// # Detalhes
+/// Os registros são padronizados uma única vez e as chaves de blocagem de cada receita são
+/// geradas com [chaves_blocagem_lote]. Cada par de registros com alguma chave em comum é
+/// comparado com a similaridade de [crate::similaridade], e os pares acima dos limiares são
+/// unidos. Os grupos são o fecho transitivo dessas uniões: se A é igual a B e B é igual a C, os
+/// três ficam no mesmo grupo, mesmo que A e C não tenham sido comparados.
+///
+/// Grupos com estado, município ou CEP diferentes nunca são unidos, assim como pares em que algum
+/// dos registros não tem logradouro.
+///
+pub fn agrupar_enderecos(
+    registros: &[RegistroEndereco],
+    opcoes: &OpcoesDeduplicacao,
+) -> Vec<usize> {
+    let chaves = chaves_blocagem_lote(registros, &opcoes.receitas);
+    agrupar_com_chaves(registros, &chaves, opcoes)
+}
+
+// Agrupa os registros com as chaves de blocagem já calculadas, uma coluna por receita.
+fn agrupar_com_chaves(
+    registros: &[RegistroEndereco],
+    chaves: &[Vec<Option<String>>],
+    opcoes: &OpcoesDeduplicacao,
+) -> Vec<usize> {
+    let padronizados: Vec<_> = registros
+        .iter()
+        .map(|r| r.endereco.endereco_padronizado())
+        .collect();
+    let mut grupos = Grupos::new(registros.iter().map(Localizacao::new).collect());
+
+    for chaves in chaves {
+        for bloco in agrupar_blocos(chaves) {
+            if bloco.len() > opcoes.tamanho_maximo_bloco {
+                continue;
+            }
+
+            for (posicao, &a) in bloco.iter().enumerate() {
+                for &b in &bloco[posicao + 1..] {
+                    // Pares já unidos, inclusive por blocos de outras receitas, não precisam ser
+                    // comparados de novo.
+                    if !grupos.compativeis(a, b) {
+                        continue;
+                    }
+
+                    let similaridade = similaridade_padronizados(
+                        &padronizados[a],
+                        &padronizados[b],
+                        &opcoes.pesos,
+                    );
+                    if opcoes.mesmo_lugar(&similaridade) {
+                        grupos.unir(a, b);
+                    }
+                }
+            }
+        }
+    }
+
+    (0..registros.len()).map(|i| grupos.raiz(i)).collect()
+}
+
+#[cfg(feature = "polars")]
+pub use lote_polars::{deduplicar_dataframe, deduplicar_para_parquet, deduplicar_parquet};
+
+#[cfg(feature = "polars")]
+mod lote_polars {
+    use polars::prelude::{
+        DataFrame, IntoLazy, LazyFrame, NamedFrom, ParquetCompression, ParquetWriter, PlPath,
+        PolarsResult, ScanArgsParquet, Series,
+    };
+
+    use super::{agrupar_com_chaves, OpcoesDeduplicacao};
+    use crate::blocagem::{chaves_blocagem_lote, registros_dataframe, ColunasEndereco};
+
+    /// Agrupa as linhas de um DataFrame que se referem ao mesmo lugar, vide
+    /// [super::agrupar_enderecos].
+    ///
+    /// O resultado tem as colunas originais, uma coluna por receita de blocagem com as chaves
+    /// geradas e a coluna "grupo", com o índice da primeira linha do grupo de cada linha.
+    ///
+    /// # Exemplo
+    /// ```
+    /// use enderecobr_rs::blocagem::ColunasEndereco;
+    /// use enderecobr_rs::deduplicacao::{deduplicar_dataframe, OpcoesDeduplicacao};
+    /// use polars::prelude::*;
+    ///
+    /// let df = df!(
+    ///     "logradouro" => ["R. Souza Lima", "RUA SOUSA LIMA", "AV ATLANTICA"],
+    ///     "numero" => ["210", "210", "1702"],
+    ///     "cep" => ["22081-010", "22081010", "22021-001"],
+    /// )
+    /// .unwrap();
+    /// let colunas = ColunasEndereco {
+    ///     logradouro: Some("logradouro".to_string()),
+    ///     numero: Some("numero".to_string()),
+    ///     cep: Some("cep".to_string()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let resultado =
+    ///     deduplicar_dataframe(df.lazy(), &colunas, &OpcoesDeduplicacao::default()).unwrap();
+    /// let grupos: Vec<_> = resultado.column("grupo").unwrap().u64().unwrap().iter().collect();
+    /// assert_eq!(grupos, [Some(0), Some(0), Some(2)]);
+    /// ```
+    pub fn deduplicar_dataframe(
+        df: LazyFrame,
+        colunas: &ColunasEndereco,
+        opcoes: &OpcoesDeduplicacao,
+    ) -> PolarsResult<DataFrame> {
+        let mut df = df.collect()?;
+        let registros = registros_dataframe(df.clone().lazy(), colunas)?;
+
+        let chaves = chaves_blocagem_lote(&registros, &opcoes.receitas);
+        for (receita, chaves) in opcoes.receitas.iter().zip(&chaves) {
+            df.with_column(Series::new(receita.nome.as_str().into(), chaves))?;
+        }
+
+        let grupos: Vec<u64> = agrupar_com_chaves(&registros, &chaves, opcoes)
+            .into_iter()
+            .map(|g| g as u64)
+            .collect();
+
+        df.with_column(Series::new("grupo".into(), grupos))?;
+        Ok(df)
+    }
+
+    /// Lê um arquivo Parquet, agrupa as linhas com [deduplicar_dataframe] e grava o resultado
+    /// em outro arquivo Parquet.
+    ///
+    /// Note que, para comparar os pares, todo o arquivo é carregado em memória.
+    pub fn deduplicar_parquet(
+        entrada: &str,
+        saida: &str,
+        colunas: &ColunasEndereco,
+        opcoes: &OpcoesDeduplicacao,
+    ) -> PolarsResult<()> {
+        let df = LazyFrame::scan_parquet(PlPath::new(entrada), ScanArgsParquet::default())?;
+        deduplicar_para_parquet(df, saida, colunas, opcoes)?;
+        Ok(())
+    }
+
+    /// Agrupa as linhas de um DataFrame com [deduplicar_dataframe] e grava o resultado em um
+    /// arquivo Parquet. Retorna também o resultado, já gravado.
+    ///
+    /// Útil quando o DataFrame de entrada precisa ser preparado antes, por exemplo limitando o
+    /// número de linhas.
+    pub fn deduplicar_para_parquet(
+        df: LazyFrame,
+        saida: &str,
+        colunas: &ColunasEndereco,
+        opcoes: &OpcoesDeduplicacao,
+    ) -> PolarsResult<DataFrame> {
+        let mut resultado = deduplicar_dataframe(df, colunas, opcoes)?;
+
+        let arquivo = std::fs::File::create(saida)?;
+        ParquetWriter::new(arquivo)
+            .with_compression(ParquetCompression::Zstd(None))
+            .finish(&mut resultado)?;
+        Ok(resultado)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{blocagem::ComponenteBlocagem, Endereco};
+
+    #[test]
+    fn agrupa_transitivamente() {
+        let paulista = RegistroEndereco {
+            endereco: Endereco {
+                logradouro: Some("AV PAULISTA".to_string()),
+                numero: Some("1000".to_string()),
+                ..Default::default()
+            },
+            estado: Some("SP".to_string()),
+            municipio: Some("3550308".to_string()),
+            cep: Some("01310-100".to_string()),
+        };
+        let registros = vec![
+            paulista.clone(),
+            RegistroEndereco {
+                endereco: Endereco {
+                    logradouro: Some("AVENIDA PAULISTA".to_string()),
+                    ..paulista.endereco.clone()
+                },
+                cep: None,
+                ..paulista.clone()
+            },
+            RegistroEndereco {
+                endereco: Endereco {
+                    logradouro: Some("AV. PAULISTA".to_string()),
+                    numero: Some("01000".to_string()),
+                    ..Default::default()
+                },
+                ..paulista.clone()
+            },
+            RegistroEndereco {
+                endereco: Endereco {
+                    logradouro: Some("AVENIDA PAULISTA".to_string()),
+                    numero: Some("1001".to_string()),
+                    ..Default::default()
+                },
+                ..paulista
+            },
+            RegistroEndereco::default(),
+        ];
+        let grupos = agrupar_enderecos(&registros, &OpcoesDeduplicacao::default());
+        assert_eq!(grupos, vec![0, 0, 0, 3, 4]);
+    }
+
+    #[test]
+    fn respeita_limiares() {
+        // Apartamentos diferentes no mesmo prédio.
+        let apto_12 = RegistroEndereco {
+            endereco: Endereco {
+                logradouro: Some("RUA AUGUSTA".to_string()),
+                numero: Some("100".to_string()),
+                complemento: Some("APTO 12".to_string()),
+                ..Default::default()
+            },
+            estado: Some("SP".to_string()),
+            municipio: Some("3550308".to_string()),
+            cep: Some("01305-000".to_string()),
+        };
+        let apto_13 = RegistroEndereco {
+            endereco: Endereco {
+                logradouro: Some("R AUGUSTA".to_string()),
+                complemento: Some("APARTAMENTO 13".to_string()),
+                ..apto_12.endereco.clone()
+            },
+            ..apto_12.clone()
+        };
+        let registros = vec![apto_12, apto_13];
+
+        let opcoes = OpcoesDeduplicacao::default();
+        assert_eq!(agrupar_enderecos(&registros, &opcoes), vec![0, 1]);
+
+        let leniente = OpcoesDeduplicacao {
+            limiar: 0.8,
+            ..Default::default()
+        };
+        assert_eq!(agrupar_enderecos(&registros, &leniente), vec![0, 0]);
+
+        let bloco_pequeno = OpcoesDeduplicacao {
+            tamanho_maximo_bloco: 1,
+            ..leniente
+        };
+        assert_eq!(agrupar_enderecos(&registros, &bloco_pequeno), vec![0, 1]);
+    }
+
+    #[test]
+    fn exige_logradouros_parecidos() {
+        // Mesmo CEP e número, mas logradouros diferentes.
+        let augusta = RegistroEndereco {
+            endereco: Endereco {
+                logradouro: Some("RUA AUGUSTA".to_string()),
+                numero: Some("100".to_string()),
+                ..Default::default()
+            },
+            estado: Some("SP".to_string()),
+            municipio: Some("3550308".to_string()),
+            cep: Some("01305-000".to_string()),
+        };
+        let frei_caneca = RegistroEndereco {
+            endereco: Endereco {
+                logradouro: Some("RUA FREI CANECA".to_string()),
+                ..augusta.endereco.clone()
+            },
+            ..augusta.clone()
+        };
+        let registros = vec![augusta, frei_caneca];
+        let opcoes = OpcoesDeduplicacao {
+            receitas: vec![ReceitaBlocagem::new("cep", &[ComponenteBlocagem::Cep(8)])],
+            limiar: 0.5,
+            ..Default::default()
+        };
+        assert_eq!(agrupar_enderecos(&registros, &opcoes), vec![0, 1]);
+    }
+
+    #[test]
+    fn nao_une_sem_logradouro_nem_com_localizacao_diferente() {
+        let augusta = RegistroEndereco {
+            endereco: Endereco {
+                logradouro: Some("RUA AUGUSTA".to_string()),
+                numero: Some("100".to_string()),
+                ..Default::default()
+            },
+            estado: Some("SP".to_string()),
+            municipio: Some("3550308".to_string()),
+            cep: Some("01305-000".to_string()),
+        };
+
+        // Um registro só com o número não pode servir de ponte entre dois logradouros.
+        let sem_logradouro = RegistroEndereco {
+            endereco: Endereco {
+                logradouro: None,
+                ..augusta.endereco.clone()
+            },
+            cep: Some("01305-999".to_string()),
+            ..augusta.clone()
+        };
+        let frei_caneca = RegistroEndereco {
+            endereco: Endereco {
+                logradouro: Some("RUA FREI CANECA".to_string()),
+                ..augusta.endereco.clone()
+            },
+            cep: Some("01305-100".to_string()),
+            ..augusta.clone()
+        };
+
+        let registros = vec![augusta.clone(), sem_logradouro, frei_caneca];
+        let grupos = agrupar_enderecos(&registros, &OpcoesDeduplicacao::default());
+        assert_eq!(grupos, vec![0, 1, 2]);
+
+        // Mesmo logradouro e número, mas em outro município ou com outro CEP.
+        let campinas = RegistroEndereco {
+            municipio: Some("CAMPINAS".to_string()),
+            ..augusta.clone()
+        };
+        let outro_cep = RegistroEndereco {
+            cep: Some("01305-001".to_string()),
+            ..augusta.clone()
+        };
+        let sem_cep = RegistroEndereco {
+            cep: None,
+            ..augusta.clone()
+        };
+        let registros = vec![augusta, campinas, outro_cep, sem_cep];
+        let grupos = agrupar_enderecos(&registros, &OpcoesDeduplicacao::default());
+        // O registro sem CEP fica com o primeiro, e não une os outros dois CEPs.
+        assert_eq!(grupos, vec![0, 1, 2, 0]);
+    }
+}
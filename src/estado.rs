@@ -1,16 +1,60 @@
-use std::{collections::HashMap, sync::LazyLock};
+use std::{collections::HashMap, fmt, sync::LazyLock};
 
 use crate::{normalizar, Padronizador};
 
-// TODO: ver se essa é a melhor forma de definir essa struct
+/// Grandes regiões brasileiras, conforme divisão do IBGE.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Regiao {
+    Norte,
+    Nordeste,
+    Sudeste,
+    Sul,
+    CentroOeste,
+}
+
+impl Regiao {
+    /// Nome da região em caixa alta e sem diacríticos.
+    pub fn nome(&self) -> &'static str {
+        match self {
+            Regiao::Norte => "NORTE",
+            Regiao::Nordeste => "NORDESTE",
+            Regiao::Sudeste => "SUDESTE",
+            Regiao::Sul => "SUL",
+            Regiao::CentroOeste => "CENTRO-OESTE",
+        }
+    }
+}
 
-#[derive(Debug, Clone, Copy)]
-struct Estado {
+/// Representa uma unidade da federação, com seu código do IBGE, nome sem diacríticos, sigla e
+/// região.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Estado {
     pub codigo: &'static str,
     pub nome: &'static str,
     pub sigla: &'static str,
+    pub regiao: Regiao,
+}
+
+/// Erros retornados pela busca de estados.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErroEstado {
+    /// O valor informado está vazio ou só contém espaços.
+    Vazio,
+    /// O valor informado não corresponde a nenhum estado conhecido.
+    NaoReconhecido(String),
+}
+
+impl fmt::Display for ErroEstado {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErroEstado::Vazio => write!(f, "Estado vazio"),
+            ErroEstado::NaoReconhecido(valor) => write!(f, "Estado não reconhecido: \"{}\"", valor),
+        }
+    }
 }
 
+impl std::error::Error for ErroEstado {}
+
 // Em Rust, a constant é criada durante a compilação, então só posso chamar funções muito restritas
 // quando uso `const`. Nesse caso, como tenho uma construção complexa da struct `Padronizador`,
 // tenho que usar static com inicialização Lazy (o LazyLock aqui previne condições de corrida).
@@ -43,8 +87,122 @@ fn criar_padronizador() -> Padronizador {
     padronizador
 }
 
+static ESTADOS_MAP_LENIENTE: LazyLock<HashMap<String, &'static Estado>> =
+    LazyLock::new(criar_estado_map_leniente);
+
+fn criar_estado_map_leniente() -> HashMap<String, &'static Estado> {
+    let padronizador = &*PADRONIZADOR_LENIENTE;
+    let mut estados = HashMap::<String, &'static Estado>::with_capacity(ESTADOS.len());
+    ESTADOS.iter().for_each(|e| {
+        estados.insert(e.sigla.to_string(), e);
+        estados.insert(padronizador.padronizar(e.nome).trim().to_string(), e);
+    });
+    estados.shrink_to_fit();
+    estados
+}
+
+static PADRONIZADOR_LENIENTE: LazyLock<Padronizador> = LazyLock::new(criar_padronizador_leniente);
+
+// Aplicado depois do padronizador padrão, tanto na entrada quanto nos nomes dos estados,
+// para que as variações sejam comparadas com uma mesma chave.
+fn criar_padronizador_leniente() -> Padronizador {
+    let mut padronizador = Padronizador::default();
+
+    padronizador
+        .adicionar(r"[.,/-]", " ")
+        .adicionar(r"^ *(ESTADO|EST|UF)\b( +D(A|E|O)S?\b)?", "") // EST. DE MINAS GERAIS
+        .adicionar(r"^ *S( +CATARINA)\b", "SANTA$1") // S. CATARINA
+        .adicionar(r"^ *S\b", "SAO") // S. PAULO
+        .adicionar(r"^ *R\b", "RIO") // R. GRANDE DO SUL
+        .adicionar(r"\bG(DE|D|RD|R)?\b", "GRANDE") // RIO GDE DO SUL
+        .adicionar(r"\bD(IS|IST|ISTR)?\b", "DISTRITO") // DIST FEDERAL
+        .adicionar(r"\bFED\b", "FEDERAL")
+        .adicionar(r"\bSTA\b", "SANTA")
+        .adicionar(r"\bESP\b", "ESPIRITO")
+        .adicionar(r"\bMT?( +GROSSO)", "MATO$1")
+        .adicionar(r"\bM( +GERAIS)", "MINAS$1")
+        .adicionar(r"\bGER\b", "GERAIS")
+        // Preposições são ignoradas na comparação: RIO GRANDE SUL, RIO DE JANEIRO...
+        .adicionar(r"\b(DOS|DAS|DO|DA|DE)\b", "")
+        .adicionar(r"\s{2,}", " ");
+
+    padronizador.preparar();
+    padronizador
+}
+
 // ====== Funções Públicas =======
 
+/// Busca um estado brasileiro a partir de sua sigla, código do IBGE ou nome, aceitando
+/// variações comuns de escrita.
+///
+/// Diferente das funções `padronizar_estados_para_*`, que retornam uma string vazia quando o
+/// valor não é reconhecido, esta função diferencia valores vazios de valores desconhecidos.
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::buscar_estado;
+/// use enderecobr_rs::estado::{ErroEstado, Regiao};
+/// assert_eq!(buscar_estado("S. PAULO").map(|e| e.sigla), Ok("SP"));
+/// assert_eq!(buscar_estado("S. CATARINA").map(|e| e.sigla), Ok("SC"));
+/// assert_eq!(buscar_estado("RIO GDE DO SUL").map(|e| e.sigla), Ok("RS"));
+/// assert_eq!(buscar_estado("DIST FEDERAL").map(|e| e.sigla), Ok("DF"));
+/// assert_eq!(buscar_estado("EST. DE MINAS GERAIS").map(|e| e.sigla), Ok("MG"));
+/// assert_eq!(buscar_estado("035").map(|e| e.regiao), Ok(Regiao::Sudeste));
+/// assert_eq!(buscar_estado(" "), Err(ErroEstado::Vazio));
+/// assert_eq!(buscar_estado("me"), Err(ErroEstado::NaoReconhecido("me".to_string())));
+/// ```
+///
+/// # Detalhes
+/// Além das operações de [buscar_estado_estrito], são realizadas:
+/// - remoção de pontuação e de prefixos como "ESTADO DE", "EST." e "UF";
+/// - expansão de abreviações frequentes (S. → SAO ou SANTA, GDE → GRANDE, DIST → DISTRITO, etc.);
+/// - desconsideração de preposições (DE, DO, DA, DOS, DAS) na comparação dos nomes.
+///
+pub fn buscar_estado(valor: &str) -> Result<Estado, ErroEstado> {
+    match buscar_estado_estrito(valor) {
+        Err(ErroEstado::NaoReconhecido(_)) => {
+            let padronizador = &*PADRONIZADOR;
+            let valor_padr = padronizador.padronizar_cow(valor);
+            let chave = PADRONIZADOR_LENIENTE.padronizar(&valor_padr);
+
+            ESTADOS_MAP_LENIENTE
+                .get(chave.trim())
+                .map(|e| **e)
+                .ok_or_else(|| ErroEstado::NaoReconhecido(valor.to_string()))
+        }
+        resultado => resultado,
+    }
+}
+
+/// Busca um estado brasileiro a partir de sua sigla, código do IBGE ou nome exato (sem levar
+/// em conta acentos, caixa e espaços extras).
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::buscar_estado_estrito;
+/// use enderecobr_rs::estado::ErroEstado;
+/// assert_eq!(buscar_estado_estrito("maranhão").map(|e| e.codigo), Ok("21"));
+/// assert_eq!(buscar_estado_estrito(""), Err(ErroEstado::Vazio));
+/// assert_eq!(
+///     buscar_estado_estrito("S. PAULO"),
+///     Err(ErroEstado::NaoReconhecido("S. PAULO".to_string()))
+/// );
+/// ```
+///
+pub fn buscar_estado_estrito(valor: &str) -> Result<Estado, ErroEstado> {
+    let padronizador = &*PADRONIZADOR;
+    let valor_padr = padronizador.padronizar_cow(valor);
+
+    if valor_padr.is_empty() {
+        return Err(ErroEstado::Vazio);
+    }
+
+    let mapa = &*ESTADOS_MAP;
+    mapa.get(valor_padr.as_ref())
+        .map(|e| **e)
+        .ok_or_else(|| ErroEstado::NaoReconhecido(valor.to_string()))
+}
+
 /// Padroniza uma string representando estados brasileiros para sua sigla de duas letras.
 ///
 /// # Exemplo
@@ -147,141 +305,168 @@ pub fn padronizar_estados_para_nome(valor: &str) -> &'static str {
 
 // ============ Dados Brutos ============
 
-const ESTADOS: [Estado; 27] = [
+pub(crate) const ESTADOS: [Estado; 27] = [
     Estado {
         codigo: "11",
         nome: "RONDONIA",
         sigla: "RO",
+        regiao: Regiao::Norte,
     },
     Estado {
         codigo: "12",
         nome: "ACRE",
         sigla: "AC",
+        regiao: Regiao::Norte,
     },
     Estado {
         codigo: "13",
         nome: "AMAZONAS",
         sigla: "AM",
+        regiao: Regiao::Norte,
     },
     Estado {
         codigo: "14",
         nome: "RORAIMA",
         sigla: "RR",
+        regiao: Regiao::Norte,
     },
     Estado {
         codigo: "15",
         nome: "PARA",
         sigla: "PA",
+        regiao: Regiao::Norte,
     },
     Estado {
         codigo: "16",
         nome: "AMAPA",
         sigla: "AP",
+        regiao: Regiao::Norte,
     },
     Estado {
         codigo: "17",
         nome: "TOCANTINS",
         sigla: "TO",
+        regiao: Regiao::Norte,
     },
     Estado {
         codigo: "21",
         nome: "MARANHAO",
         sigla: "MA",
+        regiao: Regiao::Nordeste,
     },
     Estado {
         codigo: "22",
         nome: "PIAUI",
         sigla: "PI",
+        regiao: Regiao::Nordeste,
     },
     Estado {
         codigo: "23",
         nome: "CEARA",
         sigla: "CE",
+        regiao: Regiao::Nordeste,
     },
     Estado {
         codigo: "24",
         nome: "RIO GRANDE DO NORTE",
         sigla: "RN",
+        regiao: Regiao::Nordeste,
     },
     Estado {
         codigo: "25",
         nome: "PARAIBA",
         sigla: "PB",
+        regiao: Regiao::Nordeste,
     },
     Estado {
         codigo: "26",
         nome: "PERNAMBUCO",
         sigla: "PE",
+        regiao: Regiao::Nordeste,
     },
     Estado {
         codigo: "27",
         nome: "ALAGOAS",
         sigla: "AL",
+        regiao: Regiao::Nordeste,
     },
     Estado {
         codigo: "28",
         nome: "SERGIPE",
         sigla: "SE",
+        regiao: Regiao::Nordeste,
     },
     Estado {
         codigo: "29",
         nome: "BAHIA",
         sigla: "BA",
+        regiao: Regiao::Nordeste,
     },
     Estado {
         codigo: "31",
         nome: "MINAS GERAIS",
         sigla: "MG",
+        regiao: Regiao::Sudeste,
     },
     Estado {
         codigo: "32",
         nome: "ESPIRITO SANTO",
         sigla: "ES",
+        regiao: Regiao::Sudeste,
     },
     Estado {
         codigo: "33",
         nome: "RIO DE JANEIRO",
         sigla: "RJ",
+        regiao: Regiao::Sudeste,
     },
     Estado {
         codigo: "35",
         nome: "SAO PAULO",
         sigla: "SP",
+        regiao: Regiao::Sudeste,
     },
     Estado {
         codigo: "41",
         nome: "PARANA",
         sigla: "PR",
+        regiao: Regiao::Sul,
     },
     Estado {
         codigo: "42",
         nome: "SANTA CATARINA",
         sigla: "SC",
+        regiao: Regiao::Sul,
     },
     Estado {
         codigo: "43",
         nome: "RIO GRANDE DO SUL",
         sigla: "RS",
+        regiao: Regiao::Sul,
     },
     Estado {
         codigo: "50",
         nome: "MATO GROSSO DO SUL",
         sigla: "MS",
+        regiao: Regiao::CentroOeste,
     },
     Estado {
         codigo: "51",
         nome: "MATO GROSSO",
         sigla: "MT",
+        regiao: Regiao::CentroOeste,
     },
     Estado {
         codigo: "52",
         nome: "GOIAS",
         sigla: "GO",
+        regiao: Regiao::CentroOeste,
     },
     Estado {
         codigo: "53",
         nome: "DISTRITO FEDERAL",
         sigla: "DF",
+        regiao: Regiao::CentroOeste,
     },
 ];
 
@@ -305,4 +490,57 @@ mod tests {
         assert_eq!(padronizar_estados_para_sigla(""), ""); // NA
         assert_eq!(padronizar_estados_para_sigla("MARANHÃO"), "MA");
     }
+
+    #[test]
+    fn busca_variacoes() {
+        let casos = [
+            ("SP", "SP"),
+            ("sao paulo", "SP"),
+            ("ESTADO DE SAO PAULO", "SP"),
+            ("UF SP", "SP"),
+            ("R. G. DO SUL", "RS"),
+            ("RIO GRANDE SUL", "RS"),
+            ("RIO GDE DO NORTE", "RN"),
+            ("D. FEDERAL", "DF"),
+            ("M. GERAIS", "MG"),
+            ("MT GROSSO DO SUL", "MS"),
+            ("MATO GROSSO", "MT"),
+            ("STA CATARINA", "SC"),
+            ("S. CATARINA", "SC"),
+            ("S CATARINA", "SC"),
+            ("ESP. SANTO", "ES"),
+            ("EST DO RIO DE JANEIRO", "RJ"),
+        ];
+
+        for (entrada, esperado) in casos {
+            assert_eq!(buscar_estado(entrada).map(|e| e.sigla), Ok(esperado));
+        }
+    }
+
+    #[test]
+    fn busca_retorna_erros() {
+        assert_eq!(buscar_estado(""), Err(ErroEstado::Vazio));
+        assert_eq!(buscar_estado("  "), Err(ErroEstado::Vazio));
+        assert_eq!(
+            buscar_estado("ATLANTIDA"),
+            Err(ErroEstado::NaoReconhecido("ATLANTIDA".to_string()))
+        );
+        assert_eq!(
+            buscar_estado_estrito("RIO GDE DO SUL"),
+            Err(ErroEstado::NaoReconhecido("RIO GDE DO SUL".to_string()))
+        );
+    }
+
+    #[test]
+    fn obtem_regiao() {
+        assert_eq!(buscar_estado("AM").map(|e| e.regiao), Ok(Regiao::Norte));
+        assert_eq!(buscar_estado("BA").map(|e| e.regiao), Ok(Regiao::Nordeste));
+        assert_eq!(buscar_estado("RJ").map(|e| e.regiao), Ok(Regiao::Sudeste));
+        assert_eq!(buscar_estado("PR").map(|e| e.regiao), Ok(Regiao::Sul));
+        assert_eq!(
+            buscar_estado("DF").map(|e| e.regiao),
+            Ok(Regiao::CentroOeste)
+        );
+        assert_eq!(Regiao::CentroOeste.nome(), "CENTRO-OESTE");
+    }
 }
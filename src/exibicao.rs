@@ -0,0 +1,270 @@
+use std::{collections::HashMap, sync::LazyLock};
+
+use crate::{normalizar, numero_extenso::REGEX_ROMANO, tipo_logradouro::validar_tipo_logradouro};
+
+// Em Rust, a constant é criada durante a compilação, então só posso chamar funções muito restritas
+// quando uso `const`. Nesse caso, tenho que usar static com inicialização Lazy.
+static PALAVRAS_ACENTUADAS: LazyLock<HashMap<String, String>> =
+    LazyLock::new(criar_palavras_acentuadas);
+static MUNICIPIOS_ACENTUADOS: LazyLock<HashMap<String, String>> =
+    LazyLock::new(criar_municipios_acentuados);
+
+// Palavras que ficam em caixa baixa no meio do texto.
+const PARTICULAS: [&str; 8] = ["DE", "DA", "DO", "DAS", "DOS", "E", "D", "DEL"];
+
+// Partículas que também são usadas como letras, como em "RUA E" e "BLOCO D". Só ficam em caixa
+// baixa quando há uma palavra depois e a anterior não é um tipo de logradouro ou de complemento.
+const PARTICULAS_AMBIGUAS: [&str; 2] = ["E", "D"];
+
+// Palavras de complemento que costumam ser seguidas por uma letra.
+const DESIGNADORES: [&str; 9] = [
+    "BLOCO",
+    "QUADRA",
+    "LOTE",
+    "CASA",
+    "APARTAMENTO",
+    "SALA",
+    "LOJA",
+    "TORRE",
+    "SETOR",
+];
+
+// Siglas que ficam em caixa alta.
+const SIGLAS: [&str; 3] = ["KM", "CEP", "BR"];
+
+/// Cria o dicionário de palavras sem acento → palavras acentuadas (em caixa baixa).
+///
+/// As palavras dos nomes dos municípios são usadas como base e, quando uma mesma palavra
+/// aparece com grafias diferentes, prevalece a mais frequente. As palavras da lista embutida
+/// têm prioridade sobre as dos municípios.
+pub fn criar_palavras_acentuadas() -> HashMap<String, String> {
+    let municipios_csv: &str = include_str!("data/municipios.csv");
+    let mut contagem = HashMap::<String, HashMap<String, usize>>::new();
+
+    for linha in municipios_csv.lines().skip(1) {
+        let Some(nome) = linha.split(",").nth(1) else {
+            continue;
+        };
+        for palavra in nome
+            .split([' ', '-', '\''])
+            .filter(|p| p.chars().count() > 1)
+        {
+            *contagem
+                .entry(normalizar(palavra).into_owned())
+                .or_default()
+                .entry(palavra.to_lowercase())
+                .or_default() += 1;
+        }
+    }
+
+    let mut palavras: HashMap<String, String> = contagem
+        .into_iter()
+        .filter_map(|(chave, grafias)| {
+            let (grafia, _) = grafias
+                .into_iter()
+                .max_by(|a, b| a.1.cmp(&b.1).then(b.0.cmp(&a.0)))?;
+            Some((chave, grafia))
+        })
+        .collect();
+
+    let lista: &str = include_str!("data/palavras_acentuadas.txt");
+    for palavra in lista
+        .lines()
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+    {
+        palavras.insert(normalizar(palavra).into_owned(), palavra.to_string());
+    }
+
+    palavras
+}
+
+/// Cria o mapa de nomes de municípios sem acento → nomes acentuados.
+pub fn criar_municipios_acentuados() -> HashMap<String, String> {
+    let municipios_csv: &str = include_str!("data/municipios.csv");
+    municipios_csv
+        .lines()
+        .skip(1)
+        .filter_map(|linha| linha.split(",").nth(1))
+        .map(|nome| (normalizar(nome).into_owned(), nome.to_string()))
+        .collect()
+}
+
+fn capitalizar(palavra: &str) -> String {
+    let mut letras = palavra.chars();
+    match letras.next() {
+        Some(primeira) => primeira.to_uppercase().chain(letras).collect(),
+        None => String::new(),
+    }
+}
+
+// Verifica se a palavra na posição `i` é uma letra, e não uma partícula.
+fn letra_isolada(palavras: &[&str], i: usize) -> bool {
+    if !PARTICULAS_AMBIGUAS.contains(&palavras[i]) {
+        return false;
+    }
+    let anterior = i.checked_sub(1).map(|j| palavras[j]);
+    i + 1 == palavras.len()
+        || anterior.is_some_and(|p| DESIGNADORES.contains(&p) || validar_tipo_logradouro(p))
+}
+
+fn formatar_palavra(palavra: &str, primeira: bool, acentuar: bool) -> String {
+    // Números, identificadores como "BR-101" e "S/N", siglas e números romanos ficam como estão.
+    if palavra.contains(|c: char| c.is_ascii_digit() || c == '/')
+        || (palavra.len() > 1
+            && palavra.chars().all(|c| "IVXLC".contains(c))
+            && REGEX_ROMANO.is_match(palavra))
+        || SIGLAS.contains(&&*normalizar(palavra))
+    {
+        return palavra.to_string();
+    }
+
+    // Palavras compostas, como "TENENTE-CORONEL", têm cada parte formatada.
+    if palavra.contains('-') {
+        return palavra
+            .split('-')
+            .enumerate()
+            .map(|(i, parte)| formatar_palavra(parte, primeira && i == 0, acentuar))
+            .collect::<Vec<_>>()
+            .join("-");
+    }
+
+    // Contrações como "D'OESTE" e "D'AGUA".
+    if let Some((prefixo, resto)) = palavra.split_once('\'') {
+        return format!(
+            "{}'{}",
+            formatar_palavra(prefixo, primeira, acentuar),
+            formatar_palavra(resto, true, acentuar)
+        );
+    }
+
+    let minuscula = match PALAVRAS_ACENTUADAS.get(&*normalizar(palavra)) {
+        Some(acentuada) if acentuar => acentuada.clone(),
+        _ => palavra.to_lowercase(),
+    };
+
+    if !primeira && PARTICULAS.contains(&&*normalizar(palavra)) {
+        minuscula
+    } else {
+        capitalizar(&minuscula)
+    }
+}
+
+/// Formata um texto padronizado para exibição: as palavras ficam com a inicial maiúscula, as
+/// preposições ("de", "da", "do", "das", "dos", "del" e "e") em caixa baixa, e os acentos são restaurados.
+/// Letras isoladas, como em "RUA E" e "BLOCO D", continuam em caixa alta.
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::exibicao::formatar_para_exibicao;
+/// assert_eq!(
+///     formatar_para_exibicao("AVENIDA NOSSA SENHORA COPACABANA"),
+///     "Avenida Nossa Senhora Copacabana"
+/// );
+/// assert_eq!(formatar_para_exibicao("SAO PAULO"), "São Paulo");
+/// assert_eq!(formatar_para_exibicao("PRACA DA SE"), "Praça da Sé");
+/// assert_eq!(formatar_para_exibicao("RUA XV DE NOVEMBRO"), "Rua XV de Novembro");
+/// assert_eq!(formatar_para_exibicao("RODOVIA BR-101 KM 23,5"), "Rodovia BR-101 KM 23,5");
+/// assert_eq!(formatar_para_exibicao("ALTA FLORESTA D'OESTE"), "Alta Floresta d'Oeste");
+/// assert_eq!(formatar_para_exibicao("BLOCO D QUADRA E"), "Bloco D Quadra E");
+/// ```
+///
+/// # Detalhes
+/// Operações realizadas durante a formatação:
+/// - se o texto for o nome de um município, é usado o nome oficial acentuado;
+/// - caso contrário, os acentos são restaurados palavra por palavra, com base em um dicionário
+///   de palavras frequentes em endereços e nos nomes dos municípios e estados;
+/// - palavras com números (como "BR-101"), siglas (como "KM"), "S/N" e números romanos ficam
+///   inalterados;
+/// - palavras compostas ("TENENTE-CORONEL") e contrações ("D'OESTE") têm cada parte formatada.
+///
+/// Como os acentos são restaurados por dicionário, palavras fora dele ou com mais de uma grafia
+/// possível ficam com a grafia mais frequente ou sem acento.
+///
+pub fn formatar_para_exibicao(valor: &str) -> String {
+    let valor = normalizar(valor);
+
+    // Nomes de municípios têm a grafia oficial, o que evita ambiguidades palavra a palavra.
+    let (texto, acentuar) = match MUNICIPIOS_ACENTUADOS.get(&*valor) {
+        Some(nome) => (nome.as_str(), false),
+        None => (&*valor, true),
+    };
+
+    let palavras: Vec<&str> = texto.split(' ').collect();
+    palavras
+        .iter()
+        .enumerate()
+        .map(|(i, palavra)| {
+            formatar_palavra(palavra, i == 0 || letra_isolada(&palavras, i), acentuar)
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formata_corretamente() {
+        let casos = [
+            ("RUA GENERAL GLICERIO", "Rua General Glicério"),
+            ("AVENIDA JOAO PESSOA", "Avenida João Pessoa"),
+            ("RUA DOS ANDRADAS", "Rua dos Andradas"),
+            ("RUA DA CONCEICAO E SAO JOSE", "Rua da Conceição e São José"),
+            ("DE JESUS", "De Jesus"),
+            ("RUA TENENTE-CORONEL CARDOSO", "Rua Tenente-Coronel Cardoso"),
+            ("RUA OLHO D'AGUA", "Rua Olho d'Água"),
+            ("RUA DOM PEDRO II", "Rua Dom Pedro II"),
+            ("QUADRA 5 LOTE 7", "Quadra 5 Lote 7"),
+            ("APARTAMENTO 302B", "Apartamento 302B"),
+            ("S/N", "S/N"),
+            ("RUA E", "Rua E"),
+            ("BLOCO D QUADRA E", "Bloco D Quadra E"),
+            ("RUA D PEDRO II", "Rua D Pedro II"),
+            ("TRAVESSA E 5", "Travessa E 5"),
+            ("RUA ANTONIO E SILVA", "Rua Antônio e Silva"),
+            ("", ""),
+        ];
+
+        for (entrada, esperado) in casos {
+            assert_eq!(formatar_para_exibicao(entrada), esperado);
+        }
+    }
+
+    #[test]
+    fn restaura_municipios_e_estados() {
+        let casos = [
+            ("SAO JOAO DEL REI", "São João del Rei"),
+            ("MOGI DAS CRUZES", "Mogi das Cruzes"),
+            ("GOIANIA", "Goiânia"),
+            ("ESPIRITO SANTO", "Espírito Santo"),
+            ("RIO GRANDE DO NORTE", "Rio Grande do Norte"),
+            ("PIAUI", "Piauí"),
+            ("RONDONIA", "Rondônia"),
+        ];
+
+        for (entrada, esperado) in casos {
+            assert_eq!(formatar_para_exibicao(entrada), esperado);
+        }
+    }
+
+    #[test]
+    fn formata_endereco() {
+        let endereco = crate::Endereco {
+            logradouro: Some("AV. SAO JOAO".to_string()),
+            numero: Some("0010".to_string()),
+            complemento: None,
+            localidade: Some("JD. DAS ACACIAS".to_string()),
+        };
+
+        assert_eq!(
+            endereco.endereco_para_exibicao(),
+            crate::Endereco {
+                logradouro: Some("Avenida São João".to_string()),
+                numero: Some("10".to_string()),
+                complemento: None,
+                localidade: Some("Jardim das Acácias".to_string()),
+            }
+        );
+    }
+}
@@ -0,0 +1,436 @@
+use std::sync::LazyLock;
+
+use itertools::Itertools;
+
+use crate::{metaphone::metaphone, normalizar, Padronizador};
+
+/// Opções da geração de chaves fonéticas, vide [CodificadorFonetico::chaves].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OpcoesFonetica {
+    /// Mantém as vogais no código. Quando falso, só a primeira letra de cada chave é mantida caso
+    /// seja uma vogal. Se `None`, usa o padrão do algoritmo.
+    pub manter_vogais: Option<bool>,
+    /// Tamanho fixo das chaves: as maiores são truncadas e as menores são completadas com "0".
+    /// Se `None`, usa o padrão do algoritmo.
+    pub tamanho: Option<usize>,
+    /// Gera uma chave para cada palavra. Quando falso, gera uma única chave para o texto inteiro,
+    /// ignorando os espaços.
+    pub por_palavra: bool,
+    /// Descarta as preposições que ligam nomes próprios ("DE", "DA", "DO", "DAS", "DOS"), que
+    /// pouco ajudam a distinguir um endereço de outro.
+    pub remover_stopwords: bool,
+}
+
+impl Default for OpcoesFonetica {
+    fn default() -> Self {
+        OpcoesFonetica {
+            manter_vogais: None,
+            tamanho: None,
+            por_palavra: true,
+            remover_stopwords: false,
+        }
+    }
+}
+
+/// Algoritmo de codificação fonética para o português brasileiro.
+///
+/// Os implementadores só precisam codificar uma palavra; a separação das palavras e as opções de
+/// [OpcoesFonetica] são tratadas pelos métodos [chaves](Self::chaves) e [chave](Self::chave).
+pub trait CodificadorFonetico {
+    /// Gera o código fonético de uma única palavra, já em maiúsculas, sem acentos e composta
+    /// apenas por letras.
+    fn codificar_palavra(&self, palavra: &str) -> String;
+
+    /// Indica se o algoritmo mantém as vogais por padrão.
+    fn mantem_vogais(&self) -> bool {
+        true
+    }
+
+    /// Tamanho fixo das chaves geradas por padrão pelo algoritmo, se houver.
+    fn tamanho_padrao(&self) -> Option<usize> {
+        None
+    }
+
+    /// Gera as chaves fonéticas de um texto, uma por palavra ou uma para o texto inteiro.
+    ///
+    /// # Exemplo
+    /// ```
+    /// use enderecobr_rs::fonetica::{CodificadorFonetico, Metaphone, OpcoesFonetica};
+    /// let opcoes = OpcoesFonetica::default();
+    /// assert_eq!(Metaphone.chaves("Rua Chavier", &opcoes), vec!["RUA", "XAVIER"]);
+    ///
+    /// let opcoes = OpcoesFonetica {
+    ///     manter_vogais: Some(false),
+    ///     tamanho: Some(4),
+    ///     por_palavra: false,
+    ///     remover_stopwords: false,
+    /// };
+    /// assert_eq!(Metaphone.chaves("Rua Chavier", &opcoes), vec!["RXVR"]);
+    /// ```
+    fn chaves(&self, valor: &str, opcoes: &OpcoesFonetica) -> Vec<String> {
+        let palavras = palavras_foneticas(valor)
+            .into_iter()
+            .filter(|p| !opcoes.remover_stopwords || !STOPWORDS.contains(&p.as_str()))
+            .collect_vec();
+        let grupos = if opcoes.por_palavra {
+            palavras
+        } else {
+            vec![palavras.concat()]
+        };
+
+        let manter_vogais = opcoes.manter_vogais.unwrap_or(self.mantem_vogais());
+        let tamanho = opcoes.tamanho.or(self.tamanho_padrao());
+
+        grupos
+            .iter()
+            .filter(|p| !p.is_empty())
+            .map(|p| {
+                let codigo = self.codificar_palavra(p);
+                let codigo = if manter_vogais {
+                    codigo
+                } else {
+                    remover_vogais(&codigo)
+                };
+                match tamanho {
+                    Some(tamanho) => ajustar_tamanho(&codigo, tamanho),
+                    None => codigo,
+                }
+            })
+            .filter(|c| !c.is_empty())
+            .collect()
+    }
+
+    /// Gera as chaves fonéticas de um texto, separadas por espaço.
+    ///
+    /// # Exemplo
+    /// ```
+    /// use enderecobr_rs::fonetica::{BuscaBr, CodificadorFonetico, OpcoesFonetica};
+    /// let opcoes = OpcoesFonetica::default();
+    /// assert_eq!(BuscaBr.chave("Walter", &opcoes), BuscaBr.chave("Valter", &opcoes));
+    /// ```
+    fn chave(&self, valor: &str, opcoes: &OpcoesFonetica) -> String {
+        self.chaves(valor, opcoes).join(" ")
+    }
+}
+
+const STOPWORDS: [&str; 5] = ["DE", "DA", "DO", "DAS", "DOS"];
+
+// Separa o texto em palavras compostas apenas por letras maiúsculas sem acento.
+fn palavras_foneticas(valor: &str) -> Vec<String> {
+    // O Ç vira C na normalização, mas tem som de S. O apóstrofo une as palavras, como em
+    // "D'AVILA", assim como no [metaphone] original.
+    let valor: String = valor
+        .chars()
+        .filter(|c| !matches!(c, '\'' | '’'))
+        .map(|c| match c {
+            'Ç' | 'ç' => 'S',
+            c => c,
+        })
+        .collect();
+
+    normalizar(&valor)
+        .split(|c: char| !c.is_ascii_alphabetic())
+        .filter(|p| !p.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn remover_vogais(codigo: &str) -> String {
+    let mut letras = codigo.chars();
+    letras
+        .next()
+        .into_iter()
+        .chain(letras.filter(|c| !"AEIOU".contains(*c)))
+        .dedup()
+        .collect()
+}
+
+fn ajustar_tamanho(codigo: &str, tamanho: usize) -> String {
+    codigo
+        .chars()
+        .chain(std::iter::repeat('0'))
+        .take(tamanho)
+        .collect()
+}
+
+/// Codificador baseado no Metaphone-BR, vide [crate::metaphone::metaphone].
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::fonetica::{CodificadorFonetico, Metaphone};
+/// assert_eq!(Metaphone.codificar_palavra("PHILIPPE"), "FILIPE");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Metaphone;
+
+impl CodificadorFonetico for Metaphone {
+    fn codificar_palavra(&self, palavra: &str) -> String {
+        metaphone(palavra)
+    }
+}
+
+pub fn criar_padronizador_buscabr() -> Padronizador {
+    // Baseado no algoritmo BuscaBR, de Fred Jorge Tavares de Lucena. As regras são aplicadas a
+    // uma palavra por vez, por isso as terminações usam a âncora de fim de texto.
+    let mut padronizador = Padronizador::default();
+    padronizador
+        .adicionar("B[LR]", "B")
+        .adicionar("PH", "F")
+        .adicionar("GL|GR|MG|NG|RG", "G")
+        .adicionar("Y", "I")
+        .adicionar("GE|GI|RJ|MJ", "J")
+        .adicionar("CA|CO|CU|CK|Q", "K")
+        .adicionar("CE|CI|CH", "S")
+        .adicionar("CT", "T")
+        // Terminações mudas ou de pronúncia variável.
+        .adicionar("(AO|[SZRMNL])$", "")
+        .adicionar("N|RM|GM|MD|SM", "M")
+        .adicionar("PR", "P")
+        .adicionar("X|TS|C|Z|RS", "S")
+        .adicionar("LT|TR|RT|ST", "T")
+        .adicionar("W", "V")
+        .adicionar("L", "R")
+        .adicionar("H", "");
+
+    padronizador.preparar();
+    padronizador
+}
+
+static PADRONIZADOR_BUSCABR: LazyLock<Padronizador> = LazyLock::new(criar_padronizador_buscabr);
+
+/// Codificador baseado no algoritmo BuscaBR, que aproxima grafias de mesma pronúncia no
+/// português brasileiro e, por padrão, descarta as vogais após a primeira letra.
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::fonetica::{BuscaBr, CodificadorFonetico, OpcoesFonetica};
+/// let opcoes = OpcoesFonetica::default();
+/// assert_eq!(BuscaBr.chave("Brasil", &opcoes), "BS");
+/// assert_eq!(BuscaBr.chave("Cristina", &opcoes), BuscaBr.chave("Christina", &opcoes));
+/// assert_eq!(BuscaBr.chave("Thiago", &opcoes), BuscaBr.chave("Tiago", &opcoes));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BuscaBr;
+
+impl CodificadorFonetico for BuscaBr {
+    fn codificar_palavra(&self, palavra: &str) -> String {
+        PADRONIZADOR_BUSCABR
+            .padronizar(palavra)
+            .chars()
+            .dedup()
+            .collect()
+    }
+
+    fn mantem_vogais(&self) -> bool {
+        false
+    }
+}
+
+pub fn criar_padronizador_soundex() -> Padronizador {
+    // Adaptações para os dígrafos do português, aplicadas antes da codificação do Soundex.
+    let mut padronizador = Padronizador::default();
+    padronizador
+        .adicionar("^H", "")
+        .adicionar("LH", "L")
+        .adicionar("NH", "N")
+        .adicionar("CH([LR])", "K$1")
+        .adicionar("CH|SH", "X")
+        .adicionar("PH", "F")
+        .adicionar("TH", "T")
+        .adicionar("W", "V")
+        .adicionar("QU|Q", "K")
+        .adicionar("SC([EI])", "S$1")
+        .adicionar("C([EI])", "S$1")
+        .adicionar("C", "K")
+        .adicionar("G([EI])", "J$1")
+        .adicionar("SS", "S");
+
+    padronizador.preparar();
+    padronizador
+}
+
+static PADRONIZADOR_SOUNDEX: LazyLock<Padronizador> = LazyLock::new(criar_padronizador_soundex);
+
+fn digito_soundex(letra: char) -> Option<char> {
+    match letra {
+        'B' | 'F' | 'P' | 'V' => Some('1'),
+        'C' | 'G' | 'J' | 'K' | 'Q' | 'S' | 'X' | 'Z' => Some('2'),
+        'D' | 'T' => Some('3'),
+        'L' => Some('4'),
+        'M' | 'N' => Some('5'),
+        'R' => Some('6'),
+        _ => None,
+    }
+}
+
+/// Codificador Soundex adaptado ao português: a primeira letra seguida de dígitos que agrupam
+/// consoantes de som parecido, com tamanho padrão de quatro caracteres.
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::fonetica::{CodificadorFonetico, OpcoesFonetica, SoundexPt};
+/// let opcoes = OpcoesFonetica::default();
+/// assert_eq!(SoundexPt.chave("Rua Ribeiro", &opcoes), "R000 R160");
+/// assert_eq!(SoundexPt.chave("Chaves", &opcoes), SoundexPt.chave("Xaves", &opcoes));
+/// ```
+///
+/// # Detalhes
+/// Antes da codificação, o H inicial é removido e os dígrafos são simplificados (LH, NH, CH,
+/// PH, TH, QU, SC, C e G antes de E ou I), para que a primeira letra de grafias equivalentes
+/// seja a mesma, como em "CRISTINA" e "CHRISTINA". As vogais separam consoantes iguais, enquanto H, W e Y
+/// não as separam, como no Soundex original.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SoundexPt;
+
+impl CodificadorFonetico for SoundexPt {
+    fn codificar_palavra(&self, palavra: &str) -> String {
+        let palavra = PADRONIZADOR_SOUNDEX.padronizar(palavra);
+        let mut letras = palavra.chars();
+        let Some(primeira) = letras.next() else {
+            return String::new();
+        };
+
+        let mut codigo = String::from(primeira);
+        let mut anterior = digito_soundex(primeira);
+        for letra in letras {
+            let digito = digito_soundex(letra);
+            if digito.is_none() && matches!(letra, 'H' | 'W' | 'Y') {
+                continue;
+            }
+            if let Some(d) = digito.filter(|_| digito != anterior) {
+                codigo.push(d);
+            }
+            anterior = digito;
+        }
+        codigo
+    }
+
+    fn tamanho_padrao(&self) -> Option<usize> {
+        Some(4)
+    }
+}
+
+/// Algoritmos fonéticos disponíveis, para seleção em tempo de execução.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AlgoritmoFonetico {
+    #[default]
+    Metaphone,
+    BuscaBr,
+    SoundexPt,
+}
+
+impl CodificadorFonetico for AlgoritmoFonetico {
+    fn codificar_palavra(&self, palavra: &str) -> String {
+        match self {
+            AlgoritmoFonetico::Metaphone => Metaphone.codificar_palavra(palavra),
+            AlgoritmoFonetico::BuscaBr => BuscaBr.codificar_palavra(palavra),
+            AlgoritmoFonetico::SoundexPt => SoundexPt.codificar_palavra(palavra),
+        }
+    }
+
+    fn mantem_vogais(&self) -> bool {
+        match self {
+            AlgoritmoFonetico::Metaphone => Metaphone.mantem_vogais(),
+            AlgoritmoFonetico::BuscaBr => BuscaBr.mantem_vogais(),
+            AlgoritmoFonetico::SoundexPt => SoundexPt.mantem_vogais(),
+        }
+    }
+
+    fn tamanho_padrao(&self) -> Option<usize> {
+        match self {
+            AlgoritmoFonetico::Metaphone => Metaphone.tamanho_padrao(),
+            AlgoritmoFonetico::BuscaBr => BuscaBr.tamanho_padrao(),
+            AlgoritmoFonetico::SoundexPt => SoundexPt.tamanho_padrao(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chave(codificador: &dyn CodificadorFonetico, valor: &str) -> String {
+        codificador.chave(valor, &OpcoesFonetica::default())
+    }
+
+    #[test]
+    fn metaphone_preserva_algoritmo_original() {
+        let valor = "MARYA CHAVIER HELENA PHILIPE CALHEIROS MAÇÃ";
+        assert_eq!(chave(&Metaphone, valor), metaphone(valor));
+    }
+
+    #[test]
+    fn agrupa_grafias_equivalentes() {
+        let pares = [
+            ("WALTER", "VALTER"),
+            ("SOUZA", "SOUSA"),
+            ("LUIZ", "LUIS"),
+            ("GONÇALVES", "GONSALVES"),
+        ];
+        // O Metaphone-BR não trata o TH e o CHR.
+        let pares_consoantes = [("THIAGO", "TIAGO"), ("CRISTINA", "CHRISTINA")];
+
+        for codificador in [
+            AlgoritmoFonetico::Metaphone,
+            AlgoritmoFonetico::BuscaBr,
+            AlgoritmoFonetico::SoundexPt,
+        ] {
+            let extras = match codificador {
+                AlgoritmoFonetico::Metaphone => &[][..],
+                _ => &pares_consoantes[..],
+            };
+            for (a, b) in pares.iter().chain(extras) {
+                assert_eq!(
+                    chave(&codificador, a),
+                    chave(&codificador, b),
+                    "{:?}: {} e {}",
+                    codificador,
+                    a,
+                    b
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn codifica_corretamente() {
+        assert_eq!(chave(&BuscaBr, "RUA DAS FLORES"), "R D FR");
+        assert_eq!(chave(&BuscaBr, "JOÃO"), "J");
+        assert_eq!(chave(&SoundexPt, "RUA DAS FLORES"), "R000 D200 F462");
+        assert_eq!(chave(&SoundexPt, "HELENA"), "E450");
+        assert_eq!(chave(&SoundexPt, "PFISTER"), "P236");
+    }
+
+    #[test]
+    fn aplica_opcoes() {
+        let opcoes = OpcoesFonetica {
+            manter_vogais: Some(true),
+            tamanho: None,
+            por_palavra: false,
+            remover_stopwords: false,
+        };
+        assert_eq!(BuscaBr.chave("Rua das Flores", &opcoes), "RUADASFRORE");
+        assert_eq!(SoundexPt.chave("Rua das Flores", &opcoes), "R321");
+
+        let opcoes = OpcoesFonetica {
+            manter_vogais: Some(false),
+            tamanho: Some(3),
+            por_palavra: true,
+            remover_stopwords: false,
+        };
+        assert_eq!(Metaphone.chaves("Ana Maria", &opcoes), vec!["AN0", "MR0"]);
+
+        let opcoes = OpcoesFonetica {
+            remover_stopwords: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            BuscaBr.chaves("Rua dos Andradas d'Avila", &opcoes),
+            vec!["R", "AMDRD", "DVR"]
+        );
+
+        assert!(Metaphone
+            .chaves("123 --", &OpcoesFonetica::default())
+            .is_empty());
+    }
+}
@@ -0,0 +1,858 @@
+use std::{collections::HashMap, hash::Hash};
+
+use crate::{
+    blocagem::{digitos_cep, nao_vazio, ChaveMunicipio, RegistroEndereco},
+    normalizar,
+    referencia::{
+        faixa_contem, IndiceReferencia, LinhaReferencia, NivelCorrespondencia, OpcoesReferencia,
+    },
+    separar_numero,
+};
+
+/// Coordenada geográfica em graus decimais (WGS 84).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Coordenada {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+impl Coordenada {
+    pub fn new(latitude: f64, longitude: f64) -> Self {
+        Coordenada {
+            latitude,
+            longitude,
+        }
+    }
+}
+
+/// Lado do segmento ao qual pertence a faixa de números.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Paridade {
+    /// Números pares e ímpares.
+    #[default]
+    Todos,
+    Pares,
+    Impares,
+}
+
+impl Paridade {
+    /// Identifica a paridade pelo nome, em português ou inglês. As abreviações de uma letra só
+    /// são aceitas em português, já que "E" e "D", por exemplo, costumam indicar os lados
+    /// esquerdo e direito.
+    ///
+    /// # Exemplo
+    /// ```
+    /// use enderecobr_rs::geocodificacao::Paridade;
+    /// assert_eq!(Paridade::de_nome("ímpar"), Some(Paridade::Impares));
+    /// assert_eq!(Paridade::de_nome("even"), Some(Paridade::Pares));
+    /// assert_eq!(Paridade::de_nome("ambos"), Some(Paridade::Todos));
+    /// assert_eq!(Paridade::de_nome("E"), None);
+    /// assert_eq!(Paridade::de_nome("X"), None);
+    /// ```
+    pub fn de_nome(valor: &str) -> Option<Self> {
+        match normalizar(valor.trim()).as_ref() {
+            "P" | "PAR" | "PARES" | "EVEN" => Some(Paridade::Pares),
+            "I" | "IMPAR" | "IMPARES" | "ODD" => Some(Paridade::Impares),
+            "" | "T" | "TODOS" | "AMBOS" | "A" | "M" | "MISTO" | "BOTH" => Some(Paridade::Todos),
+            _ => None,
+        }
+    }
+
+    pub fn aceita(&self, numero: u32) -> bool {
+        match self {
+            Paridade::Todos => true,
+            Paridade::Pares => numero % 2 == 0,
+            Paridade::Impares => numero % 2 == 1,
+        }
+    }
+}
+
+/// Segmento de logradouro de uma base de referência, com a sua faixa de números e a sua
+/// geometria.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Segmento {
+    /// Estado, vide [LinhaReferencia::estado].
+    pub estado: Option<String>,
+    /// Município, pelo código do IBGE ou pelo nome.
+    pub municipio: Option<String>,
+    pub logradouro: String,
+    /// Número no primeiro ponto do segmento.
+    pub numero_inicial: Option<u32>,
+    /// Número no último ponto do segmento.
+    pub numero_final: Option<u32>,
+    pub paridade: Paridade,
+    pub cep: Option<String>,
+    /// Pontos da linha do segmento, do número inicial para o final.
+    pub pontos: Vec<Coordenada>,
+}
+
+impl Segmento {
+    fn linha_referencia(&self) -> LinhaReferencia {
+        let centro = self.ponto(0.5);
+        LinhaReferencia {
+            estado: self.estado.clone(),
+            municipio: self.municipio.clone(),
+            logradouro: self.logradouro.clone(),
+            numero_inicial: self.numero_inicial,
+            numero_final: self.numero_final,
+            cep: self.cep.clone(),
+            latitude: centro.map(|c| c.latitude),
+            longitude: centro.map(|c| c.longitude),
+        }
+    }
+
+    /// Ponto a uma fração do comprimento do segmento, a partir do primeiro ponto.
+    ///
+    /// As distâncias são calculadas numa projeção equiretangular, o que é suficiente para
+    /// segmentos da ordem de quilômetros.
+    pub fn ponto(&self, fracao: f64) -> Option<Coordenada> {
+        let primeiro = *self.pontos.first()?;
+        let distancia = |a: &Coordenada, b: &Coordenada| {
+            let escala = ((a.latitude + b.latitude) / 2.0).to_radians().cos();
+            ((b.longitude - a.longitude) * escala).hypot(b.latitude - a.latitude)
+        };
+
+        let total: f64 = self
+            .pontos
+            .windows(2)
+            .map(|p| distancia(&p[0], &p[1]))
+            .sum();
+        let mut restante = fracao.clamp(0.0, 1.0) * total;
+
+        for par in self.pontos.windows(2) {
+            let (a, b) = (&par[0], &par[1]);
+            let trecho = distancia(a, b);
+            if restante <= trecho && trecho > 0.0 {
+                let t = restante / trecho;
+                return Some(Coordenada::new(
+                    a.latitude + t * (b.latitude - a.latitude),
+                    a.longitude + t * (b.longitude - a.longitude),
+                ));
+            }
+            restante -= trecho;
+        }
+
+        // Fração 1 (com erro de arredondamento) ou segmento sem comprimento.
+        Some(if total > 0.0 {
+            self.pontos[self.pontos.len() - 1]
+        } else {
+            primeiro
+        })
+    }
+
+    /// Posição interpolada do número ao longo do segmento, ou `None` se o número estiver fora
+    /// da faixa, não tiver a paridade do segmento ou se o segmento não tiver pontos.
+    pub fn interpolar(&self, numero: u32) -> Option<Coordenada> {
+        if !self.paridade.aceita(numero)
+            || !faixa_contem(self.numero_inicial, self.numero_final, numero)
+        {
+            return None;
+        }
+
+        let fracao = match (self.numero_inicial, self.numero_final) {
+            (Some(inicio), Some(fim)) if inicio != fim => {
+                (f64::from(numero) - f64::from(inicio)) / (f64::from(fim) - f64::from(inicio))
+            }
+            _ => 0.5,
+        };
+        self.ponto(fracao)
+    }
+}
+
+/// Lê os pontos de uma geometria em WKT, no formato `LINESTRING (lon lat, lon lat, ...)` ou
+/// `POINT (lon lat)`. Coordenadas adicionais (Z e M) são ignoradas.
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::geocodificacao::{ler_pontos_wkt, Coordenada};
+/// assert_eq!(
+///     ler_pontos_wkt("LINESTRING (-43.2 -22.9, -43.1 -22.8)"),
+///     Some(vec![Coordenada::new(-22.9, -43.2), Coordenada::new(-22.8, -43.1)])
+/// );
+/// assert_eq!(ler_pontos_wkt("POINT Z (1 2 3)"), Some(vec![Coordenada::new(2.0, 1.0)]));
+/// assert_eq!(ler_pontos_wkt("POLYGON ((0 0, 1 1, 1 0, 0 0))"), None);
+/// assert_eq!(ler_pontos_wkt("LINESTRING EMPTY"), None);
+/// ```
+pub fn ler_pontos_wkt(wkt: &str) -> Option<Vec<Coordenada>> {
+    let wkt = wkt.trim().to_uppercase();
+    let (tipo, resto) = wkt.split_once('(')?;
+    let tipo = tipo.split_whitespace().next()?;
+    if !matches!(tipo, "LINESTRING" | "POINT") {
+        return None;
+    }
+
+    let pontos = resto
+        .strip_suffix(')')?
+        .split(',')
+        .map(|par| {
+            let mut valores = par.split_whitespace().map(str::parse::<f64>);
+            match (valores.next(), valores.next()) {
+                (Some(Ok(longitude)), Some(Ok(latitude))) => {
+                    Some(Coordenada::new(latitude, longitude))
+                }
+                _ => None,
+            }
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(pontos).filter(|p| !p.is_empty())
+}
+
+/// Precisão de uma geocodificação, da mais para a menos precisa.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PrecisaoGeocodificacao {
+    /// Posição interpolada pelo número ao longo do segmento do logradouro.
+    Numero,
+    /// Centroide dos segmentos do logradouro.
+    Logradouro,
+    /// Centroide dos segmentos com o mesmo CEP.
+    Cep,
+    /// Centroide do município.
+    Municipio,
+}
+
+/// Resultado de uma geocodificação, vide [Geocodificador::geocodificar].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Geocodificacao {
+    pub coordenada: Coordenada,
+    pub precisao: PrecisaoGeocodificacao,
+    /// Nível da correspondência com o logradouro, quando ele foi encontrado.
+    pub nivel: Option<NivelCorrespondencia>,
+    /// Posição do segmento usado na interpolação.
+    pub segmento: Option<usize>,
+}
+
+/// Opções da geocodificação, vide [Geocodificador::geocodificar].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OpcoesGeocodificacao {
+    /// Similaridade mínima para uma correspondência aproximada com o logradouro, vide
+    /// [OpcoesReferencia::limiar_aproximado].
+    pub limiar_aproximado: f64,
+    /// Precisão mais grosseira aceita. Com [PrecisaoGeocodificacao::Logradouro], por exemplo,
+    /// os endereços cujo logradouro não é encontrado ficam sem coordenada.
+    pub precisao_minima: PrecisaoGeocodificacao,
+}
+
+impl Default for OpcoesGeocodificacao {
+    fn default() -> Self {
+        OpcoesGeocodificacao {
+            limiar_aproximado: OpcoesReferencia::default().limiar_aproximado,
+            precisao_minima: PrecisaoGeocodificacao::Municipio,
+        }
+    }
+}
+
+// Centroide do ponto central das linhas de cada chave.
+fn centroides<K: Hash + Eq>(
+    linhas: &[LinhaReferencia],
+    chave: impl Fn(&LinhaReferencia) -> Option<K>,
+) -> HashMap<K, Coordenada> {
+    let mut grupos: HashMap<K, Vec<Coordenada>> = HashMap::new();
+    for linha in linhas {
+        if let (Some(chave), Some(latitude), Some(longitude)) =
+            (chave(linha), linha.latitude, linha.longitude)
+        {
+            grupos
+                .entry(chave)
+                .or_default()
+                .push(Coordenada::new(latitude, longitude));
+        }
+    }
+    grupos
+        .into_iter()
+        .filter_map(|(chave, pontos)| Some((chave, centroide(pontos.into_iter())?)))
+        .collect()
+}
+
+fn centroide(pontos: impl Iterator<Item = Coordenada>) -> Option<Coordenada> {
+    let (soma, quantidade) = pontos.fold((Coordenada::default(), 0), |(soma, n), p| {
+        (
+            Coordenada::new(soma.latitude + p.latitude, soma.longitude + p.longitude),
+            n + 1,
+        )
+    });
+    (quantidade > 0).then(|| {
+        Coordenada::new(
+            soma.latitude / f64::from(quantidade),
+            soma.longitude / f64::from(quantidade),
+        )
+    })
+}
+
+/// Geocodificador offline, que localiza endereços pela interpolação ao longo de segmentos de
+/// logradouro de uma base de referência local.
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::Endereco;
+/// use enderecobr_rs::blocagem::RegistroEndereco;
+/// use enderecobr_rs::geocodificacao::{
+///     Coordenada, Geocodificador, OpcoesGeocodificacao, Paridade, PrecisaoGeocodificacao,
+///     Segmento,
+/// };
+///
+/// let geocodificador = Geocodificador::new(vec![Segmento {
+///     estado: None,
+///     municipio: Some("3304557".to_string()),
+///     logradouro: "RUA SOUZA LIMA".to_string(),
+///     numero_inicial: Some(100),
+///     numero_final: Some(200),
+///     paridade: Paridade::Pares,
+///     cep: Some("22081-010".to_string()),
+///     pontos: vec![Coordenada::new(-22.97, -43.19), Coordenada::new(-22.98, -43.19)],
+/// }]);
+///
+/// let registro = |numero: &str| RegistroEndereco {
+///     endereco: Endereco {
+///         logradouro: Some("R. Sousa Lima".to_string()),
+///         numero: Some(numero.to_string()),
+///         ..Default::default()
+///     },
+///     municipio: Some("Rio de Janeiro".to_string()),
+///     estado: Some("RJ".to_string()),
+///     ..Default::default()
+/// };
+///
+/// let opcoes = OpcoesGeocodificacao::default();
+/// let resultado = geocodificador.geocodificar(&registro("150"), &opcoes).unwrap();
+/// assert_eq!(resultado.precisao, PrecisaoGeocodificacao::Numero);
+/// assert!((resultado.coordenada.latitude - -22.975).abs() < 1e-9);
+///
+/// // Números ímpares não estão no segmento, então fica o centroide do logradouro.
+/// let resultado = geocodificador.geocodificar(&registro("151"), &opcoes).unwrap();
+/// assert_eq!(resultado.precisao, PrecisaoGeocodificacao::Logradouro);
+/// ```
+#[derive(Debug)]
+pub struct Geocodificador {
+    segmentos: Vec<Segmento>,
+    indice: IndiceReferencia,
+    centroides_cep: HashMap<String, Coordenada>,
+    // Pelo nome padronizado do município, que pode ser compartilhado por municípios de estados
+    // diferentes.
+    centroides_municipio: HashMap<String, Vec<(ChaveMunicipio, Coordenada)>>,
+}
+
+impl Geocodificador {
+    /// Cria o geocodificador, indexando os logradouros dos segmentos e calculando os
+    /// centroides de cada CEP e município a partir do ponto central dos segmentos.
+    pub fn new(segmentos: Vec<Segmento>) -> Self {
+        let linhas: Vec<_> = segmentos.iter().map(Segmento::linha_referencia).collect();
+
+        let centroides_cep = centroides(&linhas, |l| nao_vazio(&l.cep).map(digitos_cep));
+        let mut centroides_municipio: HashMap<String, Vec<_>> = HashMap::new();
+        for (chave, coordenada) in centroides(&linhas, |l| {
+            nao_vazio(&l.municipio).map(|m| ChaveMunicipio::new(m, nao_vazio(&l.estado)))
+        }) {
+            centroides_municipio
+                .entry(chave.nome.clone())
+                .or_default()
+                .push((chave, coordenada));
+        }
+
+        Geocodificador {
+            segmentos,
+            indice: IndiceReferencia::new(linhas),
+            centroides_cep,
+            centroides_municipio,
+        }
+    }
+
+    /// Substitui o centroide calculado para o município, por exemplo pela coordenada da sede
+    /// municipal. Também permite geocodificar municípios sem nenhum segmento.
+    ///
+    /// O estado só é necessário quando o município é identificado por um nome repetido em mais
+    /// de um estado, como "CAMPO GRANDE".
+    pub fn definir_centroide_municipio(
+        &mut self,
+        municipio: &str,
+        estado: Option<&str>,
+        coordenada: Coordenada,
+    ) {
+        let chave = ChaveMunicipio::new(municipio, estado);
+        let centroides = self
+            .centroides_municipio
+            .entry(chave.nome.clone())
+            .or_default();
+        centroides.retain(|(c, _)| *c != chave);
+        centroides.push((chave, coordenada));
+    }
+
+    // Centroide do município com a mesma chave ou, se não houver, do único município compatível.
+    // Um nome repetido sem o estado, como "CAMPO GRANDE", fica sem centroide.
+    fn centroide_municipio(&self, chave: &ChaveMunicipio) -> Option<Coordenada> {
+        let centroides = self.centroides_municipio.get(&chave.nome)?;
+        if let Some((_, coordenada)) = centroides.iter().find(|(c, _)| c == chave) {
+            return Some(*coordenada);
+        }
+        match centroides
+            .iter()
+            .filter(|(c, _)| c.compativel(chave))
+            .collect::<Vec<_>>()
+            .as_slice()
+        {
+            [(_, coordenada)] => Some(*coordenada),
+            _ => None,
+        }
+    }
+
+    pub fn segmentos(&self) -> &[Segmento] {
+        &self.segmentos
+    }
+
+    /// Geocodifica o endereço.
+    ///
+    /// # Detalhes
+    /// O logradouro é buscado com [IndiceReferencia::resolver], sem a correspondência só por
+    /// CEP. Se ele for encontrado e o endereço tiver número, o número é interpolado no segmento
+    /// do logradouro que o contém, respeitando a paridade. Caso contrário, são usados, nesta
+    /// ordem, o centroide do logradouro, o centroide do CEP e o centroide do município.
+    pub fn geocodificar(
+        &self,
+        registro: &RegistroEndereco,
+        opcoes: &OpcoesGeocodificacao,
+    ) -> Option<Geocodificacao> {
+        let opcoes_referencia = OpcoesReferencia {
+            limiar_aproximado: opcoes.limiar_aproximado,
+            aceitar_cep: false,
+        };
+        let resultado = |coordenada, precisao, nivel, segmento| {
+            (precisao <= opcoes.precisao_minima).then_some(Geocodificacao {
+                coordenada,
+                precisao,
+                nivel,
+                segmento,
+            })
+        };
+
+        if let Some(correspondencia) = self.indice.resolver(registro, &opcoes_referencia) {
+            let nivel = Some(correspondencia.nivel);
+            let mut trechos = self.indice.trechos_do_logradouro(correspondencia.indice);
+            // O segmento escolhido na busca é o primeiro candidato, já que ele tende a conter o
+            // número.
+            trechos.sort_by_key(|&i| i != correspondencia.indice);
+
+            let numero =
+                nao_vazio(&registro.endereco.numero).and_then(|n| separar_numero(n).numero());
+            let interpolado = numero.and_then(|n| {
+                trechos
+                    .iter()
+                    .find_map(|&i| Some((i, self.segmentos[i].interpolar(n)?)))
+            });
+            if let Some((i, coordenada)) = interpolado {
+                return resultado(coordenada, PrecisaoGeocodificacao::Numero, nivel, Some(i));
+            }
+
+            let centro = centroide(trechos.iter().filter_map(|&i| self.segmentos[i].ponto(0.5)));
+            if let Some(coordenada) = centro {
+                return resultado(coordenada, PrecisaoGeocodificacao::Logradouro, nivel, None);
+            }
+        }
+
+        let cep = nao_vazio(&registro.cep)
+            .map(digitos_cep)
+            .and_then(|c| self.centroides_cep.get(&c));
+        if let Some(&coordenada) = cep {
+            return resultado(coordenada, PrecisaoGeocodificacao::Cep, None, None);
+        }
+
+        let municipio = nao_vazio(&registro.municipio)
+            .map(|m| ChaveMunicipio::new(m, nao_vazio(&registro.estado)))
+            .and_then(|m| self.centroide_municipio(&m));
+        municipio.and_then(|coordenada| {
+            resultado(coordenada, PrecisaoGeocodificacao::Municipio, None, None)
+        })
+    }
+}
+
+#[cfg(feature = "polars")]
+pub use lote_polars::ColunasSegmento;
+
+#[cfg(feature = "polars")]
+mod lote_polars {
+    use polars::prelude::{polars_err, DataType, LazyFrame, PolarsResult};
+
+    use super::{ler_pontos_wkt, Geocodificador, Paridade, Segmento};
+    use crate::referencia::{coluna, escanear_parquet};
+
+    /// Nomes das colunas da base de segmentos em um DataFrame. As colunas ausentes (`None`)
+    /// ficam vazias em todas as linhas.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ColunasSegmento {
+        pub estado: Option<String>,
+        pub municipio: Option<String>,
+        pub logradouro: String,
+        pub numero_inicial: Option<String>,
+        pub numero_final: Option<String>,
+        /// Paridade em qualquer formato aceito por [Paridade::de_nome].
+        pub paridade: Option<String>,
+        pub cep: Option<String>,
+        /// Geometria do segmento em WKT, vide [super::ler_pontos_wkt].
+        pub geometria: String,
+    }
+
+    impl Default for ColunasSegmento {
+        /// Colunas com os mesmos nomes dos campos de [Segmento], exceto a geometria, na coluna
+        /// "geometria".
+        fn default() -> Self {
+            ColunasSegmento {
+                estado: Some("estado".to_string()),
+                municipio: Some("municipio".to_string()),
+                logradouro: "logradouro".to_string(),
+                numero_inicial: Some("numero_inicial".to_string()),
+                numero_final: Some("numero_final".to_string()),
+                paridade: Some("paridade".to_string()),
+                cep: Some("cep".to_string()),
+                geometria: "geometria".to_string(),
+            }
+        }
+    }
+
+    impl Geocodificador {
+        /// Cria o geocodificador a partir de um DataFrame com a base de segmentos.
+        ///
+        /// # Exemplo
+        /// ```
+        /// use enderecobr_rs::geocodificacao::{ColunasSegmento, Geocodificador, Paridade};
+        /// use polars::prelude::*;
+        ///
+        /// let df = df!(
+        ///     "municipio" => ["3304557"],
+        ///     "logradouro" => ["RUA SOUZA LIMA"],
+        ///     "numero_inicial" => [1],
+        ///     "numero_final" => [99],
+        ///     "paridade" => ["I"],
+        ///     "geometria" => ["LINESTRING (-43.19 -22.97, -43.19 -22.98)"],
+        /// )
+        /// .unwrap();
+        /// let colunas = ColunasSegmento { estado: None, cep: None, ..Default::default() };
+        ///
+        /// let geocodificador = Geocodificador::de_dataframe(df.clone().lazy(), &colunas).unwrap();
+        /// assert_eq!(geocodificador.segmentos()[0].paridade, Paridade::Impares);
+        /// assert_eq!(geocodificador.segmentos()[0].pontos.len(), 2);
+        ///
+        /// let invalido = df.lazy().with_column(lit("POLYGON ((0 0, 1 1, 1 0, 0 0))").alias("geometria"));
+        /// assert!(Geocodificador::de_dataframe(invalido, &colunas).is_err());
+        /// ```
+        ///
+        /// # Erros
+        /// Além dos erros do Polars, retorna um erro quando alguma paridade não é reconhecida por
+        /// [Paridade::de_nome] ou quando alguma geometria não pode ser lida por
+        /// [super::ler_pontos_wkt]. Paridades e geometrias nulas são aceitas, e resultam em um
+        /// segmento com todos os números ou sem pontos.
+        pub fn de_dataframe(df: LazyFrame, colunas: &ColunasSegmento) -> PolarsResult<Self> {
+            let df = df
+                .select([
+                    coluna(colunas.estado.as_deref(), DataType::String, "estado"),
+                    coluna(colunas.municipio.as_deref(), DataType::String, "municipio"),
+                    coluna(Some(&colunas.logradouro), DataType::String, "logradouro"),
+                    coluna(
+                        colunas.numero_inicial.as_deref(),
+                        DataType::UInt32,
+                        "numero_inicial",
+                    ),
+                    coluna(
+                        colunas.numero_final.as_deref(),
+                        DataType::UInt32,
+                        "numero_final",
+                    ),
+                    coluna(colunas.paridade.as_deref(), DataType::String, "paridade"),
+                    coluna(colunas.cep.as_deref(), DataType::String, "cep"),
+                    coluna(Some(&colunas.geometria), DataType::String, "geometria"),
+                ])
+                .collect()?;
+
+            let estados = df.column("estado")?.str()?;
+            let municipios = df.column("municipio")?.str()?;
+            let logradouros = df.column("logradouro")?.str()?;
+            let inicios = df.column("numero_inicial")?.u32()?;
+            let fins = df.column("numero_final")?.u32()?;
+            let paridades = df.column("paridade")?.str()?;
+            let ceps = df.column("cep")?.str()?;
+            let geometrias = df.column("geometria")?.str()?;
+
+            let segmentos = (0..df.height())
+                .map(|i| {
+                    let paridade = match paridades.get(i) {
+                        Some(paridade) => Paridade::de_nome(paridade).ok_or_else(|| {
+                            polars_err!(
+                                ComputeError: "paridade não reconhecida na linha {}: {:?}",
+                                i,
+                                paridade
+                            )
+                        })?,
+                        None => Paridade::default(),
+                    };
+                    let pontos = match geometrias.get(i) {
+                        Some(wkt) => ler_pontos_wkt(wkt).ok_or_else(|| {
+                            polars_err!(
+                                ComputeError: "geometria inválida na linha {}: {:?}",
+                                i,
+                                wkt
+                            )
+                        })?,
+                        None => vec![],
+                    };
+                    Ok(Segmento {
+                        estado: estados.get(i).map(str::to_string),
+                        municipio: municipios.get(i).map(str::to_string),
+                        logradouro: logradouros.get(i).unwrap_or_default().to_string(),
+                        numero_inicial: inicios.get(i),
+                        numero_final: fins.get(i),
+                        paridade,
+                        cep: ceps.get(i).map(str::to_string),
+                        pontos,
+                    })
+                })
+                .collect::<PolarsResult<_>>()?;
+
+            Ok(Geocodificador::new(segmentos))
+        }
+
+        /// Cria o geocodificador a partir de um arquivo Parquet com a base de segmentos, vide
+        /// [Geocodificador::de_dataframe].
+        pub fn ler_parquet(caminho: &str, colunas: &ColunasSegmento) -> PolarsResult<Self> {
+            Geocodificador::de_dataframe(escanear_parquet(caminho)?, colunas)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Endereco;
+
+    fn proximo(a: Coordenada, latitude: f64, longitude: f64) -> bool {
+        (a.latitude - latitude).abs() < 1e-9 && (a.longitude - longitude).abs() < 1e-9
+    }
+
+    #[test]
+    fn interpola_ao_longo_do_segmento() {
+        let s = Segmento {
+            logradouro: "RUA A".to_string(),
+            numero_inicial: Some(0),
+            numero_final: Some(100),
+            paridade: Paridade::Pares,
+            pontos: vec![
+                Coordenada::new(0.0, 0.0),
+                Coordenada::new(0.0, 0.001),
+                Coordenada::new(0.0, 0.003),
+            ],
+            ..Default::default()
+        };
+        assert!(proximo(s.ponto(0.0).unwrap_or_default(), 0.0, 0.0));
+        assert!(proximo(s.ponto(0.5).unwrap_or_default(), 0.0, 0.0015));
+        assert!(proximo(s.ponto(1.0).unwrap_or_default(), 0.0, 0.003));
+        assert!(proximo(s.interpolar(20).unwrap_or_default(), 0.0, 0.0006));
+        assert_eq!(s.interpolar(21), None);
+        assert_eq!(s.interpolar(102), None);
+
+        // Faixa decrescente: o número inicial fica no primeiro ponto.
+        let s = Segmento {
+            numero_inicial: Some(100),
+            numero_final: Some(0),
+            paridade: Paridade::Todos,
+            pontos: vec![Coordenada::new(0.0, 0.0), Coordenada::new(0.0, 0.001)],
+            ..s
+        };
+        assert!(proximo(s.interpolar(75).unwrap_or_default(), 0.0, 0.00025));
+
+        assert_eq!(Segmento::default().ponto(0.5), None);
+    }
+
+    #[test]
+    fn geocodifica_com_fallbacks() {
+        let pares = Segmento {
+            municipio: Some("3550308".to_string()),
+            logradouro: "AVENIDA PAULISTA".to_string(),
+            numero_inicial: Some(0),
+            numero_final: Some(100),
+            paridade: Paridade::Pares,
+            cep: Some("01310-100".to_string()),
+            pontos: vec![
+                Coordenada::new(0.0, 0.0),
+                Coordenada::new(0.0, 0.001),
+                Coordenada::new(0.0, 0.002),
+            ],
+            ..Default::default()
+        };
+        let impares = Segmento {
+            numero_inicial: Some(1),
+            numero_final: Some(101),
+            paridade: Paridade::Impares,
+            pontos: vec![Coordenada::new(0.0001, 0.0), Coordenada::new(0.0001, 0.002)],
+            ..pares.clone()
+        };
+        let continuacao = Segmento {
+            numero_inicial: Some(200),
+            numero_final: Some(102),
+            paridade: Paridade::Todos,
+            pontos: vec![Coordenada::new(0.0, 0.002), Coordenada::new(0.0, 0.004)],
+            ..pares.clone()
+        };
+        let augusta = Segmento {
+            logradouro: "RUA AUGUSTA".to_string(),
+            numero_inicial: Some(1),
+            numero_final: Some(500),
+            paridade: Paridade::Todos,
+            pontos: vec![Coordenada::new(0.01, 0.01), Coordenada::new(0.02, 0.01)],
+            ..pares.clone()
+        };
+        let g = Geocodificador::new(vec![pares, impares, continuacao, augusta]);
+        let opcoes = OpcoesGeocodificacao::default();
+        let geocodificar = |r: RegistroEndereco| g.geocodificar(&r, &opcoes);
+        let paulista = |numero: Option<&str>| RegistroEndereco {
+            endereco: Endereco {
+                logradouro: Some("AV PAULISTA".to_string()),
+                numero: numero.map(str::to_string),
+                ..Default::default()
+            },
+            estado: Some("SP".to_string()),
+            municipio: Some("SAO PAULO".to_string()),
+            cep: None,
+        };
+
+        let r = geocodificar(paulista(Some("50")));
+        assert_eq!(
+            r.map(|r| (r.precisao, r.segmento)),
+            Some((PrecisaoGeocodificacao::Numero, Some(0)))
+        );
+        assert!(proximo(
+            r.map(|r| r.coordenada).unwrap_or_default(),
+            0.0,
+            0.001
+        ));
+
+        let r = geocodificar(paulista(Some("51")));
+        assert_eq!(r.and_then(|r| r.segmento), Some(1));
+        let r = geocodificar(paulista(Some("151")));
+        assert!(proximo(
+            r.map(|r| r.coordenada).unwrap_or_default(),
+            0.0,
+            0.003
+        ));
+
+        let r = geocodificar(paulista(None));
+        assert_eq!(
+            r.map(|r| r.precisao),
+            Some(PrecisaoGeocodificacao::Logradouro)
+        );
+
+        let inexistente = RegistroEndereco {
+            endereco: Endereco {
+                logradouro: Some("RUA INEXISTENTE".to_string()),
+                numero: Some("10".to_string()),
+                ..Default::default()
+            },
+            ..paulista(None)
+        };
+        let r = geocodificar(RegistroEndereco {
+            cep: Some("01310100".to_string()),
+            ..inexistente.clone()
+        });
+        assert_eq!(r.map(|r| r.precisao), Some(PrecisaoGeocodificacao::Cep));
+
+        let r = geocodificar(inexistente.clone());
+        assert_eq!(
+            r.map(|r| r.precisao),
+            Some(PrecisaoGeocodificacao::Municipio)
+        );
+
+        assert_eq!(
+            geocodificar(RegistroEndereco {
+                municipio: Some("CAMPINAS".to_string()),
+                ..inexistente
+            }),
+            None
+        );
+    }
+
+    #[test]
+    fn centroide_de_municipios_com_nome_repetido() {
+        let segmento = |estado: &str, latitude: f64| Segmento {
+            estado: Some(estado.to_string()),
+            municipio: Some("CAMPO GRANDE".to_string()),
+            logradouro: "RUA A".to_string(),
+            pontos: vec![Coordenada::new(latitude, 0.0)],
+            ..Default::default()
+        };
+        let g = Geocodificador::new(vec![segmento("MS", -20.0), segmento("AL", -9.0)]);
+        let opcoes = OpcoesGeocodificacao::default();
+        let centroide = |estado: Option<&str>| {
+            let r = RegistroEndereco {
+                estado: estado.map(str::to_string),
+                municipio: Some("CAMPO GRANDE".to_string()),
+                ..Default::default()
+            };
+            g.geocodificar(&r, &opcoes).map(|r| r.coordenada)
+        };
+
+        assert_eq!(centroide(Some("MS")), Some(Coordenada::new(-20.0, 0.0)));
+        assert_eq!(centroide(Some("AL")), Some(Coordenada::new(-9.0, 0.0)));
+        assert_eq!(centroide(None), None);
+
+        // Sem o estado nos segmentos, o nome é suficiente.
+        let mut g = Geocodificador::new(vec![]);
+        g.definir_centroide_municipio("CAMPO GRANDE", None, Coordenada::new(-20.0, 0.0));
+        let r = RegistroEndereco {
+            estado: Some("MS".to_string()),
+            municipio: Some("CAMPO GRANDE".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            g.geocodificar(&r, &opcoes).map(|r| r.coordenada),
+            Some(Coordenada::new(-20.0, 0.0))
+        );
+    }
+
+    #[test]
+    fn paridade_por_nome() {
+        for (nome, esperado) in [
+            ("P", Some(Paridade::Pares)),
+            ("impares", Some(Paridade::Impares)),
+            ("odd", Some(Paridade::Impares)),
+            ("", Some(Paridade::Todos)),
+            // E e D são usados para os lados esquerdo e direito.
+            ("E", None),
+            ("D", None),
+            ("O", None),
+            ("B", None),
+        ] {
+            assert_eq!(Paridade::de_nome(nome), esperado, "{}", nome);
+        }
+    }
+
+    #[test]
+    fn respeita_opcoes() {
+        let mut g = Geocodificador::new(vec![Segmento {
+            municipio: Some("3550308".to_string()),
+            logradouro: "AVENIDA PAULISTA".to_string(),
+            numero_inicial: Some(0),
+            numero_final: Some(100),
+            pontos: vec![Coordenada::new(0.0, 0.0), Coordenada::new(0.0, 0.002)],
+            ..Default::default()
+        }]);
+        let r = RegistroEndereco {
+            endereco: Endereco {
+                logradouro: Some("RUA INEXISTENTE".to_string()),
+                ..Default::default()
+            },
+            estado: Some("SP".to_string()),
+            municipio: Some("CAMPINAS".to_string()),
+            cep: None,
+        };
+        let opcoes = OpcoesGeocodificacao::default();
+
+        g.definir_centroide_municipio("3509502", None, Coordenada::new(-22.9, -47.06));
+        let resultado = g.geocodificar(&r, &opcoes);
+        assert_eq!(
+            resultado.map(|r| r.coordenada),
+            Some(Coordenada::new(-22.9, -47.06))
+        );
+
+        let so_logradouro = OpcoesGeocodificacao {
+            precisao_minima: PrecisaoGeocodificacao::Logradouro,
+            ..Default::default()
+        };
+        assert_eq!(g.geocodificar(&r, &so_logradouro), None);
+    }
+}
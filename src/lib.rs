@@ -6,20 +6,34 @@ use unicode_normalization::UnicodeNormalization;
 use itertools::Itertools;
 use regex::{Regex, RegexSet};
 
+use crate::fonetica::CodificadorFonetico;
+
+pub mod abreviacao;
 pub mod bairro;
+pub mod blocagem;
 pub mod cep;
 pub mod complemento;
+pub mod datas;
+pub mod deduplicacao;
 pub mod estado;
+pub mod exibicao;
+pub mod fonetica;
+pub mod geocodificacao;
 pub mod logradouro;
 pub mod metaphone;
 pub mod municipio;
+pub mod nome_proprio;
 pub mod numero;
 pub mod numero_extenso;
+pub mod quilometro;
+pub mod referencia;
+pub mod rodovia;
 pub mod separador_endereco;
+pub mod similaridade;
 pub mod tipo_logradouro;
 
 /// Representa um endereço separado em seus atributos constituintes.
-#[derive(Debug, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct Endereco {
     pub logradouro: Option<String>,
     pub numero: Option<String>,
@@ -65,6 +79,19 @@ impl Endereco {
         }
     }
 
+    /// Obtém uma nova struct [Endereco] com todos os campos padronizados e formatados para
+    /// exibição, utilizando a função [exibicao::formatar_para_exibicao].
+    pub fn endereco_para_exibicao(&self) -> Endereco {
+        let padronizado = self.endereco_padronizado();
+        let formatar = |x: Option<String>| x.map(|x| exibicao::formatar_para_exibicao(&x));
+        Endereco {
+            logradouro: formatar(padronizado.logradouro),
+            numero: formatar(padronizado.numero),
+            complemento: formatar(padronizado.complemento),
+            localidade: formatar(padronizado.localidade),
+        }
+    }
+
     /// Obtém uma representação textual dos atributos desta struct,
     /// separados por vírgula, caso existam.
     pub fn formatar(&self) -> String {
@@ -362,20 +389,30 @@ pub fn normalizar(valor: &str) -> Cow<'_, str> {
         .collect()
 }
 
+pub use abreviacao::abreviar_bairro;
+pub use abreviacao::abreviar_complemento;
+pub use abreviacao::abreviar_logradouro;
 pub use bairro::padronizar_bairros;
 pub use cep::padronizar_cep;
 pub use cep::padronizar_cep_leniente;
 pub use cep::padronizar_cep_numerico;
 pub use complemento::padronizar_complementos;
+pub use complemento::separar_complemento;
+pub use estado::buscar_estado;
+pub use estado::buscar_estado_estrito;
 pub use estado::padronizar_estados_para_codigo;
 pub use estado::padronizar_estados_para_nome;
 pub use estado::padronizar_estados_para_sigla;
 pub use logradouro::padronizar_logradouros;
 pub use municipio::padronizar_municipios;
+pub use municipio::padronizar_municipios_em;
 pub use numero::padronizar_numeros;
 pub use numero::padronizar_numeros_para_int;
 pub use numero::padronizar_numeros_para_string;
+pub use numero::separar_numero;
+pub use similaridade::similaridade;
 pub use tipo_logradouro::padronizar_tipo_logradouro;
+pub use tipo_logradouro::separar_tipo_logradouro;
 
 #[cfg(feature = "experimental")]
 pub use separador_endereco::padronizar_endereco_bruto;
@@ -389,9 +426,16 @@ pub fn obter_padronizador_por_tipo(tipo: &str) -> Result<fn(&str) -> String, &st
     match tipo {
         "logradouro" | "logr" => Ok(padronizar_logradouros),
         "tipo_logradouro" | "tipo_logr" => Ok(padronizar_tipo_logradouro),
+        "tipo_logradouro_codigo" => Ok(|x| {
+            tipo_logradouro::codigo_tipo_logradouro(x)
+                .unwrap_or("")
+                .to_string()
+        }),
         "numero" | "num" => Ok(padronizar_numeros),
         "bairro" => Ok(padronizar_bairros),
+        "nome_proprio" => Ok(nome_proprio::padronizar_nome_proprio),
         "complemento" | "comp" => Ok(padronizar_complementos),
+        "complemento_ordenado" => Ok(complemento::padronizar_complementos_ordenados),
         "estado" => Ok(|x| padronizar_estados_para_sigla(x).to_string()),
         "estado_nome" => Ok(|x| padronizar_estados_para_nome(x).to_string()),
         "estado_codigo" => Ok(|x| padronizar_estados_para_codigo(x).to_string()),
@@ -399,6 +443,14 @@ pub fn obter_padronizador_por_tipo(tipo: &str) -> Result<fn(&str) -> String, &st
         "cep" => Ok(|cep| padronizar_cep(cep).unwrap_or("".to_string())),
         "cep_leniente" => Ok(padronizar_cep_leniente),
         "metaphone" => Ok(metaphone::metaphone),
+        "buscabr" => Ok(|x| CodificadorFonetico::chave(&fonetica::BuscaBr, x, &Default::default())),
+        "soundex" => {
+            Ok(|x| CodificadorFonetico::chave(&fonetica::SoundexPt, x, &Default::default()))
+        }
+        "romano_contextual" => Ok(|x| {
+            numero_extenso::padronizar_numero_romano_contextual(x, &Default::default()).to_string()
+        }),
+        "exibicao" => Ok(exibicao::formatar_para_exibicao),
 
         #[cfg(feature = "experimental")]
         "completo" => Ok(padronizar_endereco_bruto),
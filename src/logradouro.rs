@@ -1,13 +1,34 @@
 use std::sync::LazyLock;
 
-use crate::Padronizador;
+use crate::{
+    abreviacao::{
+        abreviacoes_padrao, adicionar_abreviacoes, adicionar_tipos_abreviados, Abreviacao,
+    },
+    datas::{padronizar_datas_com, OpcoesData},
+    nome_proprio::{adicionar_nomes, adicionar_titulos},
+    numero_extenso::{padronizar_ordinais, FormatoOrdinal},
+    quilometro::padronizar_quilometros,
+    rodovia::padronizar_rodovias,
+    Padronizador,
+};
 
 pub fn criar_padronizador_logradouros() -> Padronizador {
+    criar_padronizador_logradouros_com(&abreviacoes_padrao())
+}
+
+/// Cria o padronizador de logradouros com as abreviações informadas, vide
+/// [crate::abreviacao::DicionarioAbreviacoes].
+pub(crate) fn criar_padronizador_logradouros_com(abreviacoes: &[Abreviacao]) -> Padronizador {
     let mut padronizador = Padronizador::default();
     padronizador
         // Substituição nova
         .adicionar(r"\s{2,}", " ")
 
+        // Marcos quilométricos: a vírgula ou o ponto decimal ("KM 23.5") vira "+" para não ser
+        // tratado como pontuação. A forma final ("KM 23,5") é aplicada no fim.
+        .adicionar(r"\b(KM|QUILOMETRO)\.? ?(\d{1,3}(?:\.\d{3})+|\d+),(\d{1,3})\b", "$1 $2+$3")
+        .adicionar(r"\b(KM|QUILOMETRO)\.? ?(\d+)\.(\d{1,2})\b", "$1 $2+$3")
+
         // Pontuação
         .adicionar(r"\.\.+", ".") // ponto repetido
         .adicionar(r",,+", ",")   // virgula repetida
@@ -70,11 +91,6 @@ pub fn criar_padronizador_logradouros() -> Padronizador {
         .adicionar(r"^TRAVESSA\b(-|,|\.) *", "TRAVESSA ")
         .adicionar(r"^(TRAVESSA|RUA|RODOVIA) (TRAVESSA|TV|TRV|TRAV?)\b- *", "TRAVESSA ") // aqui ja acho que faz sentido botar o RUA porque so da match com padroes como RUA TRAVESSA-1
 
-        .adicionar(r"^P((A?R)?Q|QU?E)\b(\.|,)?", "PARQUE")
-        .adicionar(r"^(PARQUE|RODOVIA) (PARQUE|P((A?R)?Q|QU?E))\b(\.|,)?", "PARQUE") // mesmo caso de travessa
-        .adicionar(r"^PARQUE\b(-|,|\.) *", "PARQUE ")
-        .adicionar(r"^(PARQUE|RUA|RODOVIA) (PARQUE|P((A?R)?Q|QU?E))\b- *", "PARQUE ") // mesmo caso de travessa
-
         .adicionar(r"^ALA?\b(\.|,)?", "ALAMEDA")
         .adicionar(r"^ALAMEDA (ALAMEDA|ALA?)\b(\.|,)?", "ALAMEDA") // mesmo caso de travessa
         .adicionar(r"^RODOVIA (ALAMEDA|ALA)\b(\.|,)?", "ALAMEDA") // RODOVIA precisa ser separado porque nesse caso nao podemos mudar RODOVIA AL pra ALAMEDA, ja que pode ser uma rodovia estadual de alagoas
@@ -82,9 +98,7 @@ pub fn criar_padronizador_logradouros() -> Padronizador {
         .adicionar(r"^(ALAMEDA|RUA) (ALAMEDA|ALA?)\b- *", "ALAMEDA ") // mesmo caso de travessa
         .adicionar(r"^RODOVIA (ALAMEDA|ALA)\b- *", "ALAMEDA ") // mesmo caso acima
 
-        .adicionar(r"^LOT\b(\.|,)?", "LOTEAMENTO")
-        .adicionar(r"^(LOTEAMENTO|RUA|RODOVIA) LOT\b(\.|,)?", "LOTEAMENTO")
-        .adicionar(r"^LOTEAMENTO?\b(-|,|\.) *", "LOTEAMENTO ")
+        .adicionar(r"^RUA LOT\b(\.|,)?", "LOTEAMENTO")
 
         .adicionar(r"^LOC\b(\.|,)?", "LOCALIDADE")
         .adicionar(r"^(LOCALIDADE|RUA) LOC\b(\.|,)?", "LOCALIDADE")
@@ -110,103 +124,37 @@ pub fn criar_padronizador_logradouros() -> Padronizador {
         .adicionar(r"^(ESC)\b(\.|,)?", "ESCOLA")
         .adicionar(r"^(TUN)\b(\.|,)?", "TUNEL")
 
-        .adicionar(r"^DT\b(\.|,)?", "DISTRITO")
-        .adicionar(r"\bDISTR?\b\.?", "DISTRITO")
-        .adicionar(r"^DISTRITO DISTRITO\b(\.|,)?", "DISTRITO")
-        .adicionar(r"^DISTRITO?\b(-|,|\.) *", "DISTRITO ")
+        .adicionar(r"^DT\b(\.|,)?", "DISTRITO");
 
-        .adicionar(r"^NUC\b(\.|,)?", "NUCLEO")
-        .adicionar(r"^NUCLEO NUCLEO\b(\.|,)?", "NUCLEO")
-        .adicionar(r"^NUCLEO?\b(-|,|\.) *", "NUCLEO ")
+    // Tipos do dicionário de abreviações (PARQUE, LOTEAMENTO, DISTRITO, NUCLEO, etc.), com as
+    // mesmas regras de TRAVESSA. Vide [crate::abreviacao].
+    adicionar_tipos_abreviados(&mut padronizador, abreviacoes);
 
+    padronizador
         .adicionar(r"^L(RG|GO)\b(\.|,)?", "LARGO")
         .adicionar(r"^LARGO L(RG|GO)\b(\.|,)?", "LARGO")
         .adicionar(r"^LARGO?\b(-|,|\.) *", "LARGO ")
-
         .adicionar(r"\b(LN)\.?\b", "LINHA")
         .adicionar(r"\b(GL|GB)\.?\b", "GLEBA")
-
         // estabelecimentos
         .adicionar(r"^AER(OP)?\b(\.|,)?", "AEROPORTO") // sera que vale? tem uns casos estranhos aqui, e.g. "AER GUANANDY, 1", "AER WASHINGTON LUIZ, 3318"
         .adicionar(r"^AEROPORTO (AEROPORTO|AER)\b(\.|,)?", "AEROPORTO")
         .adicionar(r"^AEROPORTO INT(ERN?)?\b(\.|,)?", "AEROPORTO INTERNACIONAL")
+        .adicionar(r"^COL\b\.?", "COLONIA")
+        .adicionar(r"\bCOLONIA AGRI?C?\b\.?", "COLONIA AGRICOLA");
 
-        .adicionar(r"^COND\b(\.|,)?", "CONDOMINIO")
-        .adicionar(r"^(CONDOMINIO|RODOVIA) (CONDOMINIO|COND)\b(\.|,)?", "CONDOMINIO")
+    // Títulos (GEN, CEL, DR, N. SRA, etc.), vide [crate::nome_proprio].
+    adicionar_titulos(&mut padronizador);
 
-        .adicionar(r"^FAZ(EN?)?\b\.?", "FAZENDA")
-        .adicionar(r"^(FAZENDA|RODOVIA) (FAZ(EN?)?|FAZENDA)\b(\.|,)?", "FAZENDA")
-        .adicionar(r"\bFAZ(EN?)?\b\.?", "FAZENDA")
+    padronizador
+        // Abreviações
+        .adicionar(r"\bJAR DIM\b", "JARDIM");
 
-        .adicionar(r"^COL\b\.?", "COLONIA")
-        .adicionar(r"\bCOLONIA AGRI?C?\b\.?", "COLONIA AGRICOLA")
-
-        // títulos
-        .adicionar(r"\bSTA\b\.?", "SANTA")
-        .adicionar(r"\bSTO\b\.?", "SANTO")
-        .adicionar(r"\b(N(OS|SS?A?)?\.? S(RA|ENHORA)|(NOSSA|NSA\.?) (S(RA?)?|SEN(H(OR)?)?))\b\.?", "NOSSA SENHORA")
-        .adicionar(r"\b(N(O?S)?\.? S(R|EN(H(OR)?)?)?\.?( DE?)?|NOSSA SENHORA|NS) (FAT.*|LO?UR.*|SANTANA|GUADALUPE|NAZ.*|COP*)\b", "NOSSA SENHORA DE $7")
-        .adicionar(r"\b(N(O?S)?\.? S(R|EN(H(OR)?)?)?\.?( D(A|E)?)?|NOSSA SENHORA|NS) (GRACA|VITORIA|PENHA|CONCEICAO|PAZ|GUIA|AJUDA|CANDELARIA|PURIFICACAO|SAUDE|PIEDADE|ABADIA|GLORIA|SALETE|APRESENTACAO)\b", "NOSSA SENHORA DA $8")
-        .adicionar(r"\b(N(O?S)?\.? S(R|EN(H(OR)?)?)?\.?( D(A|E)?)?|NOSSA SENHORA D(A|E)|NS) (APA.*|AUX.*|MEDIANEIRA|CONSOLADORA)\b", "NOSSA SENHORA $9")
-        .adicionar(r"\b(N(O?S)?\.? S(R|EN(H(OR)?)?)?\.?( D(OS?)?)?|NOSSA SENHORA|NS) (NAVEGANTES)\b", "NOSSA SENHORA DOS $8")
-        .adicionar(r"\b(N(O?S)?\.? S(R|EN(H(OR)?)?)?\.?( DO?)?|NOSSA SENHORA|NS) (CARMO|LIVRAMENTO|RETIRO|SION|ROSARIO|PILAR|ROCIO|CAMINHO|DESTERRO|BOM CONSELHO|AMPARO|PERP.*|P.* S.*)\b", "NOSSA SENHORA DO $7")
-        .adicionar(r"\b(N(O?S)?\.? S(R|EN(H(OR)?)?)?\.?( D(AS?)?)?|NOSSA SENHORA|NS) (GRACAS|DORES)\b", "NOSSA SENHORA DAS $8")
-        .adicionar(r"\b(S(R|ENH?)\.?( D(OS?)?)?|SENHOR( D(OS)?)?) (BON\w*)\b", "SENHOR DO BONFIM")
-        .adicionar(r"\b(S(R|ENH?)\.?( D(OS?)?)?|SENHOR( D(OS?)?)?) (BOM ?F\w*)\b", "SENHOR DO BONFIM")
-        .adicionar(r"\b(S(R|ENH?)\.?( D(OS?)?)?|SENHOR) (PASS\w*|MONT\w*)\b", "SENHOR DOS $5")
-        .adicionar(r"\bS(R|ENH?)\.? (BOM J\w*)\b", "SENHOR BOM JESUS")
-        .adicionar(r"\b(N(O?S)?\.? S(R|EN(H(OR)?)?)?\.?( D(OS?)?)?|NOSSO SENHOR|NS) (BONF\w*|BOM ?F\w*)\b", "NOSSO SENHOR DO BONFIM")
-        .adicionar(r"\b(N(O?S)?\.? S(R|EN(H(OR)?)?)?\.?( D(OS?)?)?|NOSSO SENHOR|NS) (PASS\w*|MONT\w*)\b", "NOSSO SENHOR DOS $8")
-
-        .adicionar(r"\bALM?TE\b\.?", "ALMIRANTE")
-        .adicionar(r"\bMAL\b\.?", "MARECHAL")
-        .adicionar(r"\b(GEN|GAL)\b\.?", "GENERAL")
-        .adicionar(r"\b(SGTO?|SARG)\b\.?", "SARGENTO")
-        .adicionar(r"\b(PRIMEIRO|PRIM|1)\.? SARGENTO\b", "PRIMEIRO-SARGENTO")
-        .adicionar(r"\b(SEGUNDO|SEG|2)\.? SARGENTO\b", "SEGUNDO-SARGENTO")
-        .adicionar(r"\b(TERCEIRO|TERC|3)\.? SARGENTO\b", "TERCEIRO-SARGENTO")
-        .adicionar(r"\bCEL\b\.?", "CORONEL")
-        .adicionar(r"\bBRIG\b\.?", "BRIGADEIRO")
-        .adicionar(r"\bTEN\b\.?", "TENENTE")
-        .adicionar(r"\bTENENTE CORONEL\b", "TENENTE-CORONEL")
-        .adicionar(r"\bTENENTE BRIGADEIRO\b", "TENENTE-BRIGADEIRO")
-        .adicionar(r"\bTENENTE AVIADOR\b", "TENENTE-AVIADOR")
-        .adicionar(r"\bSUB TENENTE\b", "SUBTENENTE")
-        .adicionar(r"\b(PRIMEIRO|PRIM\.?) TENENTE\b", "PRIMEIRO-TENENTE")
-        .adicionar(r"\b(SEGUNDO|SEG\.?) TENENTE\b", "SEGUNDO-TENENTE")
-        .adicionar(r"\bSOLD\b\.?", "SOLDADO")
-        .adicionar(r"\bMAJ\b\.?", "MAJOR")
-
-        .adicionar(r"\bPROF\b\.?", "PROFESSOR")
-        .adicionar(r"\bPROFA\b\.?", "PROFESSORA")
-        .adicionar(r"\bDR\b\.?", "DOUTOR")
-        .adicionar(r"\bDRA\b\.?", "DOUTORA")
-        .adicionar(r"\bENG\b\.?", "ENGENHEIRO")
-        .adicionar(r"\bENGA\b\.?", "ENGENHEIRA")
-        .adicionar(r"\bPD?E\b\.", "PADRE") // PE pode ser só pe mesmo, então forcando o PE. (com ponto) pra ser PADRE
-        .adicionar(r"\bMONS\b\.?", "MONSENHOR")
-
-        // Erros de digitação comuns para presidente. => Sem testes
-        .adicionar(r"\b(PREISI|PREZI|PRSI|PERSI|PESI)DENTE\b", "PRESIDENTE")
-
-        .adicionar(r"\bPRES(ID)?\b\.?", "PRESIDENTE")
-        .adicionar(r"\bGOV\b\.?", "GOVERNADOR")
-        .adicionar(r"\bSEN\b\.?", "SENADOR")
-        .adicionar(r"\bPREF\b\.?", "PREFEITO")
-        .adicionar(r"\bDEP\b\.?", "DEPUTADO")
-        // PS: Regex original tinha um look-ahead (?!$) que o motor do Rust não permite.
-        // Troquei ele por um espaço em branco para garantir que não é no fim da string.
-        .adicionar(r"\bVER\b\.?(.)", "VEREADOR$1")
-        .adicionar(r"\bESPL?\.? (DOS )?MIN(IST(ERIOS?)?)?\b\.?", "ESPLANADA DOS MINISTERIOS")
-        // PS: Regex original tinha um look-ahead (?!$) que o motor do Rust não permite.
-        // Troquei ele por um espaço em branco para garantir que não é no fim da string.
-        .adicionar(r"\bMIN\b\.?(.)", "MINISTRO$1")
+    // Abreviações compartilhadas com os outros padronizadores (JD, PQ, RES, CONJ, etc.)
+    adicionar_abreviacoes(&mut padronizador, abreviacoes);
 
-        // Abreviações
-        .adicionar(r"\bJAR DIM\b", "JARDIM")
-        .adicionar(r"\bJ(D(I?M)?|A?RD|AR(DIN)?)\b\.?", "JARDIM")
+    padronizador
         .adicionar(r"\bUNID\b\.?", "UNIDADE")
-        .adicionar(r"\b(CJ|CONJ)\b\.?", "CONJUNTO")
         .adicionar(r"\bLT\b\.?", "LOTE")
         .adicionar(r"\bLTS\b\.?", "LOTES")
         .adicionar(r"\bQDA?\b\.?", "QUADRA")
@@ -217,28 +165,19 @@ pub fn criar_padronizador_logradouros() -> Padronizador {
         .adicionar(r"\bSLS\b\.?", "SALAS")
         .adicionar(r"\bEDI?F\.? EMP\b\.?", "EDIFICIO EMPRESARIAL")
         .adicionar(r"\bEDI?F\b\.?", "EDIFICIO")
-        .adicionar(r"\bCOND\b\.?", "CONDOMINIO") // apareceu antes mas como tipo de logradouro
         .adicionar(r"\bKM\b\.", "KM")
         .adicionar(r"\bS\.? ?N\b\.?", "S/N")
         .adicionar(r"(\d)\.( O)? A(ND(AR)?)?\b\.?", "$1 ANDAR")
         .adicionar(r"(\d)\.( O)? ANDARES\b", "$1 ANDARES")
         .adicionar(r"(\d)( O)? AND\b\.?", "$1 ANDAR")
         .adicionar(r"\bCX\.? ?P(T|(OST(AL)?))?\b\.?", "CAIXA POSTAL")
-        .adicionar(r"\bC\.? ?P(T|(OST(AL)?))?\b\.?", "CAIXA POSTAL")
-        // SL pode ser sobreloja ou sala
+        .adicionar(r"\bC\.? ?P(T|(OST(AL)?))?\b\.?", "CAIXA POSTAL");
+    // SL pode ser sobreloja ou sala
 
-        // interseção entre nomes e títulos
-        //   - D. pode ser muita coisa (e.g. dom vs dona), então não da pra
-        //   simplesmente assumir que vai ser um valor especifico, so no contexto
-        //   - MAR pode ser realmente só mar ou uma abreviação pra marechal
-        .adicionar(r"\bD\b\.? (PEDRO|JOAO|HENRIQUE)", "DOM $1")
-        .adicionar(r"\bI(NF)?\.? DOM\b", "INFANTE DOM")
-        .adicionar(r"\bMAR\b\.? ((CARMONA|JOFRE|HERMES|MALLET|DEODORO|MARCIANO|OTAVIO|FLORIANO|BARBACENA|FIUZA|MASCARENHAS|MASCARENHA|TITO|FONTENELLE|XAVIER|BITENCOURT|BITTENCOURT|CRAVEIRO|OLIMPO|CANDIDO|RONDON|HENRIQUE|MIGUEL|JUAREZ|FONTENELE|FONTENELLE|DEADORO|HASTIMPHILO|NIEMEYER|JOSE|LINO|MANOEL|HUMB?|HUMBERTO|ARTHUR|ANTONIO|NOBREGA|CASTELO|DEODORA)\b)", "MARECHAL $1")
-
-        // nomes
-        .adicionar(r"\b(GETULHO|JETULHO|JETULIO|JETULHO|GET|JET)\.? VARGAS\b", "GETULIO VARGAS")
-        .adicionar(r"\b(J(U[A-Z]*)?)\.? (K(U[A-Z]*)?)\b\.?", "JUSCELINO KUBITSCHEK")
+    // Nomes e abreviações ambíguas entre nomes e títulos (D. PEDRO, MAR DEODORO, etc.)
+    adicionar_nomes(&mut padronizador);
 
+    padronizador
         // expressões hifenizadas ou não
         //   - beira-mar deveria ter pelo novo acordo ortográfico, mas a grafia da
         //   grande maioria das ruas (se não todas, não tenho certeza) eh beira
@@ -248,10 +187,8 @@ pub fn criar_padronizador_logradouros() -> Padronizador {
         // rodovias
         .adicionar(r"\b(RD|RODOVIA|BR\.?|RODOVIA BR\.?) CENTO D?E (DESESSEIS|DESESEIS|DEZESSEIS|DEZESEIS)\b", "RODOVIA BR-116")
         .adicionar(r"\b(RODOVIA|BR\.?|RODOVIA BR\.?) CENTO D?E H?UM\b", "RODOVIA BR-101")
-        // será que essas duas de baixo valem?
-        .adicionar(r"\bBR\.? ?(\d{3})", "BR-$1")
-        // essa aqui é complicada... AL, AP, SE, entre outras, são siglas que podem aparecer sem serem rodovias
-        .adicionar(r"\b(RO|AC|AM|RR|PA|AP|TO|MA|PI|CE|RN|PB|PE|AL|SE|BA|MG|ES|RJ|SP|PR|SC|RS|MS|MT|GO|DF) ?(\d{3})", "$1-$2")
+        // Os identificadores das rodovias (BR-101, SP-055, etc.) são tratados antes do
+        // padronizador, em `padronizar_logradouros`, vide [crate::rodovia::padronizar_rodovias].
 
         // 0 à esquerda
         .adicionar(r" (0)(\d+)", " $2")
@@ -261,24 +198,7 @@ pub fn criar_padronizador_logradouros() -> Padronizador {
         .adicionar(r"\bHO SHI MINISTRO\b", "HO SHI MIN")
 
         // Unifica a grafia, mesmo que o nome oficial realmente seja diferente.
-        .adicionar(r"\bCAMPOS? H?ELI(Z|S)I?E?(O|U)(S|Z)?\b", "CAMPOS ELISIOS")
-
-        // datas
-
-        // PS: Mudei todos os JAN(?!EIRO) para JAN(EIRO)?
-        // PS: Mudei todos os DE? para ( DE)?
-        .adicionar(r"\b(\d+) DE? JAN(EIRO)?\b", "$1 DE JANEIRO")
-        .adicionar(r"\b(\d+) DE? FEV(EREIRO)?\b", "$1 DE FEVEREIRO")
-        .adicionar(r"\b(\d+) DE? MAR(CO)?\b", "$1 DE MARCO")
-        .adicionar(r"\b(\d+) DE? ABR(IL)?\b", "$1 DE ABRIL")
-        .adicionar(r"\b(\d+) DE? MAI(O)?\b", "$1 DE MAIO")
-        .adicionar(r"\b(\d+) DE? JUN(HO)?\b", "$1 DE JUNHO")
-        .adicionar(r"\b(\d+) DE? JUL(HO)?\b", "$1 DE JULHO")
-        .adicionar(r"\b(\d+) DE? AGO(STO)?\b", "$1 DE AGOSTO")
-        .adicionar(r"\b(\d+) DE? SET(EMBRO)?\b", "$1 DE SETEMBRO")
-        .adicionar(r"\b(\d+) DE? OUT(UBRO)?\b", "$1 DE OUTUBRO")
-        .adicionar(r"\b(\d+) DE? NOV(EMBRO)?\b", "$1 DE NOVEMBRO")
-        .adicionar(r"\b(\d+) DE? DEZ(EMBRO)?\b", "$1 DE DEZEMBRO");
+        .adicionar(r"\bCAMPOS? H?ELI(Z|S)I?E?(O|U)(S|Z)?\b", "CAMPOS ELISIOS");
 
     // ALM é um caso complicado, pode ser alameda ou almirante. Inclusive no mesmo endereço podem aparecer os dois rs
 
@@ -297,6 +217,7 @@ static PADRONIZADOR: LazyLock<Padronizador> = LazyLock::new(criar_padronizador_l
 /// ```
 /// use enderecobr_rs::padronizar_logradouros;
 /// assert_eq!(padronizar_logradouros("r. gen.. glicério"), "RUA GENERAL GLICERIO");
+/// assert_eq!(padronizar_logradouros("rod br 101 km 1.200"), "RODOVIA BR-101 KM 1200");
 /// ```
 ///
 /// # Detalhes
@@ -306,15 +227,52 @@ static PADRONIZADOR: LazyLock<Padronizador> = LazyLock::new(criar_padronizador_l
 /// - remoção de acentos e caracteres não ASCII;
 /// - adição de espaços após abreviações sinalizadas por pontos;
 /// - expansão de abreviações frequentemente utilizadas através de diversas expressões regulares (regexes);
-/// - correção de alguns pequenos erros ortográficos.
+/// - correção de alguns pequenos erros ortográficos;
+/// - padronização de identificadores de rodovias para a forma "BR-101", vide [crate::rodovia::padronizar_rodovias];
+/// - padronização de marcos quilométricos para a forma "KM 23,5";
+/// - padronização de números ordinais para a forma "3A TRAVESSA", vide
+///   [crate::numero_extenso::padronizar_ordinais];
+/// - padronização de datas para a forma "7 DE SETEMBRO", vide [crate::datas::padronizar_datas_com].
 ///
 /// Note que existe uma etapa de compilação das expressões regulares utilizadas,
 /// logo a primeira execução desta função pode demorar um pouco a mais.
 ///
 pub fn padronizar_logradouros(valor: &str) -> String {
     // Forma de obter a variável lazy
-    let padronizador = &*PADRONIZADOR;
-    padronizador.padronizar(valor)
+    padronizar_logradouros_com(valor, &OpcoesData::default())
+}
+
+/// Padroniza como [padronizar_logradouros], com as opções de padronização das datas presentes no nome.
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::logradouro::padronizar_logradouros_com;
+/// use enderecobr_rs::datas::OpcoesData;
+/// let opcoes = OpcoesData {
+///     numericas: true,
+///     ..Default::default()
+/// };
+/// assert_eq!(padronizar_logradouros_com("R. 07/09", &opcoes), "RUA 7 DE SETEMBRO");
+/// assert_eq!(padronizar_logradouros_com("R. 07/09", &OpcoesData::default()), "RUA 7/09");
+/// ```
+///
+pub fn padronizar_logradouros_com(valor: &str, opcoes: &OpcoesData) -> String {
+    padronizar_logradouros_usando(&PADRONIZADOR, valor, opcoes)
+}
+
+pub(crate) fn padronizar_logradouros_usando(
+    padronizador: &Padronizador,
+    valor: &str,
+    opcoes: &OpcoesData,
+) -> String {
+    // As rodovias são padronizadas antes, porque a remoção dos zeros à esquerda
+    // transformaria "SP 055" em "SP 55".
+    // Os ordinais são padronizados antes da normalização, que removeria os símbolos "º" e "ª".
+    let valor = padronizar_ordinais(valor, FormatoOrdinal::Numerico);
+    let valor = padronizar_rodovias(&valor);
+    let padronizado = padronizador.padronizar(&valor);
+    let padronizado = padronizar_quilometros(&padronizado);
+    padronizar_datas_com(&padronizado, opcoes)
 }
 
 #[cfg(test)]
@@ -332,4 +290,84 @@ mod tests {
         );
         assert_eq!(padronizar_logradouros(""), "");
     }
+
+    #[test]
+    fn padroniza_rodovias() {
+        let casos = [
+            ("ROD. BR101", "RODOVIA BR-101"),
+            ("RODOVIA BR 40", "RODOVIA BR-040"),
+            ("ROD SP 055", "RODOVIA SP-055"),
+            ("RDV MG-50", "RODOVIA MG-050"),
+            ("ACESSO PE 060", "ACESSO PE-060"),
+            ("RODOVIA AL 101", "RODOVIA AL-101"),
+            ("BR CENTO E DEZESSEIS", "RODOVIA BR-116"),
+            ("RUA SE 5", "RUA SE 5"),
+        ];
+
+        for (entrada, esperado) in casos {
+            assert_eq!(padronizar_logradouros(entrada), esperado);
+        }
+    }
+
+    #[test]
+    fn padroniza_quilometros() {
+        let casos = [
+            ("ROD BR 101 KM 23,5", "RODOVIA BR-101 KM 23,5"),
+            ("ROD BR 101 KM 23.5", "RODOVIA BR-101 KM 23,5"),
+            ("ROD BR 101 KM. 23.05", "RODOVIA BR-101 KM 23,05"),
+            ("ROD BR 101 KM 1.200,5", "RODOVIA BR-101 KM 1200,5"),
+            ("ROD. SP 55 KM. 1.200", "RODOVIA SP-055 KM 1200"),
+            ("ESTRADA MUNICIPAL KM 7", "ESTRADA MUNICIPAL KM 7"),
+            ("EST MUNICIPAL QUILOMETRO 7", "ESTRADA MUNICIPAL KM 7"),
+            ("RUA 1.200", "RUA 1200"),
+        ];
+
+        for (entrada, esperado) in casos {
+            assert_eq!(padronizar_logradouros(entrada), esperado);
+        }
+    }
+
+    #[test]
+    fn padroniza_datas() {
+        let casos = [
+            ("R. 7 DE SET", "RUA 7 DE SETEMBRO"),
+            ("RUA SETE DE SETEMBRO", "RUA 7 DE SETEMBRO"),
+            ("RUA 7 SETEMBRO", "RUA 7 DE SETEMBRO"),
+            ("R. 7 SET", "RUA 7 DE SETEMBRO"),
+            ("R. 7 SET.", "RUA 7 DE SETEMBRO"),
+            ("RUA 10/10", "RUA 10/10"),
+            ("AV. 1O DE MAIO", "AVENIDA 1 DE MAIO"),
+            ("RUA 15 DE NOV.", "RUA 15 DE NOVEMBRO"),
+        ];
+
+        for (entrada, esperado) in casos {
+            assert_eq!(padronizar_logradouros(entrada), esperado);
+        }
+
+        let opcoes = OpcoesData {
+            numericas: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            padronizar_logradouros_com("RUA 07/09", &opcoes),
+            "RUA 7 DE SETEMBRO"
+        );
+        assert_eq!(
+            padronizar_logradouros_com("QUADRA 07/09", &opcoes),
+            "QUADRA 7/09"
+        );
+    }
+
+    #[test]
+    fn padroniza_ordinais() {
+        let casos = [
+            ("3ª TRAVESSA DA RUA X", "3A TRAVESSA DA RUA X"),
+            ("TERCEIRA TRAVESSA DA RUA X", "3A TRAVESSA DA RUA X"),
+            ("1.A RUA", "1A RUA"),
+        ];
+
+        for (entrada, esperado) in casos {
+            assert_eq!(padronizar_logradouros(entrada), esperado);
+        }
+    }
 }
@@ -2,7 +2,10 @@ use std::{borrow::Cow, sync::LazyLock};
 
 use itertools::Itertools;
 
-use crate::Padronizador;
+use crate::{
+    fonetica::{CodificadorFonetico, Metaphone, OpcoesFonetica},
+    Padronizador,
+};
 
 pub fn criar_padronizador_metaphone() -> Padronizador {
     // Baseado na implementação em R de [https://github.com/ipeadata-lab/metaphonebr/blob/main/R/metaphonebr.R].
@@ -113,6 +116,7 @@ static PADRONIZADOR_METAPHONE: LazyLock<Padronizador> = LazyLock::new(criar_padr
 ///
 /// Esta é uma adaptação que não segue rigorosamente nenhum algoritmo Metaphone publicado,
 /// mas foi inspirada neles, considerando o contexto do português brasileiro.
+/// Para outros algoritmos fonéticos e opções de geração das chaves, vide [crate::fonetica].
 ///
 /// # Exemplo
 ///
@@ -159,6 +163,45 @@ pub fn metaphone(valor: &str) -> String {
         .collect()
 }
 
+/// Gera um código fonético [metaphone] para cada palavra do texto, preservando a separação entre
+/// elas.
+///
+/// Diferente do [metaphone], que remove as letras duplicadas do texto inteiro, cada palavra é
+/// codificada à parte, o que permite comparar os endereços palavra por palavra.
+///
+/// # Exemplo
+///
+/// ```
+/// use enderecobr_rs::metaphone::metaphone_tokens;
+/// assert_eq!(metaphone_tokens("Rua São-Sebastião"), vec!["RUA", "SAO", "SEBASTIAO"]);
+/// assert_eq!(metaphone_tokens("Av. Chavier, 123"), vec!["AV", "XAVIER"]);
+/// ```
+///
+pub fn metaphone_tokens(valor: &str) -> Vec<String> {
+    Metaphone.chaves(valor, &OpcoesFonetica::default())
+}
+
+/// Gera um código fonético [metaphone] para cada palavra do texto, com as opções informadas.
+///
+/// # Exemplo
+///
+/// ```
+/// use enderecobr_rs::fonetica::OpcoesFonetica;
+/// use enderecobr_rs::metaphone::metaphone_tokens_com;
+/// let opcoes = OpcoesFonetica {
+///     remover_stopwords: true,
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     metaphone_tokens_com("Travessa dos Andradas", &opcoes),
+///     vec!["TRAVESA", "ANDRADAS"]
+/// );
+/// ```
+///
+pub fn metaphone_tokens_com(valor: &str, opcoes: &OpcoesFonetica) -> Vec<String> {
+    Metaphone.chaves(valor, opcoes)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -167,4 +210,27 @@ mod tests {
     fn padroniza_corretamente() {
         assert_eq!(metaphone("MARYA CHAVIER HELENA PHILIPE CALHEIROS FILHA MANHA CHICO SCHMIDT SCENA ESCOVA QUILO MAÇÃ"), "MARIA XAVIER ELENA FILIPE KA1EIROS FI1A MA3A XIKO SXMIDT SENA ESKOVA KILO MASA");
     }
+
+    #[test]
+    fn separa_tokens() {
+        // No texto inteiro, as letras repetidas entre palavras unidas pela pontuação somem.
+        assert_eq!(metaphone("SAO-OLIVEIRA"), "SAOLIVEIRA");
+        assert_eq!(metaphone_tokens("SAO-OLIVEIRA"), vec!["SAO", "OLIVEIRA"]);
+
+        assert_eq!(
+            metaphone_tokens("Rua de Souza, 12 - Casa"),
+            vec!["RUA", "DE", "SOUSA", "KASA"]
+        );
+        assert!(metaphone_tokens("").is_empty());
+
+        let opcoes = OpcoesFonetica {
+            remover_stopwords: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            metaphone_tokens_com("Praça da Sé do Bairro dos Remédios", &opcoes),
+            vec!["PRASA", "SE", "BAIRO", "REMEDIOS"]
+        );
+        assert!(metaphone_tokens_com("DE DA DO", &opcoes).is_empty());
+    }
 }
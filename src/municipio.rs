@@ -1,6 +1,6 @@
 use std::{collections::HashMap, sync::LazyLock};
 
-use crate::{normalizar, Padronizador};
+use crate::{normalizar, padronizar_estados_para_codigo, Padronizador};
 
 static PADRONIZADOR: LazyLock<Padronizador> = LazyLock::new(criar_padronizador);
 
@@ -17,27 +17,18 @@ pub fn criar_padronizador() -> Padronizador {
         .adicionar("^BIRITIBA-MIRIM$", "BIRITIBA MIRIM")
         .adicionar("^SAO LUIS DO PARAITINGA$", "SAO LUIZ DO PARAITINGA")
         .adicionar("^TRAJANO DE MORAIS$", "TRAJANO DE MORAES")
-        .adicionar("^PARATI$", "PARATY")
         .adicionar("^LAGOA DO ITAENGA$", "LAGOA DE ITAENGA")
-        .adicionar("^ELDORADO DOS CARAJAS$", "ELDORADO DO CARAJAS")
-        .adicionar("^SANTANA DO LIVRAMENTO$", "SANT'ANA DO LIVRAMENTO")
         .adicionar("^BELEM DE SAO FRANCISCO$", "BELEM DO SAO FRANCISCO")
         .adicionar("^SANTO ANTONIO DO LEVERGER$", "SANTO ANTONIO DE LEVERGER")
-        .adicionar("^POXOREO$", "POXOREU")
         .adicionar("^SAO THOME DAS LETRAS$", "SAO TOME DAS LETRAS")
         .adicionar("^OLHO-D'AGUA DO BORGES$", "OLHO D'AGUA DO BORGES")
-        .adicionar("^ITAPAGE$", "ITAPAJE")
         .adicionar("^MUQUEM DE SAO FRANCISCO$", "MUQUEM DO SAO FRANCISCO")
         .adicionar("^DONA EUSEBIA$", "DONA EUZEBIA")
         .adicionar("^PASSA-VINTE$", "PASSA VINTE")
         .adicionar("^AMPARO DE SAO FRANCISCO$", "AMPARO DO SAO FRANCISCO")
         .adicionar("^BRASOPOLIS$", "BRAZOPOLIS")
-        .adicionar("^SERIDO$", "SAO VICENTE DO SERIDO")
         .adicionar("^IGUARACI$", "IGUARACY")
-        .adicionar("^AUGUSTO SEVERO$", "CAMPO GRANDE")
-        .adicionar("^FLORINIA$", "FLORINEA")
-        .adicionar("^FORTALEZA DO TABOCAO$", "TABOCAO")
-        .adicionar("^SAO VALERIO DA NATIVIDADE$", "SAO VALERIO");
+        .adicionar("^FLORINIA$", "FLORINEA");
 
     padronizador.preparar();
     padronizador
@@ -50,16 +41,180 @@ pub fn criar_municipio_map() -> HashMap<String, String> {
 
     for linha in municipios_csv.lines().skip(1) {
         let cols: Vec<&str> = linha.split(",").collect();
-        let codigo = cols.first().unwrap();
-        let nome = normalizar(cols.get(1).unwrap()).into_owned();
+        let (Some(codigo), Some(nome)) = (cols.first(), cols.get(1)) else {
+            continue;
+        };
+        let nome = normalizar(nome).into_owned();
 
         // Adiciona código do ibge no mapa
         mapa.insert(codigo.to_string(), nome.clone());
         mapa.insert(codigo[..codigo.len() - 1].to_string(), nome.clone());
     }
+
+    // Nomes antigos de municípios renomeados apontam para o nome atual, exceto quando o nome
+    // antigo ainda é o nome atual de outro município (ex.: PRESIDENTE JUSCELINO, no MA e em MG).
+    for alteracao in ALTERACOES.iter() {
+        if let (TipoAlteracaoMunicipio::Renomeacao, Some(nome_anterior)) =
+            (alteracao.tipo, alteracao.nome_anterior)
+        {
+            if CODIGOS_POR_NOME.contains_key(nome_anterior) {
+                continue;
+            }
+            if let Some(nome_atual) = mapa.get(alteracao.codigo).cloned() {
+                mapa.entry(nome_anterior.to_string()).or_insert(nome_atual);
+            }
+        }
+    }
+    mapa
+}
+
+// ====== Tabela histórica =======
+
+/// Tipo de alteração registrada na tabela histórica de municípios.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TipoAlteracaoMunicipio {
+    /// O município passou a ter outro nome. O nome antigo fica em `nome_anterior`.
+    Renomeacao,
+    /// O município foi instalado a partir do território do município relacionado.
+    Criacao,
+    /// O município foi extinto e seu território incorporado ao município relacionado.
+    Extincao,
+}
+
+/// Representa uma alteração (emancipação, renomeação ou extinção) de um município, válida a
+/// partir do ano informado.
+///
+/// As alterações vêm de uma tabela embutida que cobre apenas os eventos listados em
+/// [padronizar_municipios_em], e não todo o histórico de divisões territoriais do IBGE.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AlteracaoMunicipio {
+    pub tipo: TipoAlteracaoMunicipio,
+    pub ano: u16,
+    pub codigo: &'static str,
+    pub nome_anterior: Option<&'static str>,
+    pub codigo_relacionado: Option<&'static str>,
+}
+
+static ALTERACOES: LazyLock<Vec<AlteracaoMunicipio>> = LazyLock::new(criar_alteracoes);
+
+static CODIGOS_MAP: LazyLock<HashMap<String, &'static str>> = LazyLock::new(criar_codigos_map);
+
+static CODIGOS_POR_NOME: LazyLock<HashMap<String, Vec<&'static str>>> =
+    LazyLock::new(criar_codigos_por_nome);
+
+pub fn criar_alteracoes() -> Vec<AlteracaoMunicipio> {
+    let historico_csv: &'static str = include_str!("data/municipios_historico.csv");
+
+    let mut alteracoes: Vec<AlteracaoMunicipio> = historico_csv
+        .lines()
+        .skip(1)
+        .filter_map(|linha| {
+            let cols: Vec<&'static str> = linha.split(",").collect();
+            let tipo = match *cols.first()? {
+                "RENOMEACAO" => TipoAlteracaoMunicipio::Renomeacao,
+                "CRIACAO" => TipoAlteracaoMunicipio::Criacao,
+                "EXTINCAO" => TipoAlteracaoMunicipio::Extincao,
+                _ => return None,
+            };
+            Some(AlteracaoMunicipio {
+                tipo,
+                ano: cols.get(1)?.parse().ok()?,
+                codigo: cols.get(2)?,
+                nome_anterior: cols.get(3).copied().filter(|c| !c.is_empty()),
+                codigo_relacionado: cols.get(4).copied().filter(|c| !c.is_empty()),
+            })
+        })
+        .collect();
+
+    // A ordem cronológica é usada na reconstrução dos nomes.
+    alteracoes.sort_by_key(|a| a.ano);
+    alteracoes
+}
+
+/// Mapeia códigos de 6 e 7 dígitos para o código de 7 dígitos de municípios atuais.
+pub fn criar_codigos_map() -> HashMap<String, &'static str> {
+    let municipios_csv: &'static str = include_str!("data/municipios.csv");
+    let mut mapa = HashMap::<String, &'static str>::new();
+
+    for linha in municipios_csv.lines().skip(1) {
+        let Some((codigo, _)) = linha.split_once(",") else {
+            continue;
+        };
+        mapa.insert(codigo.to_string(), codigo);
+        mapa.insert(codigo[..codigo.len() - 1].to_string(), codigo);
+    }
     mapa
 }
 
+pub fn criar_codigos_por_nome() -> HashMap<String, Vec<&'static str>> {
+    let municipios_csv: &'static str = include_str!("data/municipios.csv");
+    let mut mapa = HashMap::<String, Vec<&'static str>>::new();
+
+    for linha in municipios_csv.lines().skip(1) {
+        let cols: Vec<&'static str> = linha.split(",").collect();
+        let (Some(codigo), Some(nome)) = (cols.first(), cols.get(1)) else {
+            continue;
+        };
+        mapa.entry(normalizar(nome).into_owned())
+            .or_default()
+            .push(codigo);
+    }
+    mapa
+}
+
+/// Compara um código de 7 dígitos com outro de 6 ou 7 dígitos.
+fn mesmo_codigo(codigo: &str, outro: &str) -> bool {
+    codigo == outro || (outro.len() == 6 && codigo.starts_with(outro))
+}
+
+fn resolver_codigo(codigo: &str) -> Option<&'static str> {
+    if let Some(atual) = CODIGOS_MAP.get(codigo) {
+        return Some(atual);
+    }
+
+    // Código fora da lista atual: segue a extinção mais recente do município.
+    ALTERACOES
+        .iter()
+        .rev()
+        .filter(|a| a.tipo == TipoAlteracaoMunicipio::Extincao && mesmo_codigo(a.codigo, codigo))
+        .find_map(|a| a.codigo_relacionado.and_then(resolver_codigo))
+}
+
+fn nome_em(codigo: &'static str, ano: u16) -> String {
+    let alteracoes: Vec<&AlteracaoMunicipio> =
+        ALTERACOES.iter().filter(|a| a.codigo == codigo).collect();
+
+    // Se o município não existia no ano pedido, o território pertencia ao município relacionado
+    // (de origem, no caso de criações, ou incorporador, no caso de extinções).
+    let mut existencia = alteracoes.iter().filter(|a| {
+        matches!(
+            a.tipo,
+            TipoAlteracaoMunicipio::Criacao | TipoAlteracaoMunicipio::Extincao
+        )
+    });
+    let origem = match existencia.clone().rfind(|a| a.ano <= ano) {
+        Some(a) if a.tipo == TipoAlteracaoMunicipio::Extincao => a.codigo_relacionado,
+        Some(_) => None,
+        None => existencia
+            .next()
+            .filter(|a| a.tipo == TipoAlteracaoMunicipio::Criacao)
+            .and_then(|a| a.codigo_relacionado),
+    };
+
+    if let Some(origem) = origem.and_then(resolver_codigo) {
+        return nome_em(origem, ano);
+    }
+
+    // O nome vigente no ano pedido é o anterior à primeira renomeação posterior a ele.
+    alteracoes
+        .iter()
+        .find(|a| a.tipo == TipoAlteracaoMunicipio::Renomeacao && a.ano > ano)
+        .and_then(|a| a.nome_anterior)
+        .map(str::to_string)
+        .or_else(|| MUNICIPIOS_MAP.get(codigo).cloned())
+        .unwrap_or_default()
+}
+
 // ====== Funções Públicas =======
 
 /// Padroniza uma string representando município brasileiros.
@@ -85,7 +240,9 @@ pub fn criar_municipio_map() -> HashMap<String, String> {
 /// - remoção de zeros à esquerda;
 /// - busca, a partir do código numérico, do nome completo de cada município;
 /// - remoção de acentos e caracteres não ASCII, correção de erros ortográficos frequentes e atualização
-///   de nomes conforme listagem de municípios do IBGE de 2022.
+///   de nomes conforme listagem de municípios do IBGE de 2022;
+/// - substituição de nomes antigos de municípios renomeados, conforme a tabela histórica usada em
+///   [padronizar_municipios_em].
 ///
 /// Note que existe uma etapa de compilação das expressões regulares utilizadas,
 /// logo a primeira execução desta função pode demorar um pouco a mais.
@@ -98,6 +255,114 @@ pub fn padronizar_municipios(valor: &str) -> String {
     municipios.get(&res).unwrap_or(&res).to_string()
 }
 
+/// Obtém o código IBGE atual (7 dígitos) a partir de um código antigo ou atual, com 6 ou 7
+/// dígitos.
+///
+/// Códigos de municípios extintos são mapeados para o município que incorporou seu território,
+/// desde que a extinção esteja na tabela histórica descrita em [padronizar_municipios_em].
+/// Retorna `None` caso o código não seja reconhecido.
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::municipio::codigo_atual;
+/// assert_eq!(codigo_atual("3304557"), Some("3304557"));
+/// assert_eq!(codigo_atual("330455"), Some("3304557"));
+/// assert_eq!(codigo_atual(" 03304557 "), Some("3304557"));
+/// assert_eq!(codigo_atual("9999999"), None);
+/// ```
+///
+pub fn codigo_atual(codigo_antigo: &str) -> Option<&'static str> {
+    let padronizador = &*PADRONIZADOR;
+    let codigo = padronizador.padronizar_cow(codigo_antigo);
+    resolver_codigo(&codigo)
+}
+
+/// Obtém o código IBGE atual (7 dígitos) de um município a partir do seu código ou do seu nome.
+///
+/// Nomes compartilhados por mais de um município, como "CAMPO GRANDE", só são resolvidos quando
+/// o estado é informado. Retorna `None` caso o município não seja reconhecido ou seja ambíguo.
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::municipio::codigo_municipio;
+/// assert_eq!(codigo_municipio("330455", None), Some("3304557"));
+/// assert_eq!(codigo_municipio("Rio de Janeiro", None), Some("3304557"));
+/// assert_eq!(codigo_municipio("PARATI", None), Some("3303807"));
+/// assert_eq!(codigo_municipio("CAMPO GRANDE", None), None);
+/// assert_eq!(codigo_municipio("CAMPO GRANDE", Some("MS")), Some("5002704"));
+/// ```
+///
+pub fn codigo_municipio(valor: &str, estado: Option<&str>) -> Option<&'static str> {
+    if !valor.trim().is_empty() && valor.trim().bytes().all(|c| c.is_ascii_digit()) {
+        return codigo_atual(valor);
+    }
+
+    let codigo_estado = estado
+        .map(padronizar_estados_para_codigo)
+        .filter(|c| !c.is_empty());
+    let candidatos: Vec<&'static str> = CODIGOS_POR_NOME
+        .get(&padronizar_municipios(valor))?
+        .iter()
+        .filter(|c| codigo_estado.map_or(true, |uf| c.starts_with(uf)))
+        .copied()
+        .collect();
+
+    match candidatos.as_slice() {
+        [codigo] => Some(codigo),
+        _ => None,
+    }
+}
+
+/// Padroniza uma string representando município brasileiros para o nome vigente no ano informado.
+///
+/// Aceita tanto o código do IBGE (atual ou antigo) quanto o nome do município (atual ou antigo).
+/// Caso o município ainda não existisse (ou estivesse extinto) no ano informado, retorna o nome
+/// do município ao qual seu território pertencia naquele ano.
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::padronizar_municipios_em;
+/// assert_eq!(padronizar_municipios_em("3303807", 2000), "PARATI");
+/// assert_eq!(padronizar_municipios_em("3303807", 2022), "PARATY");
+/// assert_eq!(padronizar_municipios_em("EMBU DAS ARTES", 2005), "EMBU");
+/// assert_eq!(padronizar_municipios_em("EMBU", 2022), "EMBU DAS ARTES");
+/// assert_eq!(padronizar_municipios_em("BALNEARIO RINCAO", 2010), "ICARA");
+/// assert_eq!(padronizar_municipios_em("RIO DE JANEIRO", 2000), "RIO DE JANEIRO");
+/// ```
+///
+/// # Detalhes
+/// Além das operações de [padronizar_municipios], são usadas as alterações registradas na tabela
+/// histórica embutida nesta biblioteca. A tabela não é um histórico completo: ela cobre as
+/// renomeações de 2010 a 2021, as instalações de 2013 e a instalação, extinção e reinstalação de
+/// Pinto Bandeira (RS). Alterações fora dela não são consideradas.
+///
+/// Em particular, as demais instalações de municípios desde 2000 (como as de 2001, quando o
+/// número de municípios passou de 5.507 para 5.560) não estão na tabela: para esses municípios,
+/// a função retorna o nome atual mesmo em anos anteriores à sua instalação. Por isso, ela ainda
+/// não basta para compatibilizar séries longas, como RAIS ou Censo de 2000 a 2022, com a
+/// divisão territorial atual.
+///
+/// Nomes ambíguos, como "CAMPO GRANDE", não são resolvidos a um único município e, por isso,
+/// retornam o nome atual padronizado. Nesses casos, prefira usar o código do IBGE.
+///
+pub fn padronizar_municipios_em(valor: &str, ano: u16) -> String {
+    let nome_atual = padronizar_municipios(valor);
+
+    let codigo = if !valor.trim().is_empty() && valor.trim().bytes().all(|c| c.is_ascii_digit()) {
+        codigo_atual(valor)
+    } else {
+        match CODIGOS_POR_NOME.get(&nome_atual).map(Vec::as_slice) {
+            Some([codigo]) => Some(*codigo),
+            _ => None,
+        }
+    };
+
+    match codigo {
+        Some(codigo) => nome_em(codigo, ano),
+        None => nome_atual,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -114,5 +379,56 @@ mod tests {
         assert_eq!(padronizar_municipios("SÃO PAULO"), "SAO PAULO");
         assert_eq!(padronizar_municipios("MOJI MIRIM"), "MOGI MIRIM");
         assert_eq!(padronizar_municipios("PARATI"), "PARATY");
+        assert_eq!(padronizar_municipios("POXOREO"), "POXOREU");
+        assert_eq!(
+            padronizar_municipios("SANTANA DO LIVRAMENTO"),
+            "SANT'ANA DO LIVRAMENTO"
+        );
+    }
+
+    #[test]
+    fn obtem_codigo_atual() {
+        assert_eq!(codigo_atual("4314548"), Some("4314548"));
+        assert_eq!(codigo_atual("431454"), Some("4314548"));
+        assert_eq!(codigo_atual(""), None);
+        assert_eq!(codigo_atual("abc"), None);
+    }
+
+    #[test]
+    fn padroniza_municipios_em_ano() {
+        // Renomeações
+        assert_eq!(padronizar_municipios_em("2401305", 2005), "AUGUSTO SEVERO");
+        assert_eq!(padronizar_municipios_em("2401305", 2011), "CAMPO GRANDE");
+        assert_eq!(padronizar_municipios_em("PARATI", 2000), "PARATI");
+        assert_eq!(padronizar_municipios_em("PARATI", 2020), "PARATY");
+
+        // Pinto Bandeira: instalado em 2001, extinto em 2003 e reinstalado em 2013
+        assert_eq!(padronizar_municipios_em("4314548", 2000), "BENTO GONCALVES");
+        assert_eq!(padronizar_municipios_em("4314548", 2002), "PINTO BANDEIRA");
+        assert_eq!(padronizar_municipios_em("4314548", 2010), "BENTO GONCALVES");
+        assert_eq!(padronizar_municipios_em("4314548", 2013), "PINTO BANDEIRA");
+
+        // Nome ambíguo e valores desconhecidos
+        assert_eq!(
+            padronizar_municipios_em("CAMPO GRANDE", 2000),
+            "CAMPO GRANDE"
+        );
+        assert_eq!(padronizar_municipios_em("ATLANTIDA", 2000), "ATLANTIDA");
+
+        // Nome antigo de Serra Caiada (RN), mas nome atual de municípios do MA e de MG.
+        assert_eq!(
+            padronizar_municipios("PRESIDENTE JUSCELINO"),
+            "PRESIDENTE JUSCELINO"
+        );
+        assert_eq!(
+            padronizar_municipios_em("PRESIDENTE JUSCELINO", 2022),
+            "PRESIDENTE JUSCELINO"
+        );
+        assert_eq!(
+            padronizar_municipios_em("2410306", 2010),
+            "PRESIDENTE JUSCELINO"
+        );
+        assert_eq!(padronizar_municipios_em("2410306", 2022), "SERRA CAIADA");
+        assert_eq!(padronizar_municipios_em("", 2000), "");
     }
 }
@@ -0,0 +1,216 @@
+use std::sync::LazyLock;
+
+use crate::{
+    abreviacao::{abreviacoes_padrao, adicionar_abreviacoes, Abreviacao},
+    Padronizador,
+};
+
+/// Adiciona ao padronizador as regras de expansão de títulos honoríficos, militares, religiosos
+/// e de cargos públicos (GEN, CEL, DR, PE., N. SRA, PRES, etc.).
+pub(crate) fn adicionar_titulos(padronizador: &mut Padronizador) {
+    padronizador
+        .adicionar(r"\bSTA\b\.?", "SANTA")
+        .adicionar(r"\bSTO\b\.?", "SANTO")
+        .adicionar(r"\b(N(OS|SS?A?)?\.? S(RA|ENHORA)|(NOSSA|NSA\.?) (S(RA?)?|SEN(H(OR)?)?))\b\.?", "NOSSA SENHORA")
+        .adicionar(r"\b(N(O?S)?\.? S(R|EN(H(OR)?)?)?\.?( DE?)?|NOSSA SENHORA|NS) (FAT.*|LO?UR.*|SANTANA|GUADALUPE|NAZ.*|COP*)\b", "NOSSA SENHORA DE $7")
+        .adicionar(r"\b(N(O?S)?\.? S(R|EN(H(OR)?)?)?\.?( D(A|E)?)?|NOSSA SENHORA|NS) (GRACA|VITORIA|PENHA|CONCEICAO|PAZ|GUIA|AJUDA|CANDELARIA|PURIFICACAO|SAUDE|PIEDADE|ABADIA|GLORIA|SALETE|APRESENTACAO)\b", "NOSSA SENHORA DA $8")
+        .adicionar(r"\b(N(O?S)?\.? S(R|EN(H(OR)?)?)?\.?( D(A|E)?)?|NOSSA SENHORA D(A|E)|NS) (APA.*|AUX.*|MEDIANEIRA|CONSOLADORA)\b", "NOSSA SENHORA $9")
+        .adicionar(r"\b(N(O?S)?\.? S(R|EN(H(OR)?)?)?\.?( D(OS?)?)?|NOSSA SENHORA|NS) (NAVEGANTES)\b", "NOSSA SENHORA DOS $8")
+        .adicionar(r"\b(N(O?S)?\.? S(R|EN(H(OR)?)?)?\.?( DO?)?|NOSSA SENHORA|NS) (CARMO|LIVRAMENTO|RETIRO|SION|ROSARIO|PILAR|ROCIO|CAMINHO|DESTERRO|BOM CONSELHO|AMPARO|PERP.*|P.* S.*)\b", "NOSSA SENHORA DO $7")
+        .adicionar(r"\b(N(O?S)?\.? S(R|EN(H(OR)?)?)?\.?( D(AS?)?)?|NOSSA SENHORA|NS) (GRACAS|DORES)\b", "NOSSA SENHORA DAS $8")
+        .adicionar(r"\b(S(R|ENH?)\.?( D(OS?)?)?|SENHOR( D(OS)?)?) (BON\w*)\b", "SENHOR DO BONFIM")
+        .adicionar(r"\b(S(R|ENH?)\.?( D(OS?)?)?|SENHOR( D(OS?)?)?) (BOM ?F\w*)\b", "SENHOR DO BONFIM")
+        .adicionar(r"\b(S(R|ENH?)\.?( D(OS?)?)?|SENHOR) (PASS\w*|MONT\w*)\b", "SENHOR DOS $5")
+        .adicionar(r"\bS(R|ENH?)\.? (BOM J\w*)\b", "SENHOR BOM JESUS")
+        .adicionar(r"\b(N(O?S)?\.? S(R|EN(H(OR)?)?)?\.?( D(OS?)?)?|NOSSO SENHOR|NS) (BONF\w*|BOM ?F\w*)\b", "NOSSO SENHOR DO BONFIM")
+        .adicionar(r"\b(N(O?S)?\.? S(R|EN(H(OR)?)?)?\.?( D(OS?)?)?|NOSSO SENHOR|NS) (PASS\w*|MONT\w*)\b", "NOSSO SENHOR DOS $8")
+
+        .adicionar(r"\bALM?TE\b\.?", "ALMIRANTE")
+        .adicionar(r"\bMAL\b\.?", "MARECHAL")
+        .adicionar(r"\b(GEN|GAL)\b\.?", "GENERAL")
+        .adicionar(r"\b(SGTO?|SARG)\b\.?", "SARGENTO")
+        .adicionar(r"\b(PRIMEIRO|PRIM|1)\.? SARGENTO\b", "PRIMEIRO-SARGENTO")
+        .adicionar(r"\b(SEGUNDO|SEG|2)\.? SARGENTO\b", "SEGUNDO-SARGENTO")
+        .adicionar(r"\b(TERCEIRO|TERC|3)\.? SARGENTO\b", "TERCEIRO-SARGENTO")
+        .adicionar(r"\bCEL\b\.?", "CORONEL")
+        .adicionar(r"\bBRIG\b\.?", "BRIGADEIRO")
+        .adicionar(r"\bTEN\b\.?", "TENENTE")
+        .adicionar(r"\bTENENTE CORONEL\b", "TENENTE-CORONEL")
+        .adicionar(r"\bTENENTE BRIGADEIRO\b", "TENENTE-BRIGADEIRO")
+        .adicionar(r"\bTENENTE AVIADOR\b", "TENENTE-AVIADOR")
+        .adicionar(r"\bSUB TENENTE\b", "SUBTENENTE")
+        .adicionar(r"\b(PRIMEIRO|PRIM\.?) TENENTE\b", "PRIMEIRO-TENENTE")
+        .adicionar(r"\b(SEGUNDO|SEG\.?) TENENTE\b", "SEGUNDO-TENENTE")
+        .adicionar(r"\bSOLD\b\.?", "SOLDADO")
+        .adicionar(r"\bMAJ\b\.?", "MAJOR")
+
+        .adicionar(r"\bPROF\b\.?", "PROFESSOR")
+        .adicionar(r"\bPROFA\b\.?", "PROFESSORA")
+        .adicionar(r"\bDR\b\.?", "DOUTOR")
+        .adicionar(r"\bDRA\b\.?", "DOUTORA")
+        .adicionar(r"\bENG\b\.?", "ENGENHEIRO")
+        .adicionar(r"\bENGA\b\.?", "ENGENHEIRA")
+        .adicionar(r"\bPD?E\b\.", "PADRE") // PE pode ser só pe mesmo, então forcando o PE. (com ponto) pra ser PADRE
+        .adicionar(r"\bMONS\b\.?", "MONSENHOR")
+
+        // Erros de digitação comuns para presidente. => Sem testes
+        .adicionar(r"\b(PREISI|PREZI|PRSI|PERSI|PESI)DENTE\b", "PRESIDENTE")
+
+        .adicionar(r"\bPRES(ID)?\b\.?", "PRESIDENTE")
+        .adicionar(r"\bGOV\b\.?", "GOVERNADOR")
+        .adicionar(r"\bSEN\b\.?", "SENADOR")
+        .adicionar(r"\bPREF\b\.?", "PREFEITO")
+        .adicionar(r"\bDEP\b\.?", "DEPUTADO")
+        // PS: Regex original tinha um look-ahead (?!$) que o motor do Rust não permite.
+        // Troquei ele por um espaço em branco para garantir que não é no fim da string.
+        .adicionar(r"\bVER\b\.?(.)", "VEREADOR$1")
+        .adicionar(r"\bESPL?\.? (DOS )?MIN(IST(ERIOS?)?)?\b\.?", "ESPLANADA DOS MINISTERIOS")
+        // PS: Regex original tinha um look-ahead (?!$) que o motor do Rust não permite.
+        // Troquei ele por um espaço em branco para garantir que não é no fim da string.
+        .adicionar(r"\bMIN\b\.?(.)", "MINISTRO$1")
+
+        .adicionar(r"\b(CMTE|COMTE|COMAND)\b\.?", "COMANDANTE")
+        .adicionar(r"\bCAP\b\.", "CAPITAO") // CAP sem ponto pode ser sigla, e.g. "CAP 2"
+        .adicionar(r"\bVISC\b\.?", "VISCONDE")
+        .adicionar(r"\bDES\b\.", "DESEMBARGADOR")
+        .adicionar(r"\bFR\b\. ", "FREI ");
+}
+
+/// Adiciona ao padronizador as regras de nomes próprios e de abreviações que podem ser tanto
+/// nomes quanto títulos, desambiguadas pelo nome que as segue.
+pub(crate) fn adicionar_nomes(padronizador: &mut Padronizador) {
+    padronizador
+        // interseção entre nomes e títulos
+        //   - D. pode ser muita coisa (e.g. dom vs dona), então não da pra
+        //   simplesmente assumir que vai ser um valor especifico, so no contexto
+        //   - MAR pode ser realmente só mar ou uma abreviação pra marechal
+        .adicionar(r"\bD\b\.? (PEDRO|JOAO|HENRIQUE)", "DOM $1")
+        .adicionar(r"\bI(NF)?\.? DOM\b", "INFANTE DOM")
+        .adicionar(r"\bMAR\b\.? ((CARMONA|JOFRE|HERMES|MALLET|DEODORO|MARCIANO|OTAVIO|FLORIANO|BARBACENA|FIUZA|MASCARENHAS|MASCARENHA|TITO|FONTENELLE|XAVIER|BITENCOURT|BITTENCOURT|CRAVEIRO|OLIMPO|CANDIDO|RONDON|HENRIQUE|MIGUEL|JUAREZ|FONTENELE|FONTENELLE|DEADORO|HASTIMPHILO|NIEMEYER|JOSE|LINO|MANOEL|HUMB?|HUMBERTO|ARTHUR|ANTONIO|NOBREGA|CASTELO|DEODORA)\b)", "MARECHAL $1")
+
+        // nomes
+        .adicionar(r"\b(GETULHO|JETULHO|JETULIO|JETULHO|GET|JET)\.? VARGAS\b", "GETULIO VARGAS")
+        .adicionar(r"\b(J(U[A-Z]*)?)\.? (K(U[A-Z]*)?)\b\.?", "JUSCELINO KUBITSCHEK")
+
+        // D. também é usado para "DONA" antes de nomes femininos
+        .adicionar(r"\bD\b\.? (MARIA|ANA|ISABEL|LEOPOLDINA|AMELIA|CAROLINA|FRANCISCA|JOANA|TERESA|TEREZA|CLARA|BENTA|GERTRUDES|ANTONIA|ADELAIDE)\b", "DONA $1")
+        // S. é usado para "SAO" antes de nomes de santos
+        .adicionar(r"\bS\b\.? (PAULO|PEDRO|JOAO|JOSE|JORGE|VICENTE|FRANCISCO|DOMINGOS?|CRISTOVAO|SEBASTIAO|BENEDITO|BENTO|LUIZ|LUIS|MIGUEL|JUDAS|LOURENCO|CAETANO|GONCALO|MATEUS|MARCOS|LUCAS|TOME|THOME|BRAZ|BRAS)\b", "SAO $1");
+}
+
+pub fn criar_padronizador_nome_proprio() -> Padronizador {
+    criar_padronizador_nome_proprio_com(&abreviacoes_padrao())
+}
+
+/// Cria o padronizador com as abreviações informadas, vide
+/// [crate::abreviacao::DicionarioAbreviacoes].
+pub(crate) fn criar_padronizador_nome_proprio_com(abreviacoes: &[Abreviacao]) -> Padronizador {
+    let mut padronizador = Padronizador::default();
+    padronizador
+        .adicionar(r"\s{2,}", " ")
+        .adicionar(r"\.\.+", ".") // remover pontos repetidos
+        .adicionar(r"\.([^ ])", ". $1") // garantir que haja espaco depois do ponto
+        .adicionar(r" \.", "."); // garantir que não haja um espaço antes dos pontos
+
+    adicionar_titulos(&mut padronizador);
+    adicionar_nomes(&mut padronizador);
+
+    // Abreviações compartilhadas com os outros padronizadores (JD, PQ, RES, CONJ, etc.)
+    adicionar_abreviacoes(&mut padronizador, abreviacoes);
+
+    padronizador.adicionar(r"\.$", ""); // remoção de ponto final
+
+    padronizador.preparar();
+    padronizador
+}
+
+// Em Rust, a constant é criada durante a compilação, então só posso chamar funções muito restritas
+// quando uso `const`. Nesse caso,  como tenho uma construção complexa da struct `Padronizador`,
+// tenho que usar static com inicialização Lazy (o LazyLock aqui previne condições de corrida).
+static PADRONIZADOR: LazyLock<Padronizador> = LazyLock::new(criar_padronizador_nome_proprio);
+
+/// Padroniza o nome próprio de um logradouro, bairro ou condomínio, expandindo títulos e
+/// abreviações de nomes.
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::nome_proprio::padronizar_nome_proprio;
+/// assert_eq!(padronizar_nome_proprio("STA. EFIGENIA"), "SANTA EFIGENIA");
+/// assert_eq!(padronizar_nome_proprio("N. SRA. DE FATIMA"), "NOSSA SENHORA DE FATIMA");
+/// assert_eq!(padronizar_nome_proprio("DR. ARNALDO"), "DOUTOR ARNALDO");
+/// assert_eq!(padronizar_nome_proprio("PE. ANCHIETA"), "PADRE ANCHIETA");
+/// assert_eq!(padronizar_nome_proprio("CEL. FAGUNDES"), "CORONEL FAGUNDES");
+/// assert_eq!(padronizar_nome_proprio("D. PEDRO II"), "DOM PEDRO II");
+/// assert_eq!(padronizar_nome_proprio("D. MARIA"), "DONA MARIA");
+/// ```
+///
+/// # Detalhes
+/// Operações realizadas durante a padronização:
+/// - remoção de espaços em excesso e adição de espaços após abreviações sinalizadas por pontos;
+/// - expansão de títulos honoríficos, militares, religiosos e de cargos públicos;
+/// - expansão de nomes frequentemente abreviados, como "JK" e "GET. VARGAS";
+/// - expansão das abreviações compartilhadas com os outros padronizadores.
+///
+/// Abreviações ambíguas são expandidas apenas quando o contexto permite a desambiguação:
+/// "D." vira "DOM" antes de "PEDRO" e "DONA" antes de "MARIA", "MAR." vira "MARECHAL" antes
+/// de "DEODORO", mas "MAR" isolado permanece inalterado, e "PE" só vira "PADRE" com ponto.
+///
+/// Note que existe uma etapa de compilação das expressões regulares utilizadas,
+/// logo a primeira execução desta função pode demorar um pouco a mais.
+///
+pub fn padronizar_nome_proprio(valor: &str) -> String {
+    // Forma de obter a variável lazy
+    let padronizador = &*PADRONIZADOR;
+    padronizador.padronizar(valor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn padroniza_corretamente() {
+        let casos = [
+            ("STA CRUZ", "SANTA CRUZ"),
+            ("STO. ANTONIO", "SANTO ANTONIO"),
+            ("NSA SRA DA PENHA", "NOSSA SENHORA DA PENHA"),
+            ("N.S. DOS NAVEGANTES", "NOSSA SENHORA DOS NAVEGANTES"),
+            ("GEN. OSORIO", "GENERAL OSORIO"),
+            ("TEN CEL CARDOSO", "TENENTE-CORONEL CARDOSO"),
+            ("PRES. VARGAS", "PRESIDENTE VARGAS"),
+            ("PROFA. MARIA", "PROFESSORA MARIA"),
+            ("MONS. TABOSA", "MONSENHOR TABOSA"),
+            ("CMTE. FRANCO", "COMANDANTE FRANCO"),
+            ("CAP. MOR", "CAPITAO MOR"),
+            ("VISC. DE MAUA", "VISCONDE DE MAUA"),
+            ("DES. LEMOS", "DESEMBARGADOR LEMOS"),
+            ("FR. CANECA", "FREI CANECA"),
+            ("S. JOSE", "SAO JOSE"),
+            ("MAR. DEODORO", "MARECHAL DEODORO"),
+            ("J. K.", "JUSCELINO KUBITSCHEK"),
+            ("GET. VARGAS", "GETULIO VARGAS"),
+            ("D. ISABEL", "DONA ISABEL"),
+            ("D. JOAO VI", "DOM JOAO VI"),
+            ("JD. DAS FLORES", "JARDIM DAS FLORES"),
+        ];
+
+        for (entrada, esperado) in casos {
+            assert_eq!(padronizar_nome_proprio(entrada), esperado);
+        }
+    }
+
+    #[test]
+    fn preserva_casos_ambiguos() {
+        let casos = [
+            ("BEIRA MAR", "BEIRA MAR"),
+            ("PE DE SERRA", "PE DE SERRA"),
+            ("D SILVA", "D SILVA"),
+            ("CAP 2", "CAP 2"),
+            ("S 10", "S 10"),
+        ];
+
+        for (entrada, esperado) in casos {
+            assert_eq!(padronizar_nome_proprio(entrada), esperado);
+        }
+    }
+}
@@ -1,6 +1,11 @@
-use std::sync::LazyLock;
+use std::{fmt, sync::LazyLock};
 
-use crate::Padronizador;
+use regex::Regex;
+
+use crate::{
+    quilometro::{padronizar_quilometro, Quilometro},
+    Padronizador,
+};
 
 pub fn criar_padronizador_numeros() -> Padronizador {
     let mut padronizador = Padronizador::default();
@@ -43,18 +48,28 @@ static PADRONIZADOR_NUMEROS: LazyLock<Padronizador> = LazyLock::new(criar_padron
 /// assert_eq!(padronizar_numeros("S/N"), "S/N");
 /// assert_eq!(padronizar_numeros("SN"), "S/N");
 /// assert_eq!(padronizar_numeros("0180 0181"), "180 181");
+/// assert_eq!(padronizar_numeros("km 1.200"), "KM 1200");
+/// assert_eq!(padronizar_numeros("km 1,2"), "KM 1,2");
+/// assert_eq!(padronizar_numeros("KM 23.5"), "KM 23,5");
 /// ```
 ///
 /// # Detalhes
 /// Operações realizadas durante a padronização:
 /// - remoção de espaços em branco antes e depois dos números e de espaços em branco em excesso entre números;
 /// - remoção de zeros à esquerda;
-/// - substituição de números vazios e de variações de SN (SN, S N, S.N., S./N., etc) por S/N.
+/// - substituição de números vazios e de variações de SN (SN, S N, S.N., S./N., etc) por S/N;
+/// - padronização de marcos quilométricos para a forma "KM 23,5", vide [padronizar_quilometro].
 ///
 /// Note que existe uma etapa de compilação das expressões regulares utilizadas,
 /// logo a primeira execução desta função pode demorar um pouco a mais.
 ///
 pub fn padronizar_numeros(valor: &str) -> String {
+    // Marcos quilométricos são tratados antes, para chegar à forma canônica
+    // ("KM 23,5") independentemente da grafia, como em "KM. 023,5".
+    if let Some(km) = padronizar_quilometro(valor) {
+        return km.to_string();
+    }
+
     // Forma de obter a variável lazy
     let padronizador = &*PADRONIZADOR_NUMEROS;
     padronizador.padronizar(valor)
@@ -117,6 +132,175 @@ pub fn padronizar_numeros_para_string<T: Into<f64> + Copy>(valor: T) -> String {
     }
 }
 
+/// Representação estruturada de um número de logradouro.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NumeroEndereco {
+    /// Número simples, como "210".
+    Inteiro(u32),
+    /// Número seguido de letras, como "45A" ou "12-B".
+    ComSufixo { numero: u32, sufixo: String },
+    /// Faixa de números, como "120-130" ou "120 A 130".
+    Intervalo { inicio: u32, fim: u32 },
+    /// Marco quilométrico, como "KM 12,5".
+    Quilometro(Quilometro),
+    /// Ausência de número (S/N e variações).
+    SemNumero,
+    /// Vários números no mesmo campo, como "180 181" ou "10/12".
+    Multiplo(Vec<NumeroEndereco>),
+    /// Valor que não pôde ser interpretado, já padronizado por [padronizar_numeros].
+    NaoReconhecido(String),
+}
+
+impl NumeroEndereco {
+    /// Obtém o número inteiro que melhor representa o valor: o próprio número, o número sem o
+    /// sufixo, o início do intervalo ou o primeiro número reconhecido de um valor múltiplo.
+    ///
+    /// # Exemplo
+    /// ```
+    /// use enderecobr_rs::numero::separar_numero;
+    /// assert_eq!(separar_numero("45A").numero(), Some(45));
+    /// assert_eq!(separar_numero("120-130").numero(), Some(120));
+    /// assert_eq!(separar_numero("S/N").numero(), None);
+    /// ```
+    pub fn numero(&self) -> Option<u32> {
+        match self {
+            NumeroEndereco::Inteiro(n) => Some(*n),
+            NumeroEndereco::ComSufixo { numero, .. } => Some(*numero),
+            NumeroEndereco::Intervalo { inicio, .. } => Some(*inicio),
+            NumeroEndereco::Multiplo(numeros) => numeros.iter().find_map(|n| n.numero()),
+            NumeroEndereco::Quilometro(_)
+            | NumeroEndereco::SemNumero
+            | NumeroEndereco::NaoReconhecido(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for NumeroEndereco {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NumeroEndereco::Inteiro(n) => write!(f, "{}", n),
+            NumeroEndereco::ComSufixo { numero, sufixo } => write!(f, "{}{}", numero, sufixo),
+            NumeroEndereco::Intervalo { inicio, fim } => write!(f, "{}-{}", inicio, fim),
+            NumeroEndereco::Quilometro(km) => write!(f, "{}", km),
+            NumeroEndereco::SemNumero => write!(f, "S/N"),
+            NumeroEndereco::Multiplo(numeros) => {
+                let partes: Vec<String> = numeros.iter().map(|n| n.to_string()).collect();
+                write!(f, "{}", partes.join(" "))
+            }
+            NumeroEndereco::NaoReconhecido(valor) => write!(f, "{}", valor),
+        }
+    }
+}
+
+static REGEX_INTERVALO: LazyLock<Regex> = LazyLock::new(criar_regex_intervalo);
+static REGEX_SUFIXO: LazyLock<Regex> = LazyLock::new(criar_regex_sufixo);
+static REGEX_SEPARADOR_MULTIPLO: LazyLock<Regex> = LazyLock::new(criar_regex_separador_multiplo);
+
+#[allow(clippy::expect_used)]
+fn criar_regex_intervalo() -> Regex {
+    Regex::new(r"^(\d+) ?(?:-|\bA\b|\bAO\b|\bATE\b) ?(\d+)$")
+        .expect("Regex de intervalo inválida (bug interno)")
+}
+
+#[allow(clippy::expect_used)]
+fn criar_regex_sufixo() -> Regex {
+    Regex::new(r"^(\d+)[ -]?([A-Z]{1,2})$").expect("Regex de sufixo inválida (bug interno)")
+}
+
+#[allow(clippy::expect_used)]
+fn criar_regex_separador_multiplo() -> Regex {
+    Regex::new(r" ?[,;] ?| E | ").expect("Regex de separador inválida (bug interno)")
+}
+
+/// Interpreta uma string representando números de logradouros em uma estrutura [NumeroEndereco].
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::numero::{separar_numero, NumeroEndereco};
+/// assert_eq!(separar_numero("0210"), NumeroEndereco::Inteiro(210));
+/// assert_eq!(separar_numero("45A"), NumeroEndereco::ComSufixo { numero: 45, sufixo: "A".to_string() });
+/// assert_eq!(separar_numero("120-130"), NumeroEndereco::Intervalo { inicio: 120, fim: 130 });
+/// assert_eq!(separar_numero("KM 12,5").to_string(), "KM 12,5");
+/// assert_eq!(separar_numero("S/N"), NumeroEndereco::SemNumero);
+/// assert_eq!(
+///     separar_numero("0180 0181"),
+///     NumeroEndereco::Multiplo(vec![NumeroEndereco::Inteiro(180), NumeroEndereco::Inteiro(181)])
+/// );
+/// assert_eq!(separar_numero("FUNDOS"), NumeroEndereco::NaoReconhecido("FUNDOS".to_string()));
+/// ```
+///
+/// # Detalhes
+/// O valor é padronizado com [padronizar_numeros] e então classificado, nesta ordem, como
+/// marco quilométrico, ausência de número, inteiro, intervalo (separado por "-", "A", "AO" ou
+/// "ATE", com início menor que o fim), número com sufixo de até duas letras ou múltiplos
+/// números (separados por espaço, ",", ";", "/" ou "E"). Valores múltiplos só são reconhecidos
+/// se todas as suas partes forem reconhecidas.
+///
+pub fn separar_numero(valor: &str) -> NumeroEndereco {
+    if let Some(km) = padronizar_quilometro(valor) {
+        return NumeroEndereco::Quilometro(km);
+    }
+
+    let valor_padronizado = padronizar_numeros(valor);
+    separar_numero_padronizado(&valor_padronizado)
+}
+
+fn separar_numero_padronizado(valor: &str) -> NumeroEndereco {
+    if valor == "S/N" {
+        return NumeroEndereco::SemNumero;
+    }
+
+    if let Ok(numero) = valor.parse::<u32>() {
+        return NumeroEndereco::Inteiro(numero);
+    }
+
+    if let Some(intervalo) = REGEX_INTERVALO.captures(valor) {
+        let inicio = intervalo[1].parse::<u32>();
+        let fim = intervalo[2].parse::<u32>();
+        if let (Ok(inicio), Ok(fim)) = (inicio, fim) {
+            if inicio < fim {
+                return NumeroEndereco::Intervalo { inicio, fim };
+            }
+        }
+    }
+
+    if let Some(sufixo) = REGEX_SUFIXO.captures(valor) {
+        if let Ok(numero) = sufixo[1].parse::<u32>() {
+            return NumeroEndereco::ComSufixo {
+                numero,
+                sufixo: sufixo[2].to_string(),
+            };
+        }
+    }
+
+    // A barra também separa números ("10/12"), exceto no próprio "S/N".
+    let partes: Vec<&str> = REGEX_SEPARADOR_MULTIPLO
+        .split(valor)
+        .flat_map(|p| {
+            if p == "S/N" {
+                vec![p]
+            } else {
+                p.split('/').collect()
+            }
+        })
+        .filter(|p| !p.is_empty())
+        .collect();
+
+    if partes.len() > 1 {
+        let numeros: Vec<NumeroEndereco> =
+            partes.into_iter().map(separar_numero_padronizado).collect();
+
+        if numeros
+            .iter()
+            .all(|n| !matches!(n, NumeroEndereco::NaoReconhecido(_)))
+        {
+            return NumeroEndereco::Multiplo(numeros);
+        }
+    }
+
+    NumeroEndereco::NaoReconhecido(valor.to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -148,6 +332,9 @@ mod tests {
             ("S. N.", "S/N"),
             ("S. Nº.", "S/N"),
             ("S/N", "S/N"),
+            ("KM 12,5", "KM 12,5"),
+            ("KM 1.200", "KM 1200"),
+            ("KM 23.5", "KM 23,5"),
             ("S/Nº", "S/N"),
             ("S./N.", "S/N"),
             ("S./Nº.", "S/N"),
@@ -205,4 +392,84 @@ mod tests {
             assert_eq!(padronizar_numeros_para_int(entrada), esperado);
         }
     }
+
+    #[test]
+    fn separa_numeros() {
+        use crate::quilometro;
+        use NumeroEndereco::*;
+
+        let casos = [
+            ("1", Inteiro(1)),
+            ("20.100", Inteiro(20100)),
+            ("", SemNumero),
+            ("S. N.", SemNumero),
+            (
+                "45 A",
+                ComSufixo {
+                    numero: 45,
+                    sufixo: "A".to_string(),
+                },
+            ),
+            (
+                "12-bc",
+                ComSufixo {
+                    numero: 12,
+                    sufixo: "BC".to_string(),
+                },
+            ),
+            (
+                "120 A 130",
+                Intervalo {
+                    inicio: 120,
+                    fim: 130,
+                },
+            ),
+            (
+                "120 ATE 130",
+                Intervalo {
+                    inicio: 120,
+                    fim: 130,
+                },
+            ),
+            ("km 7", Quilometro(quilometro::Quilometro::de_metros(7_000))),
+            (
+                "KM. 1.200",
+                Quilometro(quilometro::Quilometro::de_metros(1_200_000)),
+            ),
+            ("10/12", Multiplo(vec![Inteiro(10), Inteiro(12)])),
+            ("10 E 12", Multiplo(vec![Inteiro(10), Inteiro(12)])),
+            ("S/N S N", Multiplo(vec![SemNumero, SemNumero])),
+            (
+                "10, 12A",
+                Multiplo(vec![
+                    Inteiro(10),
+                    ComSufixo {
+                        numero: 12,
+                        sufixo: "A".to_string(),
+                    },
+                ]),
+            ),
+            ("130-120", NaoReconhecido("130-120".to_string())),
+            ("CASA 2", NaoReconhecido("CASA 2".to_string())),
+        ];
+
+        for (entrada, esperado) in casos {
+            assert_eq!(separar_numero(entrada), esperado, "{}", entrada);
+        }
+    }
+
+    #[test]
+    fn formata_numeros() {
+        let casos = [
+            ("0045a", "45A"),
+            ("120 A 130", "120-130"),
+            ("KM 12,5", "KM 12,5"),
+            ("SN", "S/N"),
+            ("01 02", "1 2"),
+        ];
+
+        for (entrada, esperado) in casos {
+            assert_eq!(separar_numero(entrada).to_string(), esperado);
+        }
+    }
 }
@@ -1,6 +1,9 @@
-use std::{borrow::Cow, sync::LazyLock};
+use std::{borrow::Cow, collections::HashMap, sync::LazyLock};
 
-use regex::Regex;
+use itertools::Itertools;
+use regex::{Captures, Regex};
+
+use crate::normalizar;
 
 const ATE_CEM: [&str; 101] = [
     "ZERO",
@@ -124,78 +127,209 @@ const ORDENS_GRANDEZA: [(&str, &str); 7] = [
     ("UM MILHAO", "MILHOES"),
     ("UM BILHAO", "BILHOES"), // Só i32 vai até aqui...
     ("UM TRILHAO", "TRILHOES"),
-    ("UM QUADRILHAO", "QUADRILHOES"),
+    ("UM QUATRILHAO", "QUATRILHOES"),
     ("UM QUINTILHAO", "QUINTILHOES"),
     ("UM SEXTILHAO", "SEXTILHOES"),
 ];
 
-/// Converte sequências de dígitos em uma string para seus equivalentes por extenso em português.
-///
-/// A função percorre a string de entrada e, ao encontrar números inteiros (em formato ASCII),
-/// os substitui pelo nome completo do número (ex: "2" → "dois"), utilizando a função `numero_por_extenso`.
-///
-/// # Notas
-/// - Números muito grandes ou inválidos (ex: overflow no parse para `i32`) são deixados inalterados.
-/// - Não trata número negativos ou decimais.
-/// - Se a string de entrada não contém nenhum dígito ASCII, a função retorna imediatamente uma referência
-///   emprestada (`Cow::Borrowed`) para evitar alocação.
+/// Opções da escrita de números por extenso.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OpcoesExtenso {
+    /// Gênero do substantivo que o número acompanha: "DUAS CASAS", "DUZENTAS CASAS". Os
+    /// milhões, bilhões, etc. são sempre masculinos: "DOIS MILHOES DE CASAS".
+    pub genero: Genero,
+    /// Trata os pontos seguidos de três dígitos como separadores de milhar ("1.000" → "MIL"),
+    /// em [padronizar_numeros_por_extenso_com].
+    pub separador_milhar: bool,
+    /// Escreve as casas decimais após uma vírgula ou ponto ("2,5" → "DOIS VIRGULA CINCO"), em
+    /// [padronizar_numeros_por_extenso_com].
+    pub decimais: bool,
+}
+
+impl Default for OpcoesExtenso {
+    fn default() -> Self {
+        OpcoesExtenso {
+            genero: Genero::Masculino,
+            separador_milhar: true,
+            decimais: true,
+        }
+    }
+}
+
+static REGEX_NUMERO: LazyLock<Regex> = LazyLock::new(criar_regex_numero);
+
+#[allow(clippy::expect_used)]
+fn criar_regex_numero() -> Regex {
+    // Sinal apenas no início ou após espaço, para não confundir com hífens como em "5-A".
+    Regex::new(r"(?:(^|\s)(-))?\b(?:(\d{1,3}(?:\.\d{3})+)\b|(\d+))(?:([.,])(\d+))?")
+        .expect("Regex de números inválida (bug interno)")
+}
+
+fn numero_texto_por_extenso(
+    negativo: bool,
+    inteiro: &str,
+    decimais: Option<&str>,
+    opcoes: &OpcoesExtenso,
+) -> Option<String> {
+    let inteiro: u64 = inteiro.replace('.', "").parse().ok()?;
+    let mut resultado = String::new();
+
+    if negativo {
+        resultado.push_str("MENOS ");
+    }
+    resultado.push_str(&natural_por_extenso(inteiro, opcoes));
+
+    if let Some(decimais) = decimais {
+        resultado.push_str(" VIRGULA");
+        // Os zeros à esquerda são lidos um a um: "2,05" → "DOIS VIRGULA ZERO CINCO".
+        let significativos = decimais.trim_start_matches('0');
+        for _ in 0..decimais.len() - significativos.len() {
+            resultado.push_str(" ZERO");
+        }
+        if !significativos.is_empty() {
+            let valor: u64 = significativos.parse().ok()?;
+            resultado.push(' ');
+            resultado.push_str(&natural_por_extenso(valor, opcoes));
+        }
+    }
+
+    Some(resultado)
+}
+
+/// Converte sequências de dígitos em uma string para seus equivalentes por extenso em português,
+/// com as opções padrão. Vide [padronizar_numeros_por_extenso_com].
 ///
 /// # Exemplos
 /// ```rust
 /// use enderecobr_rs::numero_extenso::padronizar_numeros_por_extenso;
 /// assert_eq!(padronizar_numeros_por_extenso("RUA 2"), "RUA DOIS");
-/// assert_eq!(padronizar_numeros_por_extenso("RUA -2"), "RUA -DOIS");
-/// assert_eq!(padronizar_numeros_por_extenso("RUA -2.2"), "RUA -DOIS.DOIS");
+/// assert_eq!(padronizar_numeros_por_extenso("RUA -2"), "RUA MENOS DOIS");
+/// assert_eq!(padronizar_numeros_por_extenso("RUA -2.2"), "RUA MENOS DOIS VIRGULA DOIS");
+/// assert_eq!(padronizar_numeros_por_extenso("RUA 1.500"), "RUA MIL E QUINHENTOS");
 /// assert_eq!(padronizar_numeros_por_extenso("Sem números"), "Sem números");
 /// ```
 ///
 pub fn padronizar_numeros_por_extenso(texto: &str) -> Cow<'_, str> {
+    padronizar_numeros_por_extenso_com(texto, &OpcoesExtenso::default())
+}
+
+/// Converte sequências de dígitos em uma string para seus equivalentes por extenso em português.
+///
+/// A função percorre a string de entrada e, ao encontrar números (em formato ASCII), os
+/// substitui pelo nome completo do número (ex: "2" → "DOIS"), utilizando a função
+/// [natural_por_extenso].
+///
+/// # Exemplos
+/// ```rust
+/// use enderecobr_rs::numero_extenso::{padronizar_numeros_por_extenso_com, Genero, OpcoesExtenso};
+/// let opcoes = OpcoesExtenso {
+///     genero: Genero::Feminino,
+///     ..Default::default()
+/// };
+/// assert_eq!(padronizar_numeros_por_extenso_com("RUA 2 BARRAS", &opcoes), "RUA DUAS BARRAS");
+///
+/// let opcoes = OpcoesExtenso {
+///     separador_milhar: false,
+///     decimais: false,
+///     ..Default::default()
+/// };
+/// assert_eq!(padronizar_numeros_por_extenso_com("1.500", &opcoes), "UM.QUINHENTOS");
+/// ```
+///
+/// # Notas
+/// - Os pontos seguidos de exatamente três dígitos são separadores de milhar ("1.500"), caso
+///   `separador_milhar` seja verdadeiro. Os demais pontos e as vírgulas entre dígitos são
+///   separadores decimais ("2,5" e "2.5"), caso `decimais` seja verdadeiro. Valores mal
+///   formados, com mais de três dígitos após o ponto ("1.5000"), são deixados inalterados.
+/// - O sinal de menos só é considerado no início do texto ou após um espaço, já que hífens
+///   como em "5-A" e "QUADRA 2-3" são comuns em endereços.
+/// - Números muito grandes (acima de `u64`) são deixados inalterados.
+/// - Se a string de entrada não contém nenhum dígito ASCII, a função retorna imediatamente uma referência
+///   emprestada (`Cow::Borrowed`) para evitar alocação.
+///
+pub fn padronizar_numeros_por_extenso_com<'a>(
+    texto: &'a str,
+    opcoes: &OpcoesExtenso,
+) -> Cow<'a, str> {
     // Retorna imediatamente a mesma referência de string
     // caso não existam números na string
     if !texto.as_bytes().iter().any(|c| c.is_ascii_digit()) {
         return Cow::Borrowed(texto);
     }
 
+    REGEX_NUMERO.replace_all(texto, |captura: &Captures| {
+        let antes = captura.get(1).map_or("", |m| m.as_str());
+        let negativo = captura.get(2).is_some();
+        let fim_trecho = captura.get(0).map_or(0, |m| m.end());
+
+        let (inteiro, milhar) = match (captura.get(3), captura.get(4)) {
+            (Some(m), _) => (m, true),
+            (None, Some(m)) => (m, false),
+            _ => return captura[0].to_string(),
+        };
+
+        // Com os separadores desativados, cada sequência de dígitos é convertida à parte.
+        if milhar && !opcoes.separador_milhar {
+            let sinal = if negativo { "MENOS " } else { "" };
+            let resto = padronizar_digitos_simples(&texto[inteiro.start()..fim_trecho], opcoes);
+            return format!("{}{}{}", antes, sinal, resto);
+        }
+
+        // Após um separador de milhar, só a vírgula pode ser separador decimal.
+        let decimais = captura
+            .get(6)
+            .filter(|_| opcoes.decimais && (!milhar || &captura[5] == ","));
+        // Um ponto seguido de mais de três dígitos não é separador de milhar nem decimal.
+        if decimais.is_some_and(|d| &captura[5] == "." && d.len() > 3) {
+            return captura[0].to_string();
+        }
+
+        match numero_texto_por_extenso(
+            negativo,
+            inteiro.as_str(),
+            decimais.map(|d| d.as_str()),
+            opcoes,
+        ) {
+            Some(extenso) => {
+                let fim = decimais.unwrap_or(inteiro).end();
+                let resto = padronizar_digitos_simples(&texto[fim..fim_trecho], opcoes);
+                format!("{}{}{}", antes, extenso, resto)
+            }
+            None => captura[0].to_string(),
+        }
+    })
+}
+
+// Conversão de cada sequência de dígitos, sem interpretar sinais e separadores.
+fn padronizar_digitos_simples(texto: &str, opcoes: &OpcoesExtenso) -> String {
+    let mut resultado = String::with_capacity(texto.len());
     let mut numero_atual = String::new();
-    let mut resultado = String::with_capacity(texto.len() + 5);
 
-    for caracter in texto.chars() {
+    for caracter in texto.chars().chain(std::iter::once('\0')) {
         if caracter.is_ascii_digit() {
-            // Achei um número, adiciono ele na minha string de números.
             numero_atual.push(caracter);
-        } else {
-            // Quando não é um número
-            if !numero_atual.is_empty() {
-                // Se existia um número na string, devo salvar ele por extenso...
-                match numero_atual.parse::<i32>() {
-                    Ok(n) => resultado.push_str(&numero_por_extenso(n)),
-                    Err(_) => resultado.push_str(&numero_atual),
-                }
-
-                // ... E limpar número atual.
-                numero_atual.clear();
+            continue;
+        }
+        if !numero_atual.is_empty() {
+            match numero_atual.parse::<u64>() {
+                Ok(n) => resultado.push_str(&natural_por_extenso(n, opcoes)),
+                Err(_) => resultado.push_str(&numero_atual),
             }
-            // Salvo o carácter atual (não número) no resultado final
-            resultado.push(caracter);
+            numero_atual.clear();
         }
-    }
-
-    // Finalizo salvando o número pendente.
-    if !numero_atual.is_empty() {
-        match numero_atual.parse::<i32>() {
-            Ok(n) => resultado.push_str(&numero_por_extenso(n)),
-            Err(_) => resultado.push_str(&numero_atual),
+        if caracter != '\0' {
+            resultado.push(caracter);
         }
     }
 
-    // Retorno um resultado owned (borrow seria quando é só uma referência)
-    Cow::Owned(resultado)
+    resultado
 }
 
 /// Converte um número inteiro para sua representação por extenso em português.
 ///
 /// Retorna uma referência estática (`Cow::Borrowed`) quando possível (números até 100),
 /// ou uma string alocada dinamicamente (`Cow::Owned`) para casos compostos (negativos, grandes números).
+/// Para outros gêneros e intervalos, vide [inteiro_por_extenso] e [natural_por_extenso].
 ///
 /// # Exemplos
 ///
@@ -207,67 +341,135 @@ pub fn padronizar_numeros_por_extenso(texto: &str) -> Cow<'_, str> {
 /// assert_eq!(numero_por_extenso(2_001_000), "DOIS MILHOES E MIL");
 /// ```
 pub fn numero_por_extenso(n: i32) -> Cow<'static, str> {
+    inteiro_por_extenso(n as i64, &OpcoesExtenso::default())
+}
+
+/// Converte um número inteiro com sinal para sua representação por extenso em português.
+///
+/// # Exemplos
+///
+/// ```
+/// use enderecobr_rs::numero_extenso::{inteiro_por_extenso, Genero, OpcoesExtenso};
+/// let feminino = OpcoesExtenso {
+///     genero: Genero::Feminino,
+///     ..Default::default()
+/// };
+/// assert_eq!(inteiro_por_extenso(-2, &feminino), "MENOS DUAS");
+/// assert_eq!(
+///     inteiro_por_extenso(i64::MIN, &OpcoesExtenso::default()),
+///     "MENOS NOVE QUINTILHOES DUZENTOS E VINTE E TRES QUATRILHOES TREZENTOS E SETENTA E DOIS \
+///      TRILHOES TRINTA E SEIS BILHOES OITOCENTOS E CINQUENTA E QUATRO MILHOES SETECENTOS E \
+///      SETENTA E CINCO MIL OITOCENTOS E OITO"
+/// );
+/// ```
+pub fn inteiro_por_extenso(n: i64, opcoes: &OpcoesExtenso) -> Cow<'static, str> {
+    let extenso = natural_por_extenso(n.unsigned_abs(), opcoes);
+    if n >= 0 {
+        extenso
+    } else {
+        Cow::Owned(format!("MENOS {}", extenso))
+    }
+}
+
+// Forma feminina das palavras de um número de 0 a 999: "UMA", "DUAS", "DUZENTAS"...
+fn feminino_cardinal(texto: &str) -> String {
+    texto
+        .split(' ')
+        .map(|palavra| match palavra {
+            "UM" => "UMA".to_string(),
+            "DOIS" => "DUAS".to_string(),
+            _ => match palavra.strip_suffix("ENTOS") {
+                Some(raiz) => format!("{}ENTAS", raiz),
+                None => palavra.to_string(),
+            },
+        })
+        .join(" ")
+}
+
+/// Converte um número natural para sua representação por extenso em português, no gênero
+/// informado nas opções.
+///
+/// # Exemplos
+///
+/// ```
+/// use enderecobr_rs::numero_extenso::{natural_por_extenso, Genero, OpcoesExtenso};
+/// let feminino = OpcoesExtenso {
+///     genero: Genero::Feminino,
+///     ..Default::default()
+/// };
+/// assert_eq!(natural_por_extenso(300, &feminino), "TREZENTAS");
+/// assert_eq!(natural_por_extenso(2_221, &feminino), "DUAS MIL DUZENTAS E VINTE E UMA");
+/// assert_eq!(natural_por_extenso(2_000_000, &feminino), "DOIS MILHOES");
+/// assert_eq!(
+///     natural_por_extenso(u64::MAX, &OpcoesExtenso::default()),
+///     "DEZOITO QUINTILHOES QUATROCENTOS E QUARENTA E SEIS QUATRILHOES SETECENTOS E QUARENTA E \
+///      QUATRO TRILHOES SETENTA E TRES BILHOES SETECENTOS E NOVE MILHOES QUINHENTOS E CINQUENTA \
+///      E UM MIL SEISCENTOS E QUINZE"
+/// );
+/// ```
+pub fn natural_por_extenso(n: u64, opcoes: &OpcoesExtenso) -> Cow<'static, str> {
     // Função auxiliar: converte números de 0 a 999
     // Retorna a referência estática para os casos até 100
-    fn resolver_centenas(n: u32) -> Cow<'static, str> {
-        // Se for menor que 100, usa a tabela ATE_CEM
-        if n < ATE_CEM.len() as u32 {
-            return Cow::Borrowed(ATE_CEM[n as usize]);
-        }
+    fn resolver_centenas(n: u64, genero: Genero) -> Cow<'static, str> {
+        let resultado = if n < ATE_CEM.len() as u64 {
+            // Se for menor que 100, usa a tabela ATE_CEM
+            Cow::Borrowed(ATE_CEM[n as usize])
+        } else {
+            let centena = n / 100; // extrai a casa das centenas
+            let dezenas = n % 100; // resto (dezenas e unidades)
 
-        let centena = n / 100; // extrai a casa das centenas
-        let dezenas = n % 100; // resto (dezenas e unidades)
+            // Estimativa da média do tamanho final
+            let mut resultado = String::with_capacity(10);
 
-        // Estimativa da média do tamanho final
-        let mut resultado = String::with_capacity(10);
+            if centena > 0 {
+                // Adiciona palavra da centena (ex: "DUZENTOS")
+                resultado.push_str(CENTENAS[centena as usize]);
+            }
 
-        if centena > 0 {
-            // Adiciona palavra da centena (ex: "DUZENTOS")
-            resultado.push_str(CENTENAS[centena as usize]);
-        }
+            if centena > 0 && dezenas > 0 {
+                // Espaço entre centena e dezenas, se ambas existirem
+                resultado.push_str(" E ");
+            }
 
-        if centena > 0 && dezenas > 0 {
-            // Espaço entre centena e dezenas, se ambas existirem
-            resultado.push_str(" E ");
-        }
+            if dezenas > 0 {
+                // Adiciona parte das dezenas (ex: "VINTE E CINCO")
+                resultado.push_str(ATE_CEM[dezenas as usize]);
+            }
 
-        if dezenas > 0 {
-            // Adiciona parte das dezenas (ex: "VINTE E CINCO")
-            resultado.push_str(ATE_CEM[dezenas as usize]);
-        }
+            Cow::Owned(resultado)
+        };
 
-        Cow::Owned(resultado)
+        match genero {
+            Genero::Masculino => resultado,
+            Genero::Feminino => Cow::Owned(feminino_cardinal(&resultado)),
+        }
     }
 
-    let num_abs = n.unsigned_abs();
-
-    if (0..1000).contains(&num_abs) {
+    if n < 1000 {
         // Caso base: número entre 0 e 999
-        // Curiosamente esta é a forma "rustônica" de escrever isso,
-        // segundo o clippy.
-        return if n >= 0 {
-            resolver_centenas(num_abs)
-        } else {
-            Cow::Owned(format!("MENOS {}", resolver_centenas(num_abs)))
-        };
+        return resolver_centenas(n, opcoes.genero);
     }
 
     // Determina a maior ordem de grandeza
     // (ex: milhão → 6 dígitos → ilog10/3 = 2 ordens de grandeza)
-    let maior_ordem_grandeza = num_abs.ilog10() / 3;
+    let maior_ordem_grandeza = n.ilog10() / 3;
 
-    let mut base = 10u32.pow(maior_ordem_grandeza * 3); // 1, 1_000, 1_000_000, etc.
-    let mut valor_restante = num_abs;
+    let mut base = 10u64.pow(maior_ordem_grandeza * 3); // 1, 1_000, 1_000_000, etc.
+    let mut valor_restante = n;
 
     // Estimativa/chute do tamanho esperado da string
     let mut resultado = String::with_capacity(20);
-    if n < 0 {
-        // Começa com "MENOS" se o número for negativo
-        resultado.push_str("MENOS");
-    }
 
     // Processa do maior para o menor agrupamento de 3 dígitos
     for ordem_grandeza in (0..=maior_ordem_grandeza).rev() {
+        // Os milhões, bilhões, etc. são substantivos masculinos, então só as unidades e os
+        // milhares concordam com o gênero: "DUAS MIL CASAS", mas "DOIS MILHOES DE CASAS".
+        let genero = if ordem_grandeza <= 1 {
+            opcoes.genero
+        } else {
+            Genero::Masculino
+        };
+
         // Se restar menos de 1000, processa diretamente e termina
         if valor_restante > 0 && valor_restante < 1000 {
             if !resultado.is_empty() {
@@ -279,7 +481,7 @@ pub fn numero_por_extenso(n: i32) -> Cow<'static, str> {
                     resultado.push(' ');
                 }
             }
-            resultado.push_str(&resolver_centenas(valor_restante));
+            resultado.push_str(&resolver_centenas(valor_restante, opcoes.genero));
             break;
         }
 
@@ -306,7 +508,7 @@ pub fn numero_por_extenso(n: i32) -> Cow<'static, str> {
         // no vetor ORDENS_GRANDEZA, escreve o número desse grupo (ex: "DOIS")
         // para receber o sufixo no if abaixo (ex: "MIL")
         if mais_significativo != 1 {
-            resultado.push_str(&resolver_centenas(mais_significativo));
+            resultado.push_str(&resolver_centenas(mais_significativo, genero));
             resultado.push(' ');
         }
 
@@ -323,9 +525,490 @@ pub fn numero_por_extenso(n: i32) -> Cow<'static, str> {
     Cow::Owned(resultado)
 }
 
+// ====== Conversão de extenso para dígitos =======
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PalavraNumero {
+    /// Valor e classe: 1 para unidades (0 a 19), 2 para dezenas e 3 para centenas.
+    Valor(i64, u8),
+    /// Mil, milhão, bilhão e trilhão.
+    Escala(i64),
+    E,
+    Menos,
+}
+
+static PALAVRAS_NUMERO: LazyLock<HashMap<&'static str, PalavraNumero>> =
+    LazyLock::new(criar_palavras_numero);
+static REGEX_PALAVRA: LazyLock<Regex> = LazyLock::new(criar_regex_palavra);
+
+fn criar_palavras_numero() -> HashMap<&'static str, PalavraNumero> {
+    use PalavraNumero::*;
+
+    let mut mapa = HashMap::from([
+        ("UMA", Valor(1, 1)),
+        ("DUAS", Valor(2, 1)),
+        ("CATORZE", Valor(14, 1)),
+        ("DEZESEIS", Valor(16, 1)),
+        ("DEZESETE", Valor(17, 1)),
+        ("CENTO", Valor(100, 3)),
+        ("MIL", Escala(1_000)),
+        ("MILHAO", Escala(1_000_000)),
+        ("MILHOES", Escala(1_000_000)),
+        ("BILHAO", Escala(1_000_000_000)),
+        ("BILHOES", Escala(1_000_000_000)),
+        ("TRILHAO", Escala(1_000_000_000_000)),
+        ("TRILHOES", Escala(1_000_000_000_000)),
+        ("E", E),
+        ("MENOS", Menos),
+    ]);
+
+    for (n, palavra) in ATE_CEM.iter().enumerate().take(20) {
+        mapa.insert(palavra, Valor(n as i64, 1));
+    }
+    for n in (20..100).step_by(10) {
+        mapa.insert(ATE_CEM[n], Valor(n as i64, 2));
+    }
+    for (n, palavra) in CENTENAS.iter().enumerate().skip(2) {
+        mapa.insert(palavra, Valor(n as i64 * 100, 3));
+    }
+    for (palavra, valor) in [
+        ("DUZENTAS", 200),
+        ("TREZENTAS", 300),
+        ("QUATROCENTAS", 400),
+        ("QUINHENTAS", 500),
+        ("SEISCENTAS", 600),
+        ("SETECENTAS", 700),
+        ("OITOCENTAS", 800),
+        ("NOVECENTAS", 900),
+    ] {
+        mapa.insert(palavra, Valor(valor, 3));
+    }
+    // "CEM" não pode ser seguido de dezenas ou unidades ("CENTO E UM"), por isso é tratado
+    // como se fosse da classe das unidades.
+    mapa.insert("CEM", Valor(100, 1));
+
+    mapa
+}
+
+#[allow(clippy::expect_used)]
+fn criar_regex_palavra() -> Regex {
+    Regex::new(r"[A-Z]+").expect("Regex de palavras inválida (bug interno)")
+}
+
+fn interpretar_palavras(palavras: &[&str]) -> Option<i64> {
+    let (negativo, palavras) = match palavras.split_first() {
+        Some((&"MENOS", resto)) => (true, resto),
+        _ => (false, palavras),
+    };
+
+    let mut total: i64 = 0;
+    let mut grupo: i64 = 0;
+    // Classe da última parte do grupo atual e última escala usada, que devem ser decrescentes.
+    let mut classe_anterior = 4;
+    let mut escala_anterior = i64::MAX;
+    let mut anterior = None;
+
+    for palavra in palavras {
+        let atual = *PALAVRAS_NUMERO.get(palavra)?;
+        match atual {
+            PalavraNumero::Valor(0, _) if palavras.len() > 1 => return None,
+            PalavraNumero::Valor(valor, classe) => {
+                if classe >= classe_anterior {
+                    return None;
+                }
+                grupo += valor;
+                classe_anterior = classe;
+            }
+            PalavraNumero::Escala(escala) => {
+                if escala >= escala_anterior || anterior == Some(PalavraNumero::E) {
+                    return None;
+                }
+                total = total.checked_add(grupo.max(1).checked_mul(escala)?)?;
+                grupo = 0;
+                classe_anterior = 4;
+                escala_anterior = escala;
+            }
+            PalavraNumero::E if anterior.is_none() || anterior == Some(PalavraNumero::E) => {
+                return None
+            }
+            PalavraNumero::E => {}
+            PalavraNumero::Menos => return None,
+        }
+        anterior = Some(atual);
+    }
+
+    if anterior.is_none() || anterior == Some(PalavraNumero::E) {
+        return None;
+    }
+
+    let total = total.checked_add(grupo)?;
+    Some(if negativo { -total } else { total })
+}
+
+/// Converte um número escrito por extenso em português para o seu valor inteiro.
+///
+/// Retorna `None` caso o texto não seja um número por extenso válido.
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::numero_extenso::extenso_para_numero;
+/// assert_eq!(extenso_para_numero("DUZENTOS E TRINTA E DOIS"), Some(232));
+/// assert_eq!(extenso_para_numero("dezesseis"), Some(16));
+/// assert_eq!(extenso_para_numero("DUAS MIL E QUINHENTAS"), Some(2500));
+/// assert_eq!(extenso_para_numero("MENOS MIL E QUINHENTOS"), Some(-1500));
+/// assert_eq!(extenso_para_numero("VINTE TRINTA"), None);
+/// assert_eq!(extenso_para_numero("RUA"), None);
+/// ```
+///
+/// # Detalhes
+/// São aceitas as formas masculinas e femininas ("DOIS" e "DUAS", "DUZENTOS" e "DUZENTAS"),
+/// as grafias alternativas "CATORZE", "DEZESEIS" e "DEZESETE", e o "E" entre as partes do
+/// número é opcional. As partes devem aparecer em ordem decrescente, de forma que "VINTE
+/// TRINTA" e "UM DOIS" não são números válidos.
+///
+pub fn extenso_para_numero(valor: &str) -> Option<i64> {
+    let valor = normalizar(valor);
+    let palavras: Vec<&str> = valor.split_whitespace().collect();
+    interpretar_palavras(&palavras)
+}
+
+// Limite de palavras de um número por extenso no texto, para evitar buscas muito longas.
+const MAXIMO_PALAVRAS_NUMERO: usize = 16;
+
+/// Substitui os números por extenso e os números romanos de um texto por seus algarismos,
+/// operação inversa de [padronizar_numeros_por_extenso].
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::numero_extenso::padronizar_numeros_para_digitos;
+/// assert_eq!(padronizar_numeros_para_digitos("RUA DEZESSEIS"), "RUA 16");
+/// assert_eq!(padronizar_numeros_para_digitos("RUA XVI"), "RUA 16");
+/// assert_eq!(
+///     padronizar_numeros_para_digitos("TRAVESSA CENTO E DOIS, CASA TRES"),
+///     "TRAVESSA 102, CASA 3"
+/// );
+/// assert_eq!(padronizar_numeros_para_digitos("RUA DOIS E TRES"), "RUA 2 E 3");
+/// ```
+///
+/// # Detalhes
+/// O texto é normalizado (caixa alta e sem acentos) e cada sequência de palavras que forma um
+/// número por extenso válido é substituída pelos algarismos, preferindo a sequência mais longa.
+/// Os números romanos só são convertidos quando têm mais de uma letra e usam apenas I, V e X,
+/// já que letras isoladas, como em "QUADRA C", costumam ser identificadores e palavras como
+/// "DI", "MC" ou "MIX" também formam números romanos válidos.
+///
+pub fn padronizar_numeros_para_digitos(texto: &str) -> String {
+    let texto = normalizar(texto);
+    let palavras: Vec<regex::Match> = REGEX_PALAVRA.find_iter(&texto).collect();
+
+    let mut resultado = String::with_capacity(texto.len());
+    let mut ultimo = 0;
+    let mut i = 0;
+
+    while i < palavras.len() {
+        let inicio = palavras[i].start();
+
+        // Maior sequência de palavras, separadas apenas por espaços, que forma um número.
+        let mut fim = i + 1;
+        while fim < palavras.len()
+            && fim - i < MAXIMO_PALAVRAS_NUMERO
+            && texto[palavras[fim - 1].end()..palavras[fim].start()] == *" "
+        {
+            fim += 1;
+        }
+        let numero = (i + 1..=fim).rev().find_map(|j| {
+            let trecho: Vec<&str> = palavras[i..j].iter().map(|m| m.as_str()).collect();
+            if trecho[0] == "MENOS" {
+                return None;
+            }
+            interpretar_palavras(&trecho).map(|n| (n, j))
+        });
+
+        let palavra = palavras[i].as_str();
+        let substituicao = match numero {
+            Some((n, j)) => Some((n, palavras[j - 1].end(), j)),
+            None if palavra.len() > 1
+                && palavra.chars().all(|c| matches!(c, 'I' | 'V' | 'X'))
+                && REGEX_ROMANO.is_match(palavra) =>
+            {
+                Some((
+                    romano_para_inteiro(palavra) as i64,
+                    palavras[i].end(),
+                    i + 1,
+                ))
+            }
+            None => None,
+        };
+
+        match substituicao {
+            Some((n, fim_texto, proximo)) => {
+                resultado.push_str(&texto[ultimo..inicio]);
+                resultado.push_str(&n.to_string());
+                ultimo = fim_texto;
+                i = proximo;
+            }
+            None => i += 1,
+        }
+    }
+
+    resultado.push_str(&texto[ultimo..]);
+    resultado
+}
+
+// ====== Ordinais =======
+
+/// Gênero gramatical usado na escrita de números por extenso.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Genero {
+    #[default]
+    Masculino,
+    Feminino,
+}
+
+/// Forma de escrita dos números ordinais.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FormatoOrdinal {
+    /// Algarismos seguidos da letra do gênero, como "3A" e "2O".
+    #[default]
+    Numerico,
+    /// Por extenso, como "TERCEIRA" e "SEGUNDO".
+    PorExtenso,
+}
+
+const ORDINAIS_UNIDADES: [&str; 10] = [
+    "", "PRIMEIRO", "SEGUNDO", "TERCEIRO", "QUARTO", "QUINTO", "SEXTO", "SETIMO", "OITAVO", "NONO",
+];
+
+const ORDINAIS_DEZENAS: [&str; 10] = [
+    "",
+    "DECIMO",
+    "VIGESIMO",
+    "TRIGESIMO",
+    "QUADRAGESIMO",
+    "QUINQUAGESIMO",
+    "SEXAGESIMO",
+    "SEPTUAGESIMO",
+    "OCTOGESIMO",
+    "NONAGESIMO",
+];
+
+const ORDINAIS_CENTENAS: [&str; 10] = [
+    "",
+    "CENTESIMO",
+    "DUCENTESIMO",
+    "TRECENTESIMO",
+    "QUADRINGENTESIMO",
+    "QUINGENTESIMO",
+    "SEXCENTESIMO",
+    "SEPTINGENTESIMO",
+    "OCTINGENTESIMO",
+    "NONGENTESIMO",
+];
+
+// Substantivos que costumam ser precedidos de números ordinais em endereços, como em
+// "3A TRAVESSA" e "2O ANDAR". Fora desse contexto, "3A" pode ser, por exemplo, uma casa.
+const SUBSTANTIVOS_ORDINAIS: [&str; 26] = [
+    "RUA",
+    "TRAVESSA",
+    "AVENIDA",
+    "ALAMEDA",
+    "BECO",
+    "VIELA",
+    "VILA",
+    "PRACA",
+    "ESTRADA",
+    "PASSAGEM",
+    "RAMAL",
+    "LINHA",
+    "QUADRA",
+    "ETAPA",
+    "FASE",
+    "SECAO",
+    "SETOR",
+    "ZONA",
+    "ANDAR",
+    "ANDARES",
+    "PAVIMENTO",
+    "SUBSOLO",
+    "BLOCO",
+    "DELEGACIA",
+    "BATALHAO",
+    "COMPANHIA",
+];
+
+static ORDINAIS_POR_EXTENSO: LazyLock<HashMap<String, (u32, Genero)>> =
+    LazyLock::new(criar_ordinais_por_extenso);
+static REGEX_ORDINAL_SIMBOLO: LazyLock<Regex> = LazyLock::new(criar_regex_ordinal_simbolo);
+static REGEX_ORDINAL: LazyLock<Regex> = LazyLock::new(criar_regex_ordinal);
+
+fn feminino(palavra: &str) -> String {
+    format!("{}A", palavra.strip_suffix('O').unwrap_or(palavra))
+}
+
+/// Mapeia cada palavra usada na escrita de ordinais ("TERCEIRA", "VIGESIMO", etc.) para o seu
+/// valor e gênero.
+pub fn criar_ordinais_por_extenso() -> HashMap<String, (u32, Genero)> {
+    let mut mapa = HashMap::new();
+    for (tabela, base) in [
+        (&ORDINAIS_UNIDADES, 1),
+        (&ORDINAIS_DEZENAS, 10),
+        (&ORDINAIS_CENTENAS, 100),
+    ] {
+        for (i, palavra) in tabela.iter().enumerate().skip(1) {
+            let valor = i as u32 * base;
+            mapa.insert(palavra.to_string(), (valor, Genero::Masculino));
+            mapa.insert(feminino(palavra), (valor, Genero::Feminino));
+        }
+    }
+    mapa
+}
+
+#[allow(clippy::expect_used)]
+fn criar_regex_ordinal_simbolo() -> Regex {
+    // "º", "ª" e "°" (símbolo de grau, usado frequentemente no lugar do "º").
+    Regex::new(r"(\d) ?\.? ?([ºª°])").expect("Regex de ordinais inválida (bug interno)")
+}
+
+#[allow(clippy::expect_used)]
+fn criar_regex_ordinal() -> Regex {
+    let palavras = ORDINAIS_POR_EXTENSO
+        .keys()
+        .sorted_by_key(|p| std::cmp::Reverse(p.len()))
+        .join("|");
+    let substantivos = SUBSTANTIVOS_ORDINAIS.join("|");
+
+    Regex::new(&format!(
+        r"\b(?:(\d{{1,3}})(?:\. ?)?([AO])|((?:(?:{palavras}) )*(?:{palavras})))\b\.? ({substantivos})\b"
+    ))
+    .expect("Regex de ordinais inválida (bug interno)")
+}
+
+/// Converte um número ordinal para a sua forma por extenso, no gênero informado.
+///
+/// Retorna `None` para números fora do intervalo de 1 a 999.
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::numero_extenso::{ordinal_por_extenso, Genero};
+/// assert_eq!(ordinal_por_extenso(3, Genero::Feminino).unwrap(), "TERCEIRA");
+/// assert_eq!(ordinal_por_extenso(3, Genero::Masculino).unwrap(), "TERCEIRO");
+/// assert_eq!(ordinal_por_extenso(21, Genero::Masculino).unwrap(), "VIGESIMO PRIMEIRO");
+/// assert_eq!(ordinal_por_extenso(0, Genero::Masculino), None);
+/// ```
+pub fn ordinal_por_extenso(n: u32, genero: Genero) -> Option<String> {
+    if !(1..1000).contains(&n) {
+        return None;
+    }
+
+    let partes = [
+        ORDINAIS_CENTENAS[(n / 100) as usize],
+        ORDINAIS_DEZENAS[(n / 10 % 10) as usize],
+        ORDINAIS_UNIDADES[(n % 10) as usize],
+    ];
+
+    Some(
+        partes
+            .iter()
+            .filter(|p| !p.is_empty())
+            .map(|p| match genero {
+                Genero::Masculino => p.to_string(),
+                Genero::Feminino => feminino(p),
+            })
+            .join(" "),
+    )
+}
+
+/// Interpreta um número ordinal escrito por extenso, como "VIGESIMA TERCEIRA", retornando o
+/// seu valor e gênero.
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::numero_extenso::{extenso_para_ordinal, Genero};
+/// assert_eq!(extenso_para_ordinal("TERCEIRA"), Some((3, Genero::Feminino)));
+/// assert_eq!(extenso_para_ordinal("vigésimo primeiro"), Some((21, Genero::Masculino)));
+/// assert_eq!(extenso_para_ordinal("PRIMEIRO VIGESIMO"), None);
+/// ```
+pub fn extenso_para_ordinal(valor: &str) -> Option<(u32, Genero)> {
+    let valor = normalizar(valor);
+    let mut total = 0;
+    let mut genero = None;
+    // As partes devem aparecer da maior para a menor ordem: centenas, dezenas e unidades.
+    let mut limite = 1000;
+
+    for palavra in valor.split_whitespace() {
+        let &(n, g) = ORDINAIS_POR_EXTENSO.get(palavra)?;
+        if n >= limite || genero.is_some_and(|anterior| anterior != g) {
+            return None;
+        }
+        limite = 10u32.pow(n.ilog10());
+        total += n;
+        genero = Some(g);
+    }
+
+    Some((total, genero?))
+}
+
+/// Padroniza os números ordinais de um texto, unificando as formas "3ª", "3A", "3.A" e
+/// "TERCEIRA" em uma única forma.
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::numero_extenso::{padronizar_ordinais, FormatoOrdinal};
+/// assert_eq!(padronizar_ordinais("3ª TRAVESSA", FormatoOrdinal::Numerico), "3A TRAVESSA");
+/// assert_eq!(padronizar_ordinais("TERCEIRA TRAVESSA", FormatoOrdinal::Numerico), "3A TRAVESSA");
+/// assert_eq!(padronizar_ordinais("2º ANDAR", FormatoOrdinal::Numerico), "2O ANDAR");
+/// assert_eq!(padronizar_ordinais("3.A TRAVESSA", FormatoOrdinal::PorExtenso), "TERCEIRA TRAVESSA");
+/// assert_eq!(padronizar_ordinais("CASA 3A", FormatoOrdinal::Numerico), "CASA 3A");
+/// ```
+///
+/// # Detalhes
+/// Os números seguidos de "º", "ª" ou "°" são sempre considerados ordinais. As formas "3A",
+/// "3.A" e "TERCEIRA" só são consideradas ordinais quando precedem um substantivo que
+/// costuma ser numerado dessa forma em endereços, como "RUA", "TRAVESSA", "ANDAR" ou "ETAPA",
+/// já que em outros contextos podem ser, por exemplo, o número de uma casa. São reconhecidos os
+/// ordinais de 1 a 999.
+///
+pub fn padronizar_ordinais(valor: &str, formato: FormatoOrdinal) -> String {
+    let valor = REGEX_ORDINAL_SIMBOLO.replace_all(valor, |captura: &Captures| {
+        let letra = if &captura[2] == "ª" { "A" } else { "O" };
+        format!("{}{}", &captura[1], letra)
+    });
+    let valor = normalizar(&valor);
+
+    REGEX_ORDINAL
+        .replace_all(&valor, |captura: &Captures| {
+            let ordinal = match (captura.get(1), captura.get(2), captura.get(3)) {
+                (Some(numero), Some(letra), _) => numero.as_str().parse().ok().map(|n| {
+                    let genero = match letra.as_str() {
+                        "A" => Genero::Feminino,
+                        _ => Genero::Masculino,
+                    };
+                    (n, genero)
+                }),
+                (_, _, Some(extenso)) => extenso_para_ordinal(extenso.as_str()),
+                _ => None,
+            };
+
+            let formatado = ordinal.and_then(|(n, genero)| match formato {
+                FormatoOrdinal::Numerico if n > 0 => {
+                    let letra = if genero == Genero::Feminino { "A" } else { "O" };
+                    Some(format!("{}{}", n, letra))
+                }
+                FormatoOrdinal::Numerico => None,
+                FormatoOrdinal::PorExtenso => ordinal_por_extenso(n, genero),
+            });
+
+            match formatado {
+                Some(ordinal) => format!("{} {}", ordinal, &captura[4]),
+                None => captura[0].to_string(),
+            }
+        })
+        .into_owned()
+}
+
 // Em Rust, a constant é criada durante a compilação, então só posso chamar funções muito restritas
 // quando uso `const`.
-static REGEX_ROMANO: LazyLock<Regex> = LazyLock::new(criar_regex_romano);
+pub(crate) static REGEX_ROMANO: LazyLock<Regex> = LazyLock::new(criar_regex_romano);
 static REGEX_ROMANO_TRIAGEM: LazyLock<Regex> = LazyLock::new(criar_regex_romano_triagem);
 
 #[allow(clippy::expect_used)]
@@ -344,6 +1027,8 @@ pub fn criar_regex_romano_triagem() -> Regex {
 
 /// Substitui números romanos em um texto por suas representações por extenso (em palavras).
 /// Apenas sequências que formam números romanos válidos (1–3999) são convertidas.
+/// Para converter apenas os números romanos em contextos confiáveis, como "DOM PEDRO II",
+/// vide [padronizar_numero_romano_contextual].
 /// Evita alocação de Strings usando `Cow::Borrowed` se nenhuma substituição for feita.
 ///
 /// # Exemplos
@@ -398,6 +1083,229 @@ pub fn padronizar_numero_romano_por_extenso(valor: &str) -> Cow<'_, str> {
     }
 }
 
+// Nomes de reis, rainhas e papas, após os quais o número romano é o ordinal do governante,
+// como em "DOM PEDRO II" e "PAPA PIO X". Como muitos deles também são nomes comuns, seguidos de
+// iniciais ("JOSE C SILVA"), só há confiança quando um título vem antes do nome. Sem o título,
+// eles são tratados como as divisões numeradas abaixo.
+const GATILHOS_NOMES: [&str; 34] = [
+    "AFONSO",
+    "ALEXANDRE",
+    "BENTO",
+    "BONIFACIO",
+    "CARLOS",
+    "CLEMENTE",
+    "DUARTE",
+    "EDUARDO",
+    "ELIZABETH",
+    "FELIPE",
+    "FERNANDO",
+    "FILIPE",
+    "FRANCISCO",
+    "GREGORIO",
+    "GUILHERME",
+    "HENRIQUE",
+    "INOCENCIO",
+    "ISABEL",
+    "JOAO",
+    "JORGE",
+    "JOSE",
+    "LEAO",
+    "LUIS",
+    "LUIZ",
+    "MANOEL",
+    "MANUEL",
+    "MARIA",
+    "NAPOLEAO",
+    "PAULO",
+    "PEDRO",
+    "PIO",
+    "SEBASTIAO",
+    "SISTO",
+    "URBANO",
+];
+
+// Títulos que, antes de um dos nomes acima, indicam um governante.
+const TITULOS_NOMES: [&str; 9] = [
+    "D",
+    "DOM",
+    "IMPERADOR",
+    "IMPERATRIZ",
+    "PAPA",
+    "PRINCESA",
+    "PRINCIPE",
+    "RAINHA",
+    "REI",
+];
+
+// Palavras após as quais o número romano é uma contagem, e não uma palavra ou um nome.
+const GATILHOS_SECULO: [&str; 3] = ["SEC", "SECULO", "SECULOS"];
+
+// Divisões numeradas de loteamentos e conjuntos, como em "ETAPA II" e "SETOR IV". Como elas
+// também costumam ser identificadas por letras ("BLOCO C", "QUADRA D"), só os números formados
+// por I, V e X são convertidos após elas.
+const GATILHOS_NUMERADOS: [&str; 17] = [
+    "BLOCO",
+    "CONDOMINIO",
+    "CONJUNTO",
+    "ETAPA",
+    "FASE",
+    "GLEBA",
+    "JARDIM",
+    "MODULO",
+    "NUCLEO",
+    "PARQUE",
+    "PARTE",
+    "QUADRA",
+    "RESIDENCIAL",
+    "SECAO",
+    "SETOR",
+    "UNIDADE",
+    "VILA",
+];
+
+/// Opções da conversão contextual de números romanos, vide
+/// [padronizar_numero_romano_contextual].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OpcoesRomano {
+    /// Converte as letras isoladas ("I", "V", "X"...) em qualquer contexto reconhecido, e não
+    /// apenas após nomes de governantes e "SECULO".
+    pub letras_isoladas: bool,
+    /// Números romanos sempre convertidos, independentemente do contexto (ex: "XV").
+    pub permitidos: Vec<String>,
+    /// Palavras nunca convertidas, mesmo quando formam um número romano válido (ex: "DI").
+    pub proibidos: Vec<String>,
+}
+
+// Confiança de que um número romano está sendo usado como número, pelo seu contexto.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ContextoRomano {
+    Nenhum,
+    Numerado,
+    Confiavel,
+}
+
+fn palavra_sem_pontuacao(palavra: &str) -> Cow<'_, str> {
+    normalizar(palavra.trim_matches(|c: char| !c.is_alphanumeric()))
+}
+
+fn contexto_romano(antes: &str, depois: &str) -> ContextoRomano {
+    let mut anteriores = antes.split_whitespace().rev().map(palavra_sem_pontuacao);
+    let anterior = anteriores.next();
+    let titulo = anteriores.next();
+    let seguinte = depois.split_whitespace().next().map(palavra_sem_pontuacao);
+
+    match (anterior.as_deref(), seguinte.as_deref()) {
+        (Some(a), _) if GATILHOS_SECULO.contains(&a) => ContextoRomano::Confiavel,
+        (Some(a), _) if GATILHOS_NOMES.contains(&a) => {
+            if titulo.is_some_and(|t| TITULOS_NOMES.contains(&t.as_ref())) {
+                ContextoRomano::Confiavel
+            } else {
+                ContextoRomano::Numerado
+            }
+        }
+        (Some(a), _) if GATILHOS_NUMERADOS.contains(&a) => ContextoRomano::Numerado,
+        (_, Some(s)) if SUBSTANTIVOS_ORDINAIS.contains(&s) => ContextoRomano::Numerado,
+        _ => ContextoRomano::Nenhum,
+    }
+}
+
+/// Substitui por extenso apenas os números romanos cujo contexto indica que são números, evitando
+/// converter palavras comuns e iniciais de nomes, como em "RUA C", "BLOCO D" e "L. DE SOUZA".
+///
+/// # Exemplos
+///
+/// ```
+/// use enderecobr_rs::numero_extenso::{padronizar_numero_romano_contextual, OpcoesRomano};
+/// let opcoes = OpcoesRomano::default();
+/// assert_eq!(padronizar_numero_romano_contextual("AV. DOM PEDRO I", &opcoes), "AV. DOM PEDRO UM");
+/// assert_eq!(padronizar_numero_romano_contextual("RUA PAPA PIO XII", &opcoes), "RUA PAPA PIO DOZE");
+/// assert_eq!(padronizar_numero_romano_contextual("CONJUNTO III", &opcoes), "CONJUNTO TRES");
+/// assert_eq!(padronizar_numero_romano_contextual("II TRAVESSA", &opcoes), "DOIS TRAVESSA");
+/// assert_eq!(padronizar_numero_romano_contextual("RUA C", &opcoes), "RUA C");
+/// assert_eq!(padronizar_numero_romano_contextual("BLOCO D", &opcoes), "BLOCO D");
+/// assert_eq!(padronizar_numero_romano_contextual("RUA L. DE SOUZA", &opcoes), "RUA L. DE SOUZA");
+///
+/// let opcoes = OpcoesRomano {
+///     permitidos: vec!["XV".to_string()],
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     padronizar_numero_romano_contextual("RUA XV DE NOVEMBRO", &opcoes),
+///     "RUA QUINZE DE NOVEMBRO"
+/// );
+/// ```
+///
+/// # Detalhes
+/// Um número romano válido (vide [padronizar_numero_romano_por_extenso]) é convertido quando:
+/// - vem após um título seguido do nome de um rei, rainha ou papa ("DOM PEDRO", "PAPA PIO",
+///   "D. JOAO"...) ou após "SECULO";
+/// - ou vem após um desses nomes sem o título, após uma divisão numerada ("ETAPA", "SETOR",
+///   "CONJUNTO"...) ou antes de um substantivo que aceita ordinais ("TRAVESSA", "ANDAR"...),
+///   desde que formado apenas por I, V e X. Nesses contextos as letras também costumam ser
+///   identificadores ou iniciais, como em "BLOCO D" e "JOSE C SILVA".
+///
+/// As letras isoladas só são convertidas no primeiro caso, a não ser que
+/// [OpcoesRomano::letras_isoladas] seja verdadeiro, e nunca quando seguidas de ponto, por
+/// serem iniciais. As listas [OpcoesRomano::permitidos] e [OpcoesRomano::proibidos] têm
+/// precedência sobre essas regras, e são comparadas sem diferenciar maiúsculas e acentos.
+///
+pub fn padronizar_numero_romano_contextual<'a>(
+    valor: &'a str,
+    opcoes: &OpcoesRomano,
+) -> Cow<'a, str> {
+    let contem =
+        |lista: &[String], palavra: &str| lista.iter().any(|item| normalizar(item) == palavra);
+
+    let mut resultado_opt: Option<String> = None;
+    let mut ultimo = 0usize;
+
+    for m in REGEX_ROMANO_TRIAGEM.find_iter(valor) {
+        let romano = m.as_str();
+        if romano.is_empty() || !REGEX_ROMANO.is_match(romano) {
+            continue;
+        }
+
+        let palavra = romano.to_ascii_uppercase();
+        let depois = &valor[m.end()..];
+
+        let converter = if contem(&opcoes.proibidos, &palavra) {
+            false
+        } else if contem(&opcoes.permitidos, &palavra) {
+            true
+        } else {
+            let letra_isolada = palavra.len() == 1;
+            let inicial = letra_isolada && depois.starts_with('.');
+
+            match contexto_romano(&valor[..m.start()], depois) {
+                _ if inicial => false,
+                ContextoRomano::Confiavel => true,
+                ContextoRomano::Numerado => {
+                    (!letra_isolada || opcoes.letras_isoladas)
+                        && palavra.chars().all(|c| matches!(c, 'I' | 'V' | 'X'))
+                }
+                ContextoRomano::Nenhum => false,
+            }
+        };
+
+        if !converter {
+            continue;
+        }
+
+        let trecho_atual = resultado_opt.get_or_insert_with(|| String::with_capacity(valor.len()));
+        trecho_atual.push_str(&valor[ultimo..m.start()]);
+        trecho_atual.push_str(numero_por_extenso(romano_para_inteiro(romano)).as_ref());
+        ultimo = m.end();
+    }
+
+    match resultado_opt {
+        None => Cow::Borrowed(valor),
+        Some(mut s) => {
+            s.push_str(&valor[ultimo..]);
+            Cow::Owned(s)
+        }
+    }
+}
+
 /// Converte um número romano em sua representação por extenso (número inteiro).
 ///
 /// Aceita entradas em maiúsculas ou minúsculas. A conversão segue a regra padrão de números romanos,
@@ -595,6 +1503,66 @@ mod tests {
         );
     }
 
+    #[test]
+    fn romano_contextual() {
+        let opcoes = OpcoesRomano::default();
+        let casos = [
+            ("AV. DOM PEDRO II", "AV. DOM PEDRO DOIS"),
+            ("AV. D. PEDRO I", "AV. D. PEDRO UM"),
+            ("RUA JOAO XXIII", "RUA JOAO VINTE E TRES"),
+            ("Rua João VI", "Rua João SEIS"),
+            ("PRACA SECULO XX", "PRACA SECULO VINTE"),
+            ("ETAPA II", "ETAPA DOIS"),
+            ("SETOR IV QUADRA 5", "SETOR QUATRO QUADRA 5"),
+            ("III TRAVESSA DA RUA A", "TRES TRAVESSA DA RUA A"),
+            ("RUA C", "RUA C"),
+            ("BLOCO D", "BLOCO D"),
+            ("BLOCO CD", "BLOCO CD"),
+            ("CONJUNTO I", "CONJUNTO I"),
+            ("PRACA MIL", "PRACA MIL"),
+            ("RUA L. DE SOUZA", "RUA L. DE SOUZA"),
+            (
+                "RUA DOM PEDRO I. DE ALCANTARA",
+                "RUA DOM PEDRO I. DE ALCANTARA",
+            ),
+            ("RUA MIX", "RUA MIX"),
+            ("RUA XV DE NOVEMBRO", "RUA XV DE NOVEMBRO"),
+            ("AV. D. PEDRO II", "AV. D. PEDRO DOIS"),
+            ("RUA REI LUIS XIV", "RUA REI LUIS QUATORZE"),
+            // Iniciais após nomes comuns, sem título
+            ("RUA JOSE C SILVA", "RUA JOSE C SILVA"),
+            ("RUA PAULO L MACHADO", "RUA PAULO L MACHADO"),
+            ("RUA CARLOS D ANDRADE", "RUA CARLOS D ANDRADE"),
+            ("RUA MARIA DI GIORGIO", "RUA MARIA DI GIORGIO"),
+            ("RUA JOAO V MOREIRA", "RUA JOAO V MOREIRA"),
+            ("RUA FRANCISCO M LIMA", "RUA FRANCISCO M LIMA"),
+        ];
+        for (entrada, esperado) in casos {
+            assert_eq!(
+                padronizar_numero_romano_contextual(entrada, &opcoes),
+                esperado
+            );
+        }
+
+        let opcoes = OpcoesRomano {
+            letras_isoladas: true,
+            permitidos: vec!["xv".to_string()],
+            proibidos: vec!["II".to_string()],
+        };
+        let casos = [
+            ("CONJUNTO I", "CONJUNTO UM"),
+            ("BLOCO D", "BLOCO D"),
+            ("RUA XV DE NOVEMBRO", "RUA QUINZE DE NOVEMBRO"),
+            ("AV. DOM PEDRO II", "AV. DOM PEDRO II"),
+        ];
+        for (entrada, esperado) in casos {
+            assert_eq!(
+                padronizar_numero_romano_contextual(entrada, &opcoes),
+                esperado
+            );
+        }
+    }
+
     #[test]
     fn teste_padronizacao_string_por_extenso() {
         // Caso de borda
@@ -623,4 +1591,204 @@ mod tests {
             "RUA DUZENTOS E VINTE E DOIS NUMERO QUATORZE APT CENTO E UM"
         );
     }
+
+    #[test]
+    fn padronizacao_com_separadores() {
+        let casos = [
+            ("RUA -2", "RUA MENOS DOIS"),
+            ("-2.2", "MENOS DOIS VIRGULA DOIS"),
+            ("KM 2,05", "KM DOIS VIRGULA ZERO CINCO"),
+            ("KM 2,0", "KM DOIS VIRGULA ZERO"),
+            ("1.000", "MIL"),
+            ("1.500,5", "MIL E QUINHENTOS VIRGULA CINCO"),
+            ("2.000.001", "DOIS MILHOES E UM"),
+            ("1.50", "UM VIRGULA CINQUENTA"),
+            ("1.5000", "1.5000"),
+            ("RUA 1.5000 X", "RUA 1.5000 X"),
+            ("5-A", "CINCO-A"),
+            ("QUADRA 2-3", "QUADRA DOIS-TRES"),
+            ("99999999999999999999999", "99999999999999999999999"),
+        ];
+
+        for (entrada, esperado) in casos {
+            assert_eq!(padronizar_numeros_por_extenso(entrada), esperado);
+        }
+
+        let sem_separadores = OpcoesExtenso {
+            separador_milhar: false,
+            decimais: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            padronizar_numeros_por_extenso_com("-1.000,5", &sem_separadores),
+            "MENOS UM.ZERO,CINCO"
+        );
+        assert_eq!(
+            padronizar_numeros_por_extenso_com("2.5", &sem_separadores),
+            "DOIS.CINCO"
+        );
+    }
+
+    #[test]
+    fn genero_e_intervalo() {
+        let feminino = OpcoesExtenso {
+            genero: Genero::Feminino,
+            ..Default::default()
+        };
+        let casos = [
+            (1, "UMA"),
+            (2, "DUAS"),
+            (12, "DOZE"),
+            (21, "VINTE E UMA"),
+            (200, "DUZENTAS"),
+            (100, "CEM"),
+            (101, "CENTO E UMA"),
+            (2_000, "DUAS MIL"),
+            (202_000, "DUZENTAS E DUAS MIL"),
+            (2_000_000, "DOIS MILHOES"),
+            (2_000_002, "DOIS MILHOES E DUAS"),
+            (1_200_000_000, "UM BILHAO E DUZENTOS MILHOES"),
+        ];
+        for (numero, esperado) in casos {
+            assert_eq!(natural_por_extenso(numero, &feminino), esperado);
+        }
+
+        assert_eq!(
+            padronizar_numeros_por_extenso_com("2,2", &feminino),
+            "DUAS VIRGULA DUAS"
+        );
+        assert_eq!(
+            natural_por_extenso(5_000_000_000_000, &OpcoesExtenso::default()),
+            "CINCO TRILHOES"
+        );
+        assert_eq!(
+            inteiro_por_extenso(-3_000_000_000, &OpcoesExtenso::default()),
+            "MENOS TRES BILHOES"
+        );
+    }
+
+    #[test]
+    fn ordinais() {
+        assert_eq!(
+            ordinal_por_extenso(1, Genero::Masculino).as_deref(),
+            Some("PRIMEIRO")
+        );
+        assert_eq!(
+            ordinal_por_extenso(2, Genero::Feminino).as_deref(),
+            Some("SEGUNDA")
+        );
+        assert_eq!(
+            ordinal_por_extenso(10, Genero::Feminino).as_deref(),
+            Some("DECIMA")
+        );
+        assert_eq!(
+            ordinal_por_extenso(999, Genero::Masculino).as_deref(),
+            Some("NONGENTESIMO NONAGESIMO NONO")
+        );
+        assert_eq!(ordinal_por_extenso(1000, Genero::Masculino), None);
+
+        for n in 1..1000 {
+            for genero in [Genero::Masculino, Genero::Feminino] {
+                let extenso = ordinal_por_extenso(n, genero);
+                assert_eq!(
+                    extenso.as_deref().and_then(extenso_para_ordinal),
+                    Some((n, genero))
+                );
+            }
+        }
+
+        assert_eq!(extenso_para_ordinal(""), None);
+        assert_eq!(extenso_para_ordinal("TERCEIRA TERCEIRA"), None);
+        assert_eq!(extenso_para_ordinal("VIGESIMO TERCEIRA"), None);
+    }
+
+    #[test]
+    fn padroniza_ordinais() {
+        let casos = [
+            ("3ª TRAVESSA DA RUA X", "3A TRAVESSA DA RUA X"),
+            ("3A TRAVESSA", "3A TRAVESSA"),
+            ("3.A TRAVESSA", "3A TRAVESSA"),
+            ("3. A TRAVESSA", "3A TRAVESSA"),
+            ("TERCEIRA TRAVESSA", "3A TRAVESSA"),
+            ("VIGESIMA PRIMEIRA RUA", "21A RUA"),
+            ("2º ANDAR", "2O ANDAR"),
+            ("2° ANDAR", "2O ANDAR"),
+            ("2 º ANDAR", "2O ANDAR"),
+            ("SEGUNDO ANDAR", "2O ANDAR"),
+            ("APTO 21 1O ANDAR", "APTO 21 1O ANDAR"),
+            ("1º DE MAIO", "1O DE MAIO"),
+            ("PRIMEIRO DE MAIO", "PRIMEIRO DE MAIO"),
+            ("CASA 3A", "CASA 3A"),
+            ("QUADRA 3 A", "QUADRA 3 A"),
+            ("0A RUA", "0A RUA"),
+        ];
+
+        for (entrada, esperado) in casos {
+            assert_eq!(
+                padronizar_ordinais(entrada, FormatoOrdinal::Numerico),
+                esperado
+            );
+        }
+
+        let casos = [
+            ("3A TRAVESSA", "TERCEIRA TRAVESSA"),
+            ("2º ANDAR", "SEGUNDO ANDAR"),
+            ("TERCEIRA TRAVESSA", "TERCEIRA TRAVESSA"),
+            ("15A RUA", "DECIMA QUINTA RUA"),
+        ];
+
+        for (entrada, esperado) in casos {
+            assert_eq!(
+                padronizar_ordinais(entrada, FormatoOrdinal::PorExtenso),
+                esperado
+            );
+        }
+    }
+
+    #[test]
+    fn extenso_para_numero_ida_e_volta() {
+        for n in (-2_000..=2_000).chain([1_000_000, 1_000_001, 2_500_000, 1_234_567_890]) {
+            assert_eq!(extenso_para_numero(&numero_por_extenso(n)), Some(n as i64));
+        }
+
+        assert_eq!(extenso_para_numero("TREZENTAS E UMA"), Some(301));
+        assert_eq!(extenso_para_numero("MIL"), Some(1000));
+        assert_eq!(extenso_para_numero("UM MILHAO"), Some(1_000_000));
+        assert_eq!(extenso_para_numero("DUZENTOS TRINTA DOIS"), Some(232));
+        assert_eq!(extenso_para_numero(""), None);
+        assert_eq!(extenso_para_numero("E"), None);
+        assert_eq!(extenso_para_numero("DOIS E"), None);
+        assert_eq!(extenso_para_numero("CEM E UM"), None);
+        assert_eq!(extenso_para_numero("UM DOIS"), None);
+        assert_eq!(extenso_para_numero("MIL MIL"), None);
+        assert_eq!(extenso_para_numero("ZERO ZERO"), None);
+    }
+
+    #[test]
+    fn numeros_para_digitos() {
+        let casos = [
+            ("RUA DEZESSEIS", "RUA 16"),
+            ("RUA 16", "RUA 16"),
+            ("RUA XVI", "RUA 16"),
+            ("RUA DOM PEDRO II", "RUA DOM PEDRO 2"),
+            ("QUADRA C", "QUADRA C"),
+            ("RUA DI CAVALCANTI", "RUA DI CAVALCANTI"),
+            ("RUA MC DONALDS", "RUA MC DONALDS"),
+            ("LOJA CD", "LOJA CD"),
+            ("RUA LI", "RUA LI"),
+            ("RUA MIX", "RUA MIX"),
+            ("RUA DOIS MIL E VINTE", "RUA 2020"),
+            ("AVENIDA VINTE E TRES DE MAIO", "AVENIDA 23 DE MAIO"),
+            ("RUA DOIS E TRES", "RUA 2 E 3"),
+            ("RUA TRÊS MARIAS", "RUA 3 MARIAS"),
+            ("RUA SEM NUMERO", "RUA SEM NUMERO"),
+            ("MENOS DOIS", "MENOS 2"),
+            ("VINTE-E-UM", "20-E-1"),
+            ("", ""),
+        ];
+
+        for (entrada, esperado) in casos {
+            assert_eq!(padronizar_numeros_para_digitos(entrada), esperado);
+        }
+    }
 }
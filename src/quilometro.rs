@@ -0,0 +1,214 @@
+use std::{borrow::Cow, fmt, sync::LazyLock};
+
+use regex::{Captures, Regex};
+
+use crate::normalizar;
+
+/// Representa um marco quilométrico de rodovias e estradas, como "KM 23,5".
+///
+/// O valor é armazenado em metros, para evitar problemas de arredondamento na comparação.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Quilometro {
+    metros: u64,
+}
+
+impl Quilometro {
+    /// Cria um marco quilométrico a partir da distância em metros.
+    pub fn de_metros(metros: u64) -> Self {
+        Quilometro { metros }
+    }
+
+    /// Distância do marco em metros.
+    pub fn metros(&self) -> u64 {
+        self.metros
+    }
+
+    /// Distância do marco em quilômetros.
+    ///
+    /// # Exemplo
+    /// ```
+    /// use enderecobr_rs::quilometro::padronizar_quilometro;
+    /// let km = padronizar_quilometro("KM 23,5").unwrap();
+    /// assert_eq!(km.km(), 23.5);
+    /// assert_eq!(km.metros(), 23_500);
+    /// ```
+    pub fn km(&self) -> f64 {
+        self.metros as f64 / 1000.0
+    }
+}
+
+impl fmt::Display for Quilometro {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let inteiro = self.metros / 1000;
+        let fracao = self.metros % 1000;
+        if fracao == 0 {
+            write!(f, "KM {}", inteiro)
+        } else {
+            let fracao = format!("{:03}", fracao);
+            write!(f, "KM {},{}", inteiro, fracao.trim_end_matches('0'))
+        }
+    }
+}
+
+// Em Rust, a constant é criada durante a compilação, então só posso chamar funções muito restritas
+// quando uso `const`.
+static REGEX_QUILOMETRO: LazyLock<Regex> = LazyLock::new(criar_regex_quilometro);
+static REGEX_QUILOMETRO_TEXTO: LazyLock<Regex> = LazyLock::new(criar_regex_quilometro_texto);
+
+// Aceita "KM 23", "KM. 23,5", "KM23", "QUILOMETRO 23" e a notação de marco "KM 23+500".
+// Assim como em números, o ponto seguido de três dígitos é separador de milhar ("KM 1.200" é o
+// quilômetro 1200), mas seguido de um ou dois dígitos é separador decimal ("KM 23.5"). O último
+// grupo captura o restante de valores mal formados, como "KM 1,2345", que não são reconhecidos.
+const PADRAO_QUILOMETRO: &str =
+    r"\b(?:KM|QUILOMETRO)\.? ?(\d{1,3}(?:\.\d{3})+|\d+)(?:[,+](\d{1,3})|\.(\d{1,2}))?([.,+]\d+)?\b";
+
+#[allow(clippy::expect_used)]
+fn criar_regex_quilometro() -> Regex {
+    Regex::new(&format!("^{}$", PADRAO_QUILOMETRO))
+        .expect("Regex de quilômetro inválida (bug interno)")
+}
+
+#[allow(clippy::expect_used)]
+fn criar_regex_quilometro_texto() -> Regex {
+    Regex::new(PADRAO_QUILOMETRO).expect("Regex de quilômetro inválida (bug interno)")
+}
+
+fn quilometro_de_captura(captura: &Captures) -> Option<Quilometro> {
+    if captura.get(4).is_some() {
+        return None;
+    }
+    let inteiro: u64 = captura.get(1)?.as_str().replace('.', "").parse().ok()?;
+    // A parte decimal é completada à direita: "5" → 500 metros, "05" → 50 metros.
+    let fracao: u64 = captura
+        .get(2)
+        .or_else(|| captura.get(3))
+        .map_or(Some(0), |m| format!("{:0<3}", m.as_str()).parse().ok())?;
+
+    Some(Quilometro::de_metros(
+        inteiro.checked_mul(1000)?.checked_add(fracao)?,
+    ))
+}
+
+/// Interpreta uma string contendo apenas um marco quilométrico.
+///
+/// Retorna `None` caso a string não seja um marco quilométrico.
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::quilometro::padronizar_quilometro;
+/// assert_eq!(padronizar_quilometro("km 23,5").unwrap().to_string(), "KM 23,5");
+/// assert_eq!(padronizar_quilometro("KM. 7").unwrap().to_string(), "KM 7");
+/// assert_eq!(padronizar_quilometro("KM 12+300").unwrap().to_string(), "KM 12,3");
+/// assert_eq!(padronizar_quilometro("KM 1.200").unwrap().to_string(), "KM 1200");
+/// assert_eq!(padronizar_quilometro("Quilômetro 40").unwrap().to_string(), "KM 40");
+/// assert_eq!(padronizar_quilometro("KM 23.5").unwrap().to_string(), "KM 23,5");
+/// assert_eq!(padronizar_quilometro("KM 1,2345"), None);
+/// assert_eq!(padronizar_quilometro("RUA 7"), None);
+/// ```
+///
+pub fn padronizar_quilometro(valor: &str) -> Option<Quilometro> {
+    let valor = normalizar(valor);
+    let captura = REGEX_QUILOMETRO.captures(&valor)?;
+    quilometro_de_captura(&captura)
+}
+
+/// Substitui todos os marcos quilométricos de um texto já normalizado (sem acentos e em caixa
+/// alta) pela sua forma canônica, "KM 23,5".
+///
+/// Evita alocação de Strings usando `Cow::Borrowed` se o texto não contiver marcos.
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::quilometro::padronizar_quilometros;
+/// assert_eq!(padronizar_quilometros("RODOVIA BR-101 KM 23+500"), "RODOVIA BR-101 KM 23,5");
+/// assert_eq!(padronizar_quilometros("ESTRADA MUNICIPAL KM7"), "ESTRADA MUNICIPAL KM 7");
+/// assert_eq!(padronizar_quilometros("RUA AZUL"), "RUA AZUL");
+/// ```
+///
+pub fn padronizar_quilometros(texto: &str) -> Cow<'_, str> {
+    if !texto.contains("KM") && !texto.contains("QUILOMETRO") {
+        return Cow::Borrowed(texto);
+    }
+
+    REGEX_QUILOMETRO_TEXTO.replace_all(texto, |captura: &Captures| {
+        quilometro_de_captura(captura)
+            .map(|km| km.to_string())
+            .unwrap_or_else(|| captura[0].to_string())
+    })
+}
+
+/// Extrai o primeiro marco quilométrico de um texto, como um logradouro.
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::quilometro::extrair_quilometro;
+/// let km = extrair_quilometro("ROD BR 101 KM 23,5").unwrap();
+/// assert_eq!(km.km(), 23.5);
+/// assert_eq!(extrair_quilometro("RUA AZUL"), None);
+/// ```
+///
+pub fn extrair_quilometro(texto: &str) -> Option<Quilometro> {
+    let texto = normalizar(texto);
+    REGEX_QUILOMETRO_TEXTO
+        .captures_iter(&texto)
+        .find_map(|c| quilometro_de_captura(&c))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn padroniza_corretamente() {
+        let casos = [
+            ("KM 23", "KM 23"),
+            ("KM 23,5", "KM 23,5"),
+            ("KM 23,50", "KM 23,5"),
+            ("KM 23,05", "KM 23,05"),
+            ("KM 23,005", "KM 23,005"),
+            ("KM 0", "KM 0"),
+            ("KM 012", "KM 12"),
+            ("km23", "KM 23"),
+            ("KM. 1.200", "KM 1200"),
+            ("KM 1.200,5", "KM 1200,5"),
+            ("KM 1+200", "KM 1,2"),
+            ("KM 23.5", "KM 23,5"),
+            ("KM 23.05", "KM 23,05"),
+        ];
+
+        for (entrada, esperado) in casos {
+            assert_eq!(
+                padronizar_quilometro(entrada).map(|km| km.to_string()),
+                Some(esperado.to_string())
+            );
+        }
+
+        assert_eq!(padronizar_quilometro(""), None);
+        assert_eq!(padronizar_quilometro("KM"), None);
+        assert_eq!(padronizar_quilometro("KM 1,2345"), None);
+        assert_eq!(padronizar_quilometro("KM 23.5.1"), None);
+        assert_eq!(padronizar_quilometro("KM 1.2000"), None);
+        assert_eq!(padronizar_quilometro("KMS 12"), None);
+    }
+
+    #[test]
+    fn padroniza_texto() {
+        assert_eq!(
+            padronizar_quilometros("ROD BR 101 KM 1.200 E KM 30,0"),
+            "ROD BR 101 KM 1200 E KM 30"
+        );
+        assert_eq!(padronizar_quilometros("QUILOMETRO 5"), "KM 5");
+        assert_eq!(padronizar_quilometros("KMS"), "KMS");
+        assert_eq!(padronizar_quilometros("KM 1,2345"), "KM 1,2345");
+        assert_eq!(padronizar_quilometros("BR-101 KM 23.5"), "BR-101 KM 23,5");
+    }
+
+    #[test]
+    fn ordena_por_distancia() {
+        assert!(Quilometro::de_metros(1_200) < Quilometro::de_metros(12_000));
+        assert_eq!(
+            padronizar_quilometro("KM 7"),
+            Some(Quilometro::de_metros(7_000))
+        );
+    }
+}
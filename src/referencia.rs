@@ -0,0 +1,813 @@
+use std::collections::HashMap;
+
+use crate::{
+    blocagem::{digitos_cep, fonetica_logradouro, nao_vazio, ChaveMunicipio, RegistroEndereco},
+    padronizar_logradouros, separar_numero,
+    similaridade::PalavrasLogradouro,
+};
+
+/// Linha de uma tabela de referência de logradouros, como o CNEFE ou o cadastro de um
+/// município.
+///
+/// Cada linha representa um logradouro, ou um trecho dele quando a tabela tem as faixas de
+/// números.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LinhaReferencia {
+    /// Estado, em qualquer formato aceito por [crate::padronizar_estados_para_sigla]. Só é
+    /// necessário quando o município é identificado por um nome repetido em mais de um estado,
+    /// como "CAMPO GRANDE".
+    pub estado: Option<String>,
+    /// Município, pelo código do IBGE ou pelo nome.
+    pub municipio: Option<String>,
+    pub logradouro: String,
+    /// Primeiro número do trecho.
+    pub numero_inicial: Option<u32>,
+    /// Último número do trecho.
+    pub numero_final: Option<u32>,
+    pub cep: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+}
+
+impl LinhaReferencia {
+    /// Indica se o número está na faixa de números da linha. Quando só um dos extremos da faixa
+    /// é conhecido, a faixa tem apenas esse número.
+    pub fn contem_numero(&self, numero: u32) -> bool {
+        faixa_contem(self.numero_inicial, self.numero_final, numero)
+    }
+}
+
+/// Indica se o número está na faixa entre os dois extremos, em qualquer ordem. Quando só um dos
+/// extremos é conhecido, a faixa tem apenas esse número.
+pub(crate) fn faixa_contem(
+    numero_inicial: Option<u32>,
+    numero_final: Option<u32>,
+    numero: u32,
+) -> bool {
+    let (inicio, fim) = match (numero_inicial, numero_final) {
+        (Some(inicio), Some(fim)) => (inicio.min(fim), inicio.max(fim)),
+        (Some(n), None) | (None, Some(n)) => (n, n),
+        (None, None) => return false,
+    };
+    (inicio..=fim).contains(&numero)
+}
+
+/// Nível da correspondência entre um endereço e a tabela de referência, do mais para o menos
+/// confiável.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum NivelCorrespondencia {
+    /// Logradouro padronizado idêntico.
+    Exato,
+    /// Logradouro com a mesma chave fonética, como "RUA SOUZA LIMA" e "RUA SOUSA LIMA".
+    Fonetico,
+    /// Logradouro com similaridade acima do limiar, vide [OpcoesReferencia::limiar_aproximado].
+    Aproximado,
+    /// Nenhum logradouro correspondente, mas o CEP é o mesmo.
+    Cep,
+}
+
+/// Opções da busca na tabela de referência, vide [IndiceReferencia::resolver].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OpcoesReferencia {
+    /// Similaridade mínima entre os logradouros para uma correspondência aproximada.
+    pub limiar_aproximado: f64,
+    /// Se deve retornar uma linha com o mesmo CEP quando nenhum logradouro corresponde.
+    pub aceitar_cep: bool,
+}
+
+impl Default for OpcoesReferencia {
+    fn default() -> Self {
+        OpcoesReferencia {
+            limiar_aproximado: 0.85,
+            aceitar_cep: true,
+        }
+    }
+}
+
+/// Linha da tabela de referência escolhida para um endereço.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Correspondencia<'a> {
+    /// Posição da linha na tabela de referência.
+    pub indice: usize,
+    pub linha: &'a LinhaReferencia,
+    pub nivel: NivelCorrespondencia,
+    /// Similaridade entre os logradouros, de 0 a 1.
+    pub similaridade_logradouro: f64,
+    /// Se o número do endereço está na faixa de números da linha.
+    pub numero_no_intervalo: bool,
+    /// Se o CEP do endereço é o mesmo da linha.
+    pub cep_igual: bool,
+    /// Pontuação da correspondência, de 0 a 1: 80% da similaridade dos logradouros, mais 10%
+    /// se o número estiver na faixa e 10% se o CEP for o mesmo.
+    pub score: f64,
+}
+
+// Campos de uma linha de referência (ou do endereço buscado) no formato usado pelo índice.
+#[derive(Debug, Default)]
+struct ChavesReferencia {
+    logradouro: Option<String>,
+    palavras: PalavrasLogradouro,
+    fonetica: Option<String>,
+    // Inicial do código fonético do nome do logradouro, que separa as linhas comparadas na
+    // busca aproximada.
+    inicial: Option<char>,
+    municipio: Option<ChaveMunicipio>,
+    cep: Option<String>,
+}
+
+impl ChavesReferencia {
+    fn new(
+        logradouro: Option<&str>,
+        municipio: Option<&str>,
+        estado: Option<&str>,
+        cep: Option<&str>,
+    ) -> Self {
+        let logradouro = logradouro
+            .map(padronizar_logradouros)
+            .filter(|l| !l.is_empty());
+        let palavras = logradouro
+            .as_deref()
+            .map(PalavrasLogradouro::new)
+            .unwrap_or_default();
+        let fonetica = Some(palavras.fonetica.join(" ")).filter(|f| !f.is_empty());
+        let inicial = logradouro
+            .as_deref()
+            .and_then(fonetica_logradouro)
+            .and_then(|f| f.chars().next());
+
+        ChavesReferencia {
+            logradouro,
+            palavras,
+            fonetica,
+            inicial,
+            municipio: municipio.map(|m| ChaveMunicipio::new(m, estado)),
+            cep: cep.map(digitos_cep),
+        }
+    }
+
+    fn prefixo_cep(&self) -> Option<&str> {
+        self.cep.as_deref().and_then(|c| c.get(..5))
+    }
+}
+
+/// Índice em memória de uma tabela de referência de logradouros, usado para encontrar a linha
+/// que corresponde a um endereço.
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::Endereco;
+/// use enderecobr_rs::blocagem::RegistroEndereco;
+/// use enderecobr_rs::referencia::{
+///     IndiceReferencia, LinhaReferencia, NivelCorrespondencia, OpcoesReferencia,
+/// };
+///
+/// let linha = |logradouro: &str, inicio: u32, fim: u32, cep: &str| LinhaReferencia {
+///     municipio: Some("3304557".to_string()),
+///     logradouro: logradouro.to_string(),
+///     numero_inicial: Some(inicio),
+///     numero_final: Some(fim),
+///     cep: Some(cep.to_string()),
+///     ..Default::default()
+/// };
+/// let indice = IndiceReferencia::new(vec![
+///     linha("RUA SOUZA LIMA", 1, 199, "22081010"),
+///     linha("RUA SOUZA LIMA", 200, 399, "22081011"),
+///     linha("AVENIDA ATLANTICA", 1, 4000, "22021001"),
+/// ]);
+///
+/// let registro = RegistroEndereco {
+///     endereco: Endereco {
+///         logradouro: Some("R. Sousa Lima".to_string()),
+///         numero: Some("210".to_string()),
+///         ..Default::default()
+///     },
+///     municipio: Some("Rio de Janeiro".to_string()),
+///     estado: Some("RJ".to_string()),
+///     ..Default::default()
+/// };
+///
+/// let resultado = indice.resolver(&registro, &OpcoesReferencia::default()).unwrap();
+/// assert_eq!(resultado.indice, 1);
+/// assert_eq!(resultado.nivel, NivelCorrespondencia::Fonetico);
+/// assert!(resultado.numero_no_intervalo);
+/// ```
+#[derive(Debug, Default)]
+pub struct IndiceReferencia {
+    linhas: Vec<LinhaReferencia>,
+    chaves: Vec<ChavesReferencia>,
+    por_logradouro: HashMap<String, Vec<usize>>,
+    por_fonetica: HashMap<String, Vec<usize>>,
+    // Pelo nome do município e pela inicial fonética do logradouro.
+    por_municipio: HashMap<(String, Option<char>), Vec<usize>>,
+    por_prefixo_cep: HashMap<String, Vec<usize>>,
+}
+
+fn indexar(mapa: &mut HashMap<String, Vec<usize>>, chave: Option<&str>, indice: usize) {
+    if let Some(chave) = chave {
+        mapa.entry(chave.to_string()).or_default().push(indice);
+    }
+}
+
+impl IndiceReferencia {
+    /// Cria o índice, padronizando os logradouros, municípios e CEPs das linhas.
+    pub fn new(linhas: Vec<LinhaReferencia>) -> Self {
+        let mut indice = IndiceReferencia::default();
+
+        for (i, linha) in linhas.iter().enumerate() {
+            let chaves = ChavesReferencia::new(
+                Some(linha.logradouro.as_str()),
+                nao_vazio(&linha.municipio),
+                nao_vazio(&linha.estado),
+                nao_vazio(&linha.cep),
+            );
+            indexar(&mut indice.por_logradouro, chaves.logradouro.as_deref(), i);
+            indexar(&mut indice.por_fonetica, chaves.fonetica.as_deref(), i);
+            indexar(&mut indice.por_prefixo_cep, chaves.prefixo_cep(), i);
+            if let Some(municipio) = &chaves.municipio {
+                indice
+                    .por_municipio
+                    .entry((municipio.nome.clone(), chaves.inicial))
+                    .or_default()
+                    .push(i);
+            }
+            indice.chaves.push(chaves);
+        }
+
+        indice.linhas = linhas;
+        indice
+    }
+
+    /// Linhas da tabela de referência, na ordem original.
+    pub fn linhas(&self) -> &[LinhaReferencia] {
+        &self.linhas
+    }
+
+    pub fn len(&self) -> usize {
+        self.linhas.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.linhas.is_empty()
+    }
+
+    /// Posições de todas as linhas com o mesmo logradouro padronizado e o mesmo município da
+    /// linha informada, incluindo ela. Útil quando a tabela tem um trecho do logradouro por linha.
+    pub fn trechos_do_logradouro(&self, indice: usize) -> Vec<usize> {
+        let Some(chaves) = self.chaves.get(indice) else {
+            return vec![];
+        };
+        let Some(linhas) = chaves
+            .logradouro
+            .as_deref()
+            .and_then(|l| self.por_logradouro.get(l))
+        else {
+            return vec![indice];
+        };
+
+        linhas
+            .iter()
+            .copied()
+            .filter(|&i| self.chaves[i].municipio == chaves.municipio)
+            .collect()
+    }
+
+    /// Busca a linha da tabela de referência que melhor corresponde ao endereço.
+    ///
+    /// # Detalhes
+    /// Os níveis de [NivelCorrespondencia] são testados em ordem, e a busca para no primeiro
+    /// nível com alguma linha candidata. Quando o município do endereço e o da linha são
+    /// conhecidos, eles precisam ser o mesmo. Municípios com nomes repetidos em mais de um
+    /// estado, quando o estado não é informado, são comparados pelo nome.
+    ///
+    /// A busca aproximada compara o logradouro com as linhas do mesmo município ou, quando o
+    /// município não é conhecido, com as linhas que têm os mesmos cinco primeiros dígitos do
+    /// CEP. Para evitar a comparação com todas essas linhas, só são comparados os logradouros
+    /// cuja primeira palavra significativa (vide [crate::blocagem::ComponenteBlocagem::FoneticaLogradouro])
+    /// começa com o mesmo som. Sem município nem CEP, só as buscas exata e fonética são feitas.
+    ///
+    /// Dentre as candidatas de um mesmo nível, é escolhida a de maior
+    /// [Correspondencia::score], o que favorece os trechos que contêm o número do endereço.
+    pub fn resolver(
+        &self,
+        registro: &RegistroEndereco,
+        opcoes: &OpcoesReferencia,
+    ) -> Option<Correspondencia<'_>> {
+        let endereco = &registro.endereco;
+        let busca = ChavesReferencia::new(
+            nao_vazio(&endereco.logradouro),
+            nao_vazio(&registro.municipio),
+            nao_vazio(&registro.estado),
+            nao_vazio(&registro.cep),
+        );
+        let numero = nao_vazio(&endereco.numero).and_then(|n| separar_numero(n).numero());
+
+        let candidatas = |mapa: &HashMap<String, Vec<usize>>, chave: Option<&str>| -> Vec<usize> {
+            self.no_municipio(&busca, chave.and_then(|c| mapa.get(c)))
+        };
+
+        let exatas = candidatas(&self.por_logradouro, busca.logradouro.as_deref());
+        if let Some(c) = self.escolher(&busca, numero, &exatas, NivelCorrespondencia::Exato) {
+            return Some(c);
+        }
+
+        let foneticas = candidatas(&self.por_fonetica, busca.fonetica.as_deref());
+        if let Some(c) = self.escolher(&busca, numero, &foneticas, NivelCorrespondencia::Fonetico) {
+            return Some(c);
+        }
+
+        if busca.logradouro.is_some() {
+            let vizinhas = match &busca.municipio {
+                Some(municipio) => self.no_municipio(
+                    &busca,
+                    self.por_municipio
+                        .get(&(municipio.nome.clone(), busca.inicial)),
+                ),
+                None => candidatas(&self.por_prefixo_cep, busca.prefixo_cep())
+                    .into_iter()
+                    .filter(|&i| self.chaves[i].inicial == busca.inicial)
+                    .collect(),
+            };
+            let aproximada = self
+                .escolher(&busca, numero, &vizinhas, NivelCorrespondencia::Aproximado)
+                .filter(|c| c.similaridade_logradouro >= opcoes.limiar_aproximado);
+            if aproximada.is_some() {
+                return aproximada;
+            }
+        }
+
+        if !opcoes.aceitar_cep {
+            return None;
+        }
+        let mesmo_cep: Vec<_> = candidatas(&self.por_prefixo_cep, busca.prefixo_cep())
+            .into_iter()
+            .filter(|&i| busca.cep.is_some() && self.chaves[i].cep == busca.cep)
+            .collect();
+        self.escolher(&busca, numero, &mesmo_cep, NivelCorrespondencia::Cep)
+    }
+
+    // Linhas candidatas que não divergem do município buscado.
+    fn no_municipio(&self, busca: &ChavesReferencia, linhas: Option<&Vec<usize>>) -> Vec<usize> {
+        let mesmo_municipio = |i: &usize| match (&busca.municipio, &self.chaves[*i].municipio) {
+            (Some(a), Some(b)) => a.compativel(b),
+            _ => true,
+        };
+        linhas
+            .map(|linhas| linhas.iter().copied().filter(mesmo_municipio).collect())
+            .unwrap_or_default()
+    }
+
+    fn escolher(
+        &self,
+        busca: &ChavesReferencia,
+        numero: Option<u32>,
+        candidatas: &[usize],
+        nivel: NivelCorrespondencia,
+    ) -> Option<Correspondencia<'_>> {
+        candidatas
+            .iter()
+            .map(|&i| self.avaliar(busca, numero, i, nivel))
+            // Em caso de empate, fica a primeira linha da tabela.
+            .reduce(|melhor, c| if c.score > melhor.score { c } else { melhor })
+    }
+
+    fn avaliar(
+        &self,
+        busca: &ChavesReferencia,
+        numero: Option<u32>,
+        indice: usize,
+        nivel: NivelCorrespondencia,
+    ) -> Correspondencia<'_> {
+        let linha = &self.linhas[indice];
+        let chaves = &self.chaves[indice];
+
+        let similaridade_logradouro = match (&busca.logradouro, &chaves.logradouro) {
+            (Some(a), Some(b)) if a == b => 1.0,
+            (Some(_), Some(_)) => busca.palavras.similaridade(&chaves.palavras),
+            _ => 0.0,
+        };
+        let numero_no_intervalo = numero.is_some_and(|n| linha.contem_numero(n));
+        let cep_igual = busca.cep.is_some() && busca.cep == chaves.cep;
+
+        let score = 0.8 * similaridade_logradouro
+            + if numero_no_intervalo { 0.1 } else { 0.0 }
+            + if cep_igual { 0.1 } else { 0.0 };
+
+        Correspondencia {
+            indice,
+            linha,
+            nivel,
+            similaridade_logradouro,
+            numero_no_intervalo,
+            cep_igual,
+            score,
+        }
+    }
+}
+
+#[cfg(feature = "polars")]
+pub use lote_polars::ColunasReferencia;
+
+#[cfg(feature = "polars")]
+pub(crate) use lote_polars::{coluna, escanear_parquet};
+
+#[cfg(feature = "polars")]
+mod lote_polars {
+    use polars::prelude::{
+        col, lit, DataType, Expr, LazyFrame, PlPath, PolarsResult, ScanArgsParquet, NULL,
+    };
+
+    use super::{IndiceReferencia, LinhaReferencia};
+
+    /// Nomes das colunas da tabela de referência em um DataFrame. As colunas ausentes
+    /// (`None`) ficam vazias em todas as linhas.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ColunasReferencia {
+        pub estado: Option<String>,
+        pub municipio: Option<String>,
+        pub logradouro: String,
+        pub numero_inicial: Option<String>,
+        pub numero_final: Option<String>,
+        pub cep: Option<String>,
+        pub latitude: Option<String>,
+        pub longitude: Option<String>,
+    }
+
+    impl Default for ColunasReferencia {
+        /// Colunas com os mesmos nomes dos campos de [LinhaReferencia].
+        fn default() -> Self {
+            ColunasReferencia {
+                estado: Some("estado".to_string()),
+                municipio: Some("municipio".to_string()),
+                logradouro: "logradouro".to_string(),
+                numero_inicial: Some("numero_inicial".to_string()),
+                numero_final: Some("numero_final".to_string()),
+                cep: Some("cep".to_string()),
+                latitude: Some("latitude".to_string()),
+                longitude: Some("longitude".to_string()),
+            }
+        }
+    }
+
+    /// Seleciona a coluna `nome` com o tipo e o nome informados, ou uma coluna vazia quando o
+    /// nome não é informado.
+    pub(crate) fn coluna(nome: Option<&str>, tipo: DataType, alias: &str) -> Expr {
+        match nome {
+            Some(nome) => col(nome),
+            None => lit(NULL),
+        }
+        .cast(tipo)
+        .alias(alias)
+    }
+
+    /// Abre um arquivo Parquet para leitura preguiçosa.
+    pub(crate) fn escanear_parquet(caminho: &str) -> PolarsResult<LazyFrame> {
+        LazyFrame::scan_parquet(PlPath::new(caminho), ScanArgsParquet::default())
+    }
+
+    impl IndiceReferencia {
+        /// Cria o índice a partir de um DataFrame com a tabela de referência.
+        ///
+        /// # Exemplo
+        /// ```
+        /// use enderecobr_rs::referencia::{ColunasReferencia, IndiceReferencia};
+        /// use polars::prelude::*;
+        ///
+        /// let df = df!(
+        ///     "municipio" => [3304557, 3304557],
+        ///     "logradouro" => ["RUA SOUZA LIMA", "AVENIDA ATLANTICA"],
+        ///     "cep" => ["22081-010", "22021-001"],
+        /// )
+        /// .unwrap();
+        /// let colunas = ColunasReferencia {
+        ///     estado: None,
+        ///     numero_inicial: None,
+        ///     numero_final: None,
+        ///     latitude: None,
+        ///     longitude: None,
+        ///     ..Default::default()
+        /// };
+        ///
+        /// let indice = IndiceReferencia::de_dataframe(df.lazy(), &colunas).unwrap();
+        /// assert_eq!(indice.len(), 2);
+        /// assert_eq!(indice.linhas()[0].municipio.as_deref(), Some("3304557"));
+        /// ```
+        pub fn de_dataframe(df: LazyFrame, colunas: &ColunasReferencia) -> PolarsResult<Self> {
+            let df = df
+                .select([
+                    coluna(colunas.estado.as_deref(), DataType::String, "estado"),
+                    coluna(colunas.municipio.as_deref(), DataType::String, "municipio"),
+                    coluna(Some(&colunas.logradouro), DataType::String, "logradouro"),
+                    coluna(
+                        colunas.numero_inicial.as_deref(),
+                        DataType::UInt32,
+                        "numero_inicial",
+                    ),
+                    coluna(
+                        colunas.numero_final.as_deref(),
+                        DataType::UInt32,
+                        "numero_final",
+                    ),
+                    coluna(colunas.cep.as_deref(), DataType::String, "cep"),
+                    coluna(colunas.latitude.as_deref(), DataType::Float64, "latitude"),
+                    coluna(colunas.longitude.as_deref(), DataType::Float64, "longitude"),
+                ])
+                .collect()?;
+
+            let estados = df.column("estado")?.str()?;
+            let municipios = df.column("municipio")?.str()?;
+            let logradouros = df.column("logradouro")?.str()?;
+            let inicios = df.column("numero_inicial")?.u32()?;
+            let fins = df.column("numero_final")?.u32()?;
+            let ceps = df.column("cep")?.str()?;
+            let latitudes = df.column("latitude")?.f64()?;
+            let longitudes = df.column("longitude")?.f64()?;
+
+            let linhas = (0..df.height())
+                .map(|i| LinhaReferencia {
+                    estado: estados.get(i).map(str::to_string),
+                    municipio: municipios.get(i).map(str::to_string),
+                    logradouro: logradouros.get(i).unwrap_or_default().to_string(),
+                    numero_inicial: inicios.get(i),
+                    numero_final: fins.get(i),
+                    cep: ceps.get(i).map(str::to_string),
+                    latitude: latitudes.get(i),
+                    longitude: longitudes.get(i),
+                })
+                .collect();
+
+            Ok(IndiceReferencia::new(linhas))
+        }
+
+        /// Cria o índice a partir de um arquivo Parquet com a tabela de referência, vide
+        /// [IndiceReferencia::de_dataframe].
+        pub fn ler_parquet(caminho: &str, colunas: &ColunasReferencia) -> PolarsResult<Self> {
+            IndiceReferencia::de_dataframe(escanear_parquet(caminho)?, colunas)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Endereco;
+
+    #[test]
+    fn niveis_de_correspondencia() {
+        let indice = IndiceReferencia::new(vec![
+            LinhaReferencia {
+                municipio: Some("3550308".to_string()),
+                logradouro: "AVENIDA PAULISTA".to_string(),
+                numero_inicial: Some(1),
+                numero_final: Some(999),
+                cep: Some("01310100".to_string()),
+                ..Default::default()
+            },
+            LinhaReferencia {
+                municipio: Some("3550308".to_string()),
+                logradouro: "AVENIDA PAULISTA".to_string(),
+                numero_inicial: Some(1000),
+                numero_final: Some(2000),
+                cep: Some("01310200".to_string()),
+                ..Default::default()
+            },
+            LinhaReferencia {
+                municipio: Some("3550308".to_string()),
+                logradouro: "RUA AUGUSTA".to_string(),
+                numero_inicial: Some(1),
+                numero_final: Some(3000),
+                cep: Some("01305000".to_string()),
+                ..Default::default()
+            },
+            LinhaReferencia {
+                municipio: Some("3550308".to_string()),
+                logradouro: "RUA DOUTOR ARNALDO".to_string(),
+                numero_inicial: Some(1),
+                numero_final: Some(900),
+                cep: Some("01246000".to_string()),
+                ..Default::default()
+            },
+            LinhaReferencia {
+                municipio: Some("3509502".to_string()),
+                logradouro: "RUA AUGUSTA".to_string(),
+                numero_inicial: Some(1),
+                numero_final: Some(500),
+                cep: Some("13010000".to_string()),
+                ..Default::default()
+            },
+        ]);
+        let opcoes = OpcoesReferencia::default();
+        let sp = RegistroEndereco {
+            estado: Some("SP".to_string()),
+            ..Default::default()
+        };
+        let resolver = |r: RegistroEndereco| {
+            indice
+                .resolver(&r, &opcoes)
+                .map(|c| (c.indice, c.nivel, c.numero_no_intervalo))
+        };
+
+        assert_eq!(
+            resolver(RegistroEndereco {
+                endereco: Endereco {
+                    logradouro: Some("AV PAULISTA".to_string()),
+                    numero: Some("1578".to_string()),
+                    ..Default::default()
+                },
+                municipio: Some("SAO PAULO".to_string()),
+                ..sp.clone()
+            }),
+            Some((1, NivelCorrespondencia::Exato, true))
+        );
+        assert_eq!(
+            resolver(RegistroEndereco {
+                endereco: Endereco {
+                    logradouro: Some("R AUGUSTA".to_string()),
+                    numero: Some("10".to_string()),
+                    ..Default::default()
+                },
+                municipio: Some("CAMPINAS".to_string()),
+                ..sp.clone()
+            }),
+            Some((4, NivelCorrespondencia::Exato, true))
+        );
+        assert_eq!(
+            resolver(RegistroEndereco {
+                endereco: Endereco {
+                    logradouro: Some("RUA DR ARNALDO".to_string()),
+                    ..Default::default()
+                },
+                municipio: Some("3550308".to_string()),
+                ..sp.clone()
+            }),
+            Some((3, NivelCorrespondencia::Exato, false))
+        );
+        assert_eq!(
+            resolver(RegistroEndereco {
+                endereco: Endereco {
+                    logradouro: Some("RUA AUGUSTTA".to_string()),
+                    numero: Some("100".to_string()),
+                    ..Default::default()
+                },
+                municipio: Some("SAO PAULO".to_string()),
+                ..sp.clone()
+            }),
+            Some((2, NivelCorrespondencia::Fonetico, true))
+        );
+        assert_eq!(
+            resolver(RegistroEndereco {
+                endereco: Endereco {
+                    logradouro: Some("AVENIDA PAULISA".to_string()),
+                    numero: Some("100".to_string()),
+                    ..Default::default()
+                },
+                municipio: Some("SAO PAULO".to_string()),
+                ..sp.clone()
+            }),
+            Some((0, NivelCorrespondencia::Aproximado, true))
+        );
+        assert_eq!(
+            resolver(RegistroEndereco {
+                endereco: Endereco {
+                    logradouro: Some("RUA INEXISTENTE".to_string()),
+                    ..Default::default()
+                },
+                cep: Some("01305-000".to_string()),
+                ..sp.clone()
+            }),
+            Some((2, NivelCorrespondencia::Cep, false))
+        );
+        assert_eq!(
+            resolver(RegistroEndereco {
+                endereco: Endereco {
+                    logradouro: Some("RUA INEXISTENTE".to_string()),
+                    ..Default::default()
+                },
+                ..sp
+            }),
+            None
+        );
+    }
+
+    #[test]
+    fn respeita_opcoes() {
+        let indice = IndiceReferencia::new(vec![
+            LinhaReferencia {
+                municipio: Some("3550308".to_string()),
+                logradouro: "AVENIDA PAULISTA".to_string(),
+                numero_inicial: Some(1),
+                numero_final: Some(999),
+                cep: Some("01310100".to_string()),
+                ..Default::default()
+            },
+            LinhaReferencia {
+                municipio: Some("3550308".to_string()),
+                logradouro: "RUA AUGUSTA".to_string(),
+                numero_inicial: Some(1),
+                numero_final: Some(3000),
+                cep: Some("01305000".to_string()),
+                ..Default::default()
+            },
+        ]);
+        let r = RegistroEndereco {
+            endereco: Endereco {
+                logradouro: Some("RUA INEXISTENTE".to_string()),
+                ..Default::default()
+            },
+            estado: Some("SP".to_string()),
+            municipio: Some("SAO PAULO".to_string()),
+            cep: Some("01305000".to_string()),
+        };
+        let sem_cep = OpcoesReferencia {
+            aceitar_cep: false,
+            ..Default::default()
+        };
+        assert!(indice.resolver(&r, &OpcoesReferencia::default()).is_some());
+        assert!(indice.resolver(&r, &sem_cep).is_none());
+
+        let r = RegistroEndereco {
+            endereco: Endereco {
+                logradouro: Some("AVENIDA PAULISA".to_string()),
+                ..Default::default()
+            },
+            estado: Some("SP".to_string()),
+            municipio: Some("SAO PAULO".to_string()),
+            cep: None,
+        };
+        let rigido = OpcoesReferencia {
+            limiar_aproximado: 1.0,
+            aceitar_cep: false,
+        };
+        assert!(indice.resolver(&r, &rigido).is_none());
+    }
+
+    #[test]
+    fn municipios_com_nome_repetido() {
+        let sem_estado = LinhaReferencia {
+            municipio: Some("CAMPO GRANDE".to_string()),
+            logradouro: "RUA DOM AQUINO".to_string(),
+            ..Default::default()
+        };
+        let busca = |logradouro: &str| RegistroEndereco {
+            endereco: Endereco {
+                logradouro: Some(logradouro.to_string()),
+                ..Default::default()
+            },
+            estado: Some("MS".to_string()),
+            municipio: Some("CAMPO GRANDE".to_string()),
+            cep: None,
+        };
+        let opcoes = OpcoesReferencia::default();
+        let resolver = |indice: &IndiceReferencia, logradouro: &str| {
+            indice
+                .resolver(&busca(logradouro), &opcoes)
+                .map(|c| (c.indice, c.nivel))
+        };
+
+        // Sem o estado na tabela, o município é comparado pelo nome.
+        let indice = IndiceReferencia::new(vec![sem_estado.clone()]);
+        assert_eq!(
+            resolver(&indice, "R DOM AQUINO"),
+            Some((0, NivelCorrespondencia::Exato))
+        );
+        assert_eq!(
+            resolver(&indice, "RUA DOM AQUIMO"),
+            Some((0, NivelCorrespondencia::Aproximado))
+        );
+
+        // Com o estado, o município de outro estado é descartado.
+        let indice = IndiceReferencia::new(vec![
+            LinhaReferencia {
+                estado: Some("AL".to_string()),
+                ..sem_estado.clone()
+            },
+            LinhaReferencia {
+                estado: Some("MS".to_string()),
+                ..sem_estado
+            },
+        ]);
+        assert_eq!(
+            resolver(&indice, "R DOM AQUINO"),
+            Some((1, NivelCorrespondencia::Exato))
+        );
+    }
+
+    #[test]
+    fn faixa_de_numeros() {
+        let l = LinhaReferencia {
+            logradouro: "RUA A".to_string(),
+            numero_inicial: Some(20),
+            numero_final: Some(10),
+            ..Default::default()
+        };
+        assert!(l.contem_numero(10));
+        assert!(l.contem_numero(15));
+        assert!(!l.contem_numero(21));
+
+        let l = LinhaReferencia {
+            numero_final: Some(5),
+            ..Default::default()
+        };
+        assert!(l.contem_numero(5));
+        assert!(!LinhaReferencia::default().contem_numero(5));
+    }
+}
@@ -0,0 +1,215 @@
+use std::{borrow::Cow, fmt, sync::LazyLock};
+
+use itertools::Itertools;
+use regex::{Captures, Regex};
+
+use crate::{
+    estado::{buscar_estado_estrito, Estado, ESTADOS},
+    normalizar,
+};
+
+/// Esfera responsável por uma rodovia.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Jurisdicao {
+    /// Rodovias federais, com prefixo "BR".
+    Federal,
+    /// Rodovias estaduais, com o prefixo da sigla da UF, como "SP" ou "MG".
+    Estadual,
+}
+
+/// Representa o identificador de uma rodovia, como "BR-101" ou "SP-055".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rodovia {
+    pub jurisdicao: Jurisdicao,
+    /// Estado da rodovia estadual. É `None` para rodovias federais.
+    pub uf: Option<Estado>,
+    pub numero: u16,
+}
+
+impl Rodovia {
+    /// Prefixo do identificador da rodovia: "BR" para rodovias federais e a sigla da UF
+    /// para rodovias estaduais.
+    pub fn prefixo(&self) -> &'static str {
+        match (self.jurisdicao, self.uf) {
+            (Jurisdicao::Estadual, Some(uf)) => uf.sigla,
+            _ => "BR",
+        }
+    }
+}
+
+impl fmt::Display for Rodovia {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{:03}", self.prefixo(), self.numero)
+    }
+}
+
+// Em Rust, a constant é criada durante a compilação, então só posso chamar funções muito restritas
+// quando uso `const`.
+static REGEX_RODOVIA: LazyLock<Regex> = LazyLock::new(criar_regex_rodovia);
+static REGEX_RODOVIA_TEXTO: LazyLock<Regex> = LazyLock::new(criar_regex_rodovia_texto);
+
+// Prefixos de rodovias: BR e as siglas das UFs.
+fn padrao_prefixos() -> String {
+    format!("BR|{}", ESTADOS.iter().map(|e| e.sigla).join("|"))
+}
+
+#[allow(clippy::expect_used)]
+fn criar_regex_rodovia() -> Regex {
+    Regex::new(&format!(
+        r"^(?:(?:RODOVIA|ROD|RDV)\b[.,]? ?)?({})\.? ?-? ?(\d{{1,3}})$",
+        padrao_prefixos()
+    ))
+    .expect("Regex de rodovia inválida (bug interno)")
+}
+
+#[allow(clippy::expect_used)]
+fn criar_regex_rodovia_texto() -> Regex {
+    Regex::new(&format!(
+        r"\b((?:RODOVIA|ROD|RDV)\b[.,]? ?)?\b({})\.? ?-? ?(\d{{1,3}})\b",
+        padrao_prefixos()
+    ))
+    .expect("Regex de rodovia inválida (bug interno)")
+}
+
+fn rodovia_de_prefixo(prefixo: &str, numero: &str) -> Option<Rodovia> {
+    let numero = numero.parse().ok()?;
+    if prefixo == "BR" {
+        return Some(Rodovia {
+            jurisdicao: Jurisdicao::Federal,
+            uf: None,
+            numero,
+        });
+    }
+
+    Some(Rodovia {
+        jurisdicao: Jurisdicao::Estadual,
+        uf: Some(buscar_estado_estrito(prefixo).ok()?),
+        numero,
+    })
+}
+
+/// Interpreta uma string contendo apenas o identificador de uma rodovia.
+///
+/// Retorna `None` caso a string não seja o identificador de uma rodovia federal ou estadual.
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::rodovia::{padronizar_rodovia, Jurisdicao};
+/// assert_eq!(padronizar_rodovia("rod. br101").unwrap().to_string(), "BR-101");
+/// assert_eq!(padronizar_rodovia("SP 55").unwrap().to_string(), "SP-055");
+/// assert_eq!(padronizar_rodovia("SP055").unwrap().to_string(), "SP-055");
+///
+/// let rodovia = padronizar_rodovia("MG-050").unwrap();
+/// assert_eq!(rodovia.jurisdicao, Jurisdicao::Estadual);
+/// assert_eq!(rodovia.uf.map(|uf| uf.nome), Some("MINAS GERAIS"));
+/// assert_eq!(rodovia.numero, 50);
+///
+/// assert_eq!(padronizar_rodovia("RUA AZUL"), None);
+/// ```
+///
+/// # Detalhes
+/// São reconhecidos o prefixo "BR", para rodovias federais, e as siglas das UFs, para rodovias
+/// estaduais, opcionalmente precedidos de "RODOVIA", "ROD." ou "RDV". O número da rodovia é
+/// completado com zeros à esquerda até ter três dígitos.
+///
+pub fn padronizar_rodovia(valor: &str) -> Option<Rodovia> {
+    let valor = normalizar(valor);
+    let captura = REGEX_RODOVIA.captures(&valor)?;
+    rodovia_de_prefixo(&captura[1], &captura[2])
+}
+
+/// Substitui os identificadores de rodovias de um texto já normalizado (sem acentos e em caixa
+/// alta) pela sua forma canônica, como "BR-101".
+///
+/// Como várias siglas de UFs também aparecem em endereços sem se referirem a rodovias (AL, SE,
+/// PA, etc.), só são substituídos os identificadores precedidos de "RODOVIA" (ou suas
+/// abreviações), os de rodovias federais e os de número com três dígitos.
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::rodovia::padronizar_rodovias;
+/// assert_eq!(padronizar_rodovias("ROD. SP 55 KM 10"), "ROD. SP-055 KM 10");
+/// assert_eq!(padronizar_rodovias("MARGEM DA BR 40"), "MARGEM DA BR-040");
+/// assert_eq!(padronizar_rodovias("RUA AL 5"), "RUA AL 5");
+/// ```
+///
+pub fn padronizar_rodovias(texto: &str) -> Cow<'_, str> {
+    REGEX_RODOVIA_TEXTO.replace_all(texto, |captura: &Captures| {
+        let inicio = captura.get(1).map_or("", |m| m.as_str());
+        let prefixo = &captura[2];
+        let numero = &captura[3];
+
+        let explicita = !inicio.is_empty() || prefixo == "BR" || numero.len() == 3;
+        match rodovia_de_prefixo(prefixo, numero) {
+            Some(rodovia) if explicita => format!("{}{}", inicio, rodovia),
+            _ => captura[0].to_string(),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn padroniza_corretamente() {
+        let casos = [
+            ("BR 101", "BR-101"),
+            ("BR101", "BR-101"),
+            ("BR-101", "BR-101"),
+            ("BR.101", "BR-101"),
+            ("BR 40", "BR-040"),
+            ("rod. br101", "BR-101"),
+            ("RODOVIA BR 116", "BR-116"),
+            ("RDV SP 55", "SP-055"),
+            ("SP-055", "SP-055"),
+            ("SP055", "SP-055"),
+            ("SP - 55", "SP-055"),
+            ("DF-001", "DF-001"),
+        ];
+
+        for (entrada, esperado) in casos {
+            assert_eq!(
+                padronizar_rodovia(entrada).map(|r| r.to_string()),
+                Some(esperado.to_string())
+            );
+        }
+
+        assert_eq!(padronizar_rodovia(""), None);
+        assert_eq!(padronizar_rodovia("BR"), None);
+        assert_eq!(padronizar_rodovia("XX 101"), None);
+        assert_eq!(padronizar_rodovia("BR 1010"), None);
+    }
+
+    #[test]
+    fn estrutura_rodovia() {
+        let federal = padronizar_rodovia("BR 101");
+        assert_eq!(
+            federal.as_ref().map(|r| (r.jurisdicao, r.prefixo())),
+            Some((Jurisdicao::Federal, "BR"))
+        );
+        assert_eq!(federal.and_then(|r| r.uf), None);
+
+        let estadual = padronizar_rodovia("RS 020");
+        assert_eq!(
+            estadual.map(|r| (r.jurisdicao, r.uf.map(|uf| uf.codigo), r.numero)),
+            Some((Jurisdicao::Estadual, Some("43"), 20))
+        );
+    }
+
+    #[test]
+    fn padroniza_texto() {
+        let casos = [
+            ("RODOVIA BR 101 KM 20", "RODOVIA BR-101 KM 20"),
+            ("RODOVIA SP 55", "RODOVIA SP-055"),
+            ("ACESSO PE 060", "ACESSO PE-060"),
+            ("RUA SE 1", "RUA SE 1"),
+            ("QUADRA PA 1234", "QUADRA PA 1234"),
+            ("RUA AZUL", "RUA AZUL"),
+        ];
+
+        for (entrada, esperado) in casos {
+            assert_eq!(padronizar_rodovias(entrada), esperado);
+        }
+    }
+}
@@ -0,0 +1,478 @@
+use itertools::Itertools;
+
+use crate::{
+    complemento::TipoComplemento,
+    fonetica::OpcoesFonetica,
+    metaphone::metaphone_tokens_com,
+    normalizar,
+    numero::{separar_numero, NumeroEndereco},
+    separar_complemento, Endereco,
+};
+
+/// Resultado da comparação entre dois endereços, vide [similaridade].
+///
+/// Cada campo vai de 0 (totalmente diferentes) a 1 (equivalentes), e é `None` quando o campo
+/// está ausente em algum dos endereços.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Similaridade {
+    pub logradouro: Option<f64>,
+    pub numero: Option<f64>,
+    pub complemento: Option<f64>,
+    pub localidade: Option<f64>,
+    /// Média dos campos presentes, ponderada por [PesosSimilaridade].
+    pub total: f64,
+}
+
+/// Pesos de cada campo no cálculo de [Similaridade::total].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PesosSimilaridade {
+    pub logradouro: f64,
+    pub numero: f64,
+    pub complemento: f64,
+    pub localidade: f64,
+}
+
+impl Default for PesosSimilaridade {
+    fn default() -> Self {
+        PesosSimilaridade {
+            logradouro: 0.4,
+            numero: 0.3,
+            complemento: 0.1,
+            localidade: 0.2,
+        }
+    }
+}
+
+/// Calcula a similaridade de Jaro-Winkler entre dois textos, de 0 a 1.
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::similaridade::jaro_winkler;
+/// assert_eq!(jaro_winkler("SOUZA", "SOUZA"), 1.0);
+/// assert!(jaro_winkler("SOUZA", "SOUSA") > 0.9);
+/// assert_eq!(jaro_winkler("ABC", "XYZ"), 0.0);
+/// ```
+pub fn jaro_winkler(a: &str, b: &str) -> f64 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    // Caracteres iguais só contam como correspondentes se estiverem próximos.
+    let janela = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut usados_b = vec![false; b.len()];
+    let mut correspondentes_a = Vec::with_capacity(a.len());
+
+    for (i, ca) in a.iter().enumerate() {
+        let inicio = i.saturating_sub(janela);
+        let fim = (i + janela + 1).min(b.len());
+        if let Some(j) = (inicio..fim).find(|&j| !usados_b[j] && b[j] == *ca) {
+            usados_b[j] = true;
+            correspondentes_a.push(*ca);
+        }
+    }
+
+    if correspondentes_a.is_empty() {
+        return 0.0;
+    }
+
+    let correspondentes_b = b
+        .iter()
+        .zip(&usados_b)
+        .filter(|(_, usado)| **usado)
+        .map(|(c, _)| *c);
+    let transposicoes = correspondentes_a
+        .iter()
+        .zip(correspondentes_b)
+        .filter(|(ca, cb)| **ca != *cb)
+        .count();
+
+    let m = correspondentes_a.len() as f64;
+    let jaro =
+        (m / a.len() as f64 + m / b.len() as f64 + (m - transposicoes as f64 / 2.0) / m) / 3.0;
+
+    let prefixo = a
+        .iter()
+        .zip(&b)
+        .take(4)
+        .take_while(|(ca, cb)| ca == cb)
+        .count();
+
+    jaro + prefixo as f64 * 0.1 * (1.0 - jaro)
+}
+
+// Média, para cada palavra de `a`, da maior similaridade com alguma palavra de `b`.
+fn melhor_correspondencia<S: AsRef<str>>(a: &[S], b: &[S]) -> f64 {
+    let soma: f64 = a
+        .iter()
+        .map(|pa| {
+            b.iter()
+                .map(|pb| jaro_winkler(pa.as_ref(), pb.as_ref()))
+                .fold(0.0, f64::max)
+        })
+        .sum();
+    soma / a.len() as f64
+}
+
+fn similaridade_palavras<S: AsRef<str>>(a: &[S], b: &[S]) -> f64 {
+    match (a.is_empty(), b.is_empty()) {
+        (true, true) => 1.0,
+        (true, false) | (false, true) => 0.0,
+        (false, false) => (melhor_correspondencia(a, b) + melhor_correspondencia(b, a)) / 2.0,
+    }
+}
+
+/// Calcula a similaridade entre os conjuntos de palavras de dois textos, de 0 a 1, usando
+/// [jaro_winkler] entre as palavras. A ordem e a repetição das palavras não importam.
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::similaridade::similaridade_conjunto_tokens;
+/// assert_eq!(similaridade_conjunto_tokens("JARDIM AMERICA", "AMERICA JARDIM"), 1.0);
+/// assert!(similaridade_conjunto_tokens("RUA SOUZA LIMA", "RUA SOUSA LIMA") > 0.95);
+/// ```
+pub fn similaridade_conjunto_tokens(a: &str, b: &str) -> f64 {
+    let a = normalizar(a);
+    let b = normalizar(b);
+    let palavras_a = a.split_whitespace().unique().collect_vec();
+    let palavras_b = b.split_whitespace().unique().collect_vec();
+    similaridade_palavras(&palavras_a, &palavras_b)
+}
+
+// Palavras e códigos fonéticos de um logradouro, calculados uma só vez quando ele é comparado
+// com muitos outros.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct PalavrasLogradouro {
+    pub(crate) palavras: Vec<String>,
+    pub(crate) fonetica: Vec<String>,
+}
+
+impl PalavrasLogradouro {
+    pub(crate) fn new(logradouro: &str) -> Self {
+        let opcoes = OpcoesFonetica {
+            remover_stopwords: true,
+            ..Default::default()
+        };
+        PalavrasLogradouro {
+            palavras: normalizar(logradouro)
+                .split_whitespace()
+                .unique()
+                .map(str::to_string)
+                .collect(),
+            fonetica: metaphone_tokens_com(logradouro, &opcoes),
+        }
+    }
+
+    pub(crate) fn similaridade(&self, outro: &PalavrasLogradouro) -> f64 {
+        let palavras = similaridade_palavras(&self.palavras, &outro.palavras);
+        let fonetica = similaridade_palavras(&self.fonetica, &outro.fonetica);
+        (palavras + fonetica) / 2.0
+    }
+}
+
+pub(crate) fn similaridade_logradouro(a: &str, b: &str) -> f64 {
+    PalavrasLogradouro::new(a).similaridade(&PalavrasLogradouro::new(b))
+}
+
+// Faixas de números cobertas por um número de logradouro.
+fn faixas_numero(numero: &NumeroEndereco) -> Vec<(u32, u32)> {
+    match numero {
+        NumeroEndereco::Inteiro(n) | NumeroEndereco::ComSufixo { numero: n, .. } => vec![(*n, *n)],
+        NumeroEndereco::Intervalo { inicio, fim } => vec![(*inicio, *fim)],
+        NumeroEndereco::Multiplo(numeros) => numeros.iter().flat_map(faixas_numero).collect(),
+        NumeroEndereco::Quilometro(_)
+        | NumeroEndereco::SemNumero
+        | NumeroEndereco::NaoReconhecido(_) => vec![],
+    }
+}
+
+/// Calcula a similaridade entre dois números de logradouro, de 0 a 1.
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::similaridade::similaridade_numero;
+/// assert_eq!(similaridade_numero("0210", "210"), 1.0);
+/// assert_eq!(similaridade_numero("125", "120-130"), 0.9);
+/// assert_eq!(similaridade_numero("45A", "45"), 0.8);
+/// assert_eq!(similaridade_numero("45", "46"), 0.0);
+/// ```
+///
+/// # Detalhes
+/// Os números são interpretados com [separar_numero]. Números equivalentes têm similaridade 1.
+/// Um número contido em um intervalo, ou presente em ambos os valores múltiplos, tem 0,9. O
+/// mesmo número com sufixos diferentes, ou com sufixo em apenas um deles, tem 0,8. Valores não
+/// reconhecidos são comparados com [jaro_winkler], e os demais casos têm similaridade 0.
+///
+pub fn similaridade_numero(a: &str, b: &str) -> f64 {
+    let numero_a = separar_numero(a);
+    let numero_b = separar_numero(b);
+
+    if numero_a == numero_b {
+        return 1.0;
+    }
+
+    match (&numero_a, &numero_b) {
+        (NumeroEndereco::NaoReconhecido(a), NumeroEndereco::NaoReconhecido(b)) => {
+            return jaro_winkler(a, b);
+        }
+        (
+            NumeroEndereco::Inteiro(_) | NumeroEndereco::ComSufixo { .. },
+            NumeroEndereco::Inteiro(_) | NumeroEndereco::ComSufixo { .. },
+        ) if numero_a.numero() == numero_b.numero() => return 0.8,
+        _ => {}
+    }
+
+    let faixas_b = faixas_numero(&numero_b);
+    let sobrepostos = faixas_numero(&numero_a)
+        .iter()
+        .any(|(ia, fa)| faixas_b.iter().any(|(ib, fb)| ia <= fb && ib <= fa));
+
+    if sobrepostos {
+        0.9
+    } else {
+        0.0
+    }
+}
+
+/// Calcula a similaridade entre dois complementos, de 0 a 1, comparando suas unidades.
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::similaridade::similaridade_complemento;
+/// assert_eq!(similaridade_complemento("APTO 12 BL B", "BLOCO B APARTAMENTO 12"), 1.0);
+/// assert_eq!(similaridade_complemento("APTO 12 BL B", "APTO 12"), 2.0 / 3.0);
+/// assert_eq!(similaridade_complemento("APTO 12", "APTO 13"), 0.0);
+/// ```
+///
+/// # Detalhes
+/// Os complementos são separados em unidades com [separar_complemento], e a similaridade é o
+/// coeficiente de Dice entre elas: o dobro das unidades em comum dividido pelo total de unidades.
+/// Os trechos não reconhecidos de cada complemento contam como uma única unidade, comparada
+/// com [similaridade_conjunto_tokens].
+///
+pub fn similaridade_complemento(a: &str, b: &str) -> f64 {
+    let (outros_a, unidades_a): (Vec<_>, Vec<_>) = separar_complemento(a)
+        .into_iter()
+        .partition(|u| u.tipo == TipoComplemento::Outro);
+    let (outros_b, mut unidades_b): (Vec<_>, Vec<_>) = separar_complemento(b)
+        .into_iter()
+        .partition(|u| u.tipo == TipoComplemento::Outro);
+
+    let texto_a = outros_a.iter().map(|u| u.valor.as_str()).join(" ");
+    let texto_b = outros_b.iter().map(|u| u.valor.as_str()).join(" ");
+
+    let total = unidades_a.len()
+        + unidades_b.len()
+        + usize::from(!texto_a.is_empty())
+        + usize::from(!texto_b.is_empty());
+    if total == 0 {
+        return 1.0;
+    }
+
+    let mut comuns = 0.0;
+    for unidade in &unidades_a {
+        if let Some(posicao) = unidades_b.iter().position(|u| u == unidade) {
+            unidades_b.swap_remove(posicao);
+            comuns += 1.0;
+        }
+    }
+    if !texto_a.is_empty() && !texto_b.is_empty() {
+        comuns += similaridade_conjunto_tokens(&texto_a, &texto_b);
+    }
+
+    2.0 * comuns / total as f64
+}
+
+/// Compara dois endereços, com os pesos padrão de [PesosSimilaridade].
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::Endereco;
+/// use enderecobr_rs::similaridade::similaridade;
+///
+/// let a = Endereco {
+///     logradouro: Some("R. Souza Lima".to_string()),
+///     numero: Some("0210".to_string()),
+///     complemento: Some("apto 12 bl b".to_string()),
+///     localidade: Some("Copacabana".to_string()),
+/// };
+/// let b = Endereco {
+///     logradouro: Some("RUA SOUSA LIMA".to_string()),
+///     numero: Some("210".to_string()),
+///     complemento: Some("BLOCO B APARTAMENTO 12".to_string()),
+///     localidade: None,
+/// };
+///
+/// let resultado = similaridade(&a, &b);
+/// assert!(resultado.logradouro.unwrap() > 0.95);
+/// assert_eq!(resultado.numero, Some(1.0));
+/// assert_eq!(resultado.complemento, Some(1.0));
+/// assert_eq!(resultado.localidade, None);
+/// assert!(resultado.total > 0.95);
+/// ```
+pub fn similaridade(a: &Endereco, b: &Endereco) -> Similaridade {
+    similaridade_com_pesos(a, b, &PesosSimilaridade::default())
+}
+
+/// Compara dois endereços, campo a campo, e calcula uma similaridade total ponderada.
+///
+/// # Detalhes
+/// Os endereços são padronizados com [Endereco::endereco_padronizado], então podem ser
+/// informados os valores brutos. Cada campo é comparado da seguinte forma:
+/// - logradouro: média entre a [similaridade_conjunto_tokens] dos textos e a dos códigos
+///   fonéticos de cada palavra, sem preposições (vide [crate::metaphone::metaphone_tokens]);
+/// - número: [similaridade_numero];
+/// - complemento: [similaridade_complemento];
+/// - localidade: [similaridade_conjunto_tokens].
+///
+/// O total é a média ponderada dos campos presentes em ambos os endereços, com os pesos
+/// normalizados para somar 1. Se nenhum campo estiver presente em ambos, o total é 0.
+///
+pub fn similaridade_com_pesos(
+    a: &Endereco,
+    b: &Endereco,
+    pesos: &PesosSimilaridade,
+) -> Similaridade {
+    similaridade_padronizados(&a.endereco_padronizado(), &b.endereco_padronizado(), pesos)
+}
+
+// Compara endereços já padronizados, evitando padronizar o mesmo endereço a cada par comparado.
+pub(crate) fn similaridade_padronizados(
+    a: &Endereco,
+    b: &Endereco,
+    pesos: &PesosSimilaridade,
+) -> Similaridade {
+    let comparar = |x: &Option<String>, y: &Option<String>, funcao: fn(&str, &str) -> f64| {
+        x.as_deref().zip(y.as_deref()).map(|(x, y)| funcao(x, y))
+    };
+
+    let logradouro = comparar(&a.logradouro, &b.logradouro, similaridade_logradouro);
+    let numero = comparar(&a.numero, &b.numero, similaridade_numero);
+    let complemento = comparar(&a.complemento, &b.complemento, similaridade_complemento);
+    let localidade = comparar(&a.localidade, &b.localidade, similaridade_conjunto_tokens);
+
+    let (soma, soma_pesos) = [
+        (logradouro, pesos.logradouro),
+        (numero, pesos.numero),
+        (complemento, pesos.complemento),
+        (localidade, pesos.localidade),
+    ]
+    .iter()
+    .filter_map(|(valor, peso)| valor.map(|v| (v * peso, *peso)))
+    .fold((0.0, 0.0), |(s, p), (v, peso)| (s + v, p + peso));
+
+    let total = if soma_pesos > 0.0 {
+        soma / soma_pesos
+    } else {
+        0.0
+    };
+
+    Similaridade {
+        logradouro,
+        numero,
+        complemento,
+        localidade,
+        total,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calcula_jaro_winkler() {
+        assert!((jaro_winkler("MARTHA", "MARHTA") - 0.961).abs() < 0.001);
+        assert!((jaro_winkler("DIXON", "DICKSONX") - 0.813).abs() < 0.001);
+        assert_eq!(jaro_winkler("", ""), 1.0);
+        assert_eq!(jaro_winkler("A", ""), 0.0);
+    }
+
+    #[test]
+    fn compara_palavras_de_logradouros() {
+        let a = PalavrasLogradouro::new("RUA SOUZA LIMA");
+        let b = PalavrasLogradouro::new("rua sousa lima");
+        assert_eq!(a.similaridade(&a), 1.0);
+        assert_eq!(
+            a.similaridade(&b),
+            similaridade_logradouro("RUA SOUZA LIMA", "rua sousa lima")
+        );
+        assert!(a.similaridade(&b) > a.similaridade(&PalavrasLogradouro::new("RUA AUGUSTA")));
+    }
+
+    #[test]
+    fn compara_numeros() {
+        assert_eq!(similaridade_numero("S/N", "SN"), 1.0);
+        assert_eq!(similaridade_numero("181", "180 181"), 0.9);
+        assert_eq!(similaridade_numero("45A", "45B"), 0.8);
+        assert_eq!(similaridade_numero("S/N", "45"), 0.0);
+    }
+
+    #[test]
+    fn compara_complementos() {
+        assert_eq!(similaridade_complemento("", ""), 1.0);
+        assert_eq!(
+            similaridade_complemento("CASA 2 FUNDOS", "FUNDOS CASA 2"),
+            1.0
+        );
+        assert_eq!(
+            similaridade_complemento("PROXIMO AO MERCADO", "PROXIMO AO MERCADO"),
+            1.0
+        );
+        assert_eq!(similaridade_complemento("QD 1 LT 2", "QD 1 LT 3"), 0.5);
+    }
+
+    #[test]
+    fn compara_enderecos() {
+        let a = Endereco {
+            logradouro: Some("AV N SRA COPACABANA".to_string()),
+            numero: Some("100".to_string()),
+            complemento: None,
+            localidade: Some("COPACABANA".to_string()),
+        };
+        let b = Endereco {
+            logradouro: Some("AVENIDA NOSSA SENHORA DE COPACABANA".to_string()),
+            numero: Some("100".to_string()),
+            complemento: None,
+            localidade: Some("Copacabana".to_string()),
+        };
+        let resultado = similaridade(&a, &b);
+        assert!(resultado.total > 0.95, "{:?}", resultado);
+        assert_eq!(resultado.complemento, None);
+
+        let c = Endereco {
+            logradouro: Some("RUA BARATA RIBEIRO".to_string()),
+            ..a.clone()
+        };
+        let resultado = similaridade(&a, &c);
+        assert!(resultado.logradouro.unwrap_or(1.0) < 0.7, "{:?}", resultado);
+        assert!(resultado.total < 0.9, "{:?}", resultado);
+
+        let vazio = Endereco::default();
+        assert_eq!(similaridade(&a, &vazio).total, 0.0);
+    }
+
+    #[test]
+    fn aplica_pesos() {
+        let a = Endereco {
+            logradouro: Some("RUA A".to_string()),
+            numero: Some("10".to_string()),
+            ..Default::default()
+        };
+        let b = Endereco {
+            numero: Some("20".to_string()),
+            ..a.clone()
+        };
+        let pesos = PesosSimilaridade {
+            logradouro: 1.0,
+            numero: 3.0,
+            ..Default::default()
+        };
+        assert_eq!(similaridade_com_pesos(&a, &b, &pesos).total, 0.25);
+    }
+}
@@ -1,8 +1,19 @@
-use std::sync::LazyLock;
+use std::{collections::HashSet, sync::LazyLock};
 
-use crate::Padronizador;
+use itertools::Itertools;
+
+use crate::{
+    abreviacao::{abreviacoes_padrao, adicionar_abreviacoes, Abreviacao},
+    normalizar, padronizar_logradouros, Padronizador,
+};
 
 pub fn criar_padronizador_tipo_logradouro() -> Padronizador {
+    criar_padronizador_tipo_logradouro_com(&abreviacoes_padrao())
+}
+
+/// Cria o padronizador com as abreviações informadas, vide
+/// [crate::abreviacao::DicionarioAbreviacoes].
+pub(crate) fn criar_padronizador_tipo_logradouro_com(abreviacoes: &[Abreviacao]) -> Padronizador {
     let mut padronizador = Padronizador::default();
     padronizador
         // Substituição nova
@@ -31,6 +42,8 @@ pub fn criar_padronizador_tipo_logradouro() -> Padronizador {
         .adicionar(r"^\d+$", "") // tipos de logradouro não podem ser números
 
         // ordenacao de logradouros - e.g. 3A RUA, 15A TRAVESSA, 1A RODOVIA, 1O BECO, etc
+        // o tipo fica restrito ao catálogo de tipos; o ordinal é mantido no logradouro completo,
+        // vide [crate::numero_extenso::padronizar_ordinais]
         .adicionar(r"\b\d+(A|O) ?", "")
 
         // tipos de logradouro
@@ -46,29 +59,23 @@ pub fn criar_padronizador_tipo_logradouro() -> Padronizador {
         .adicionar(r"\b(PCA?|PR(A|C))\b\.?", "PRACA")
         // regexp original: \bBE?CO?\b(?<!BECO)\.?
         .adicionar_com_ignorar(r"\bBE?CO?\b\.?", "BECO", r"\bBE?CO?\bBECO\.?") // (?<!BECO) serve para remover os matches com a palavra BECO ja correta 
-        .adicionar(r"\b(T(RA?)?V|TRA)\b\.?", "TRAVESSA")
-        .adicionar(r"\bP((A?R)?Q|QU?E)\b\.?", "PARQUE")
+        .adicionar(r"\b(T(RA?)?V|TRA)\b\.?", "TRAVESSA");
+
+    // Abreviações compartilhadas com os outros padronizadores (JD, PQ, RES, CONJ, etc.)
+    adicionar_abreviacoes(&mut padronizador, abreviacoes);
+
+    padronizador
         // Regexp original: (?<!RODOVIA )\bAL(A|M)?\b\.?
         .adicionar_com_ignorar(r"\bAL(A|M)?\b\.?", "ALAMEDA", r"RODOVIA \bAL(A|M)?\b\.?") // evitando um possivel caso de RODOVIA AL ..., que faria referencia a uma rodovia estadual de alagoas
-        .adicionar(r"\bLOT\b\.?", "LOTEAMENTO")
         .adicionar(r"\bVI?L\b\.?", "VILA")
         .adicionar(r"\bLAD\b\.?", "LADEIRA")
-        .adicionar(r"\bDIS(TR?)?\b\.?", "DISTRITO")
-        .adicionar(r"\bNUC\b\.?", "NUCLEO")
         .adicionar(r"\bL(AR|RG|GO)\b\.?", "LARGO")
         .adicionar(r"\bAER(OP)?\b\.?", "AEROPORTO")
-        .adicionar(r"\bFAZ(EN?)?\b\.?", "FAZENDA")
-        .adicionar(r"\bCOND\b\.?", "CONDOMINIO")
         .adicionar(r"\bSIT\b\.?", "SITIO")
-        .adicionar(r"\bRES(ID)?\b\.?", "RESIDENCIAL")
         .adicionar(r"\bQ(U(AD?)?|D(RA?)?)\b\.?", "QUADRA")
-        .adicionar(r"\bCHAC\b\.?", "CHACARA") // CHA pode ser CHAPADAO
         .adicionar(r"\bCPO\b\.?", "CAMPO")
         .adicionar(r"\bCOL\b\.?", "COLONIA")
-        .adicionar(r"\bC(ONJ|J)\b\.?", "CONJUNTO")
-        .adicionar(r"\bJ(D(I?M)?|A?RD|AR(DIN)?)\b\.?", "JARDIM")
         .adicionar(r"\bFAV\b\.?", "FAVELA")
-        .adicionar(r"\bNUC\b\.?", "NUCLEO")
         .adicionar(r"\bVIE\b\.?", "VIELA")
         .adicionar(r"\bSET\b\.?", "SETOR")
         .adicionar(r"\bILH\b\.?", "ILHA")
@@ -126,6 +133,113 @@ pub fn criar_padronizador_tipo_logradouro() -> Padronizador {
 // quando uso `const`. Nesse caso,  como tenho uma construção complexa da struct `Padronizador`,
 // tenho que usar static com inicialização Lazy (o LazyLock aqui previne condições de corrida).
 static PADRONIZADOR: LazyLock<Padronizador> = LazyLock::new(criar_padronizador_tipo_logradouro);
+static TIPOS_LOGRADOURO: LazyLock<HashSet<&'static str>> = LazyLock::new(criar_tipos_logradouro);
+
+/// Cria o conjunto de tipos de logradouro reconhecidos, a partir das expansões das regras
+/// do padronizador de tipos de logradouro.
+pub fn criar_tipos_logradouro() -> HashSet<&'static str> {
+    PADRONIZADOR
+        .obter_pares()
+        .into_iter()
+        .map(|(_, substituicao, _)| substituicao)
+        .filter(|s| !s.is_empty() && s.chars().all(|c| c.is_ascii_uppercase() || c == '-'))
+        .collect()
+}
+
+// ====== Catálogo de tipos de logradouro =======
+
+/// Representa um tipo de logradouro do catálogo oficial, com o código (abreviatura) usado pelos
+/// Correios no Diretório Nacional de Endereços (DNE). O catálogo não inclui códigos do IBGE.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TipoLogradouro {
+    pub codigo: &'static str,
+    pub nome: &'static str,
+}
+
+// FIXME: O pedido original também previa os códigos do IBGE (CNEFE). Eles ainda não foram
+// incluídos por falta de uma fonte oficial conferida; até lá, o catálogo só tem os códigos do DNE
+// e a ausência precisa ser aceita por quem pediu antes de considerar o catálogo completo.
+static CATALOGO: LazyLock<Vec<TipoLogradouro>> = LazyLock::new(criar_catalogo);
+
+pub fn criar_catalogo() -> Vec<TipoLogradouro> {
+    let tipos_csv: &'static str = include_str!("data/tipos_logradouro.csv");
+    tipos_csv
+        .lines()
+        .skip(1)
+        .filter_map(|linha| {
+            let (codigo, nome) = linha.split_once(",")?;
+            Some(TipoLogradouro { codigo, nome })
+        })
+        .collect()
+}
+
+/// Lista os tipos de logradouro do catálogo embutido, em ordem alfabética de código.
+pub fn catalogo_tipos_logradouro() -> &'static [TipoLogradouro] {
+    &CATALOGO
+}
+
+/// Busca um tipo de logradouro no catálogo, a partir do seu nome, de uma abreviação ou do seu
+/// código.
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::tipo_logradouro::buscar_tipo_logradouro;
+/// let tipo = buscar_tipo_logradouro("Av.").unwrap();
+/// assert_eq!(tipo.nome, "AVENIDA");
+/// assert_eq!(tipo.codigo, "AV");
+/// assert_eq!(buscar_tipo_logradouro("PRL").map(|t| t.nome), Some("PROLONGAMENTO"));
+/// assert_eq!(buscar_tipo_logradouro("XYZ"), None);
+/// ```
+///
+/// # Detalhes
+/// O valor é padronizado com [padronizar_tipo_logradouro] e comparado com os nomes do catálogo.
+/// Caso não corresponda a nenhum nome, é comparado com os códigos.
+///
+pub fn buscar_tipo_logradouro(valor: &str) -> Option<TipoLogradouro> {
+    let padronizado = padronizar_tipo_logradouro(valor);
+    CATALOGO
+        .iter()
+        .find(|t| t.nome == padronizado)
+        .or_else(|| {
+            let valor = normalizar(valor);
+            let valor = valor.trim_end_matches('.');
+            CATALOGO.iter().find(|t| t.codigo == valor)
+        })
+        .copied()
+}
+
+/// Verifica se um valor é um tipo de logradouro do catálogo, escrito por extenso.
+///
+/// Útil para restringir colunas de saída a um vocabulário controlado: valores que não foram
+/// expandidos pela padronização (como "XPTO") ou abreviações (como "AV") não são válidos.
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::tipo_logradouro::validar_tipo_logradouro;
+/// assert!(validar_tipo_logradouro("AVENIDA"));
+/// assert!(validar_tipo_logradouro("Chapadão"));
+/// assert!(!validar_tipo_logradouro("AV"));
+/// assert!(!validar_tipo_logradouro("XPTO"));
+/// ```
+///
+pub fn validar_tipo_logradouro(valor: &str) -> bool {
+    let valor = normalizar(valor);
+    CATALOGO.iter().any(|t| t.nome == valor)
+}
+
+/// Obtém o código de um tipo de logradouro do catálogo. Veja [buscar_tipo_logradouro].
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::tipo_logradouro::codigo_tipo_logradouro;
+/// assert_eq!(codigo_tipo_logradouro("RUA"), Some("R"));
+/// assert_eq!(codigo_tipo_logradouro("TRAV."), Some("TV"));
+/// assert_eq!(codigo_tipo_logradouro("XPTO"), None);
+/// ```
+///
+pub fn codigo_tipo_logradouro(valor: &str) -> Option<&'static str> {
+    buscar_tipo_logradouro(valor).map(|t| t.codigo)
+}
 
 /// Padroniza uma string representando complementos de logradouros.
 ///
@@ -155,6 +269,79 @@ pub fn padronizar_tipo_logradouro(valor: &str) -> String {
     padronizador.padronizar(valor)
 }
 
+/// Separa o tipo de um logradouro completo do restante do seu nome.
+///
+/// Retorna o tipo padronizado, caso a primeira palavra do logradouro seja um tipo de logradouro
+/// reconhecido, e o restante do logradouro padronizado com [padronizar_logradouros].
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::separar_tipo_logradouro;
+/// assert_eq!(
+///     separar_tipo_logradouro("AV. PAULISTA"),
+///     (Some("AVENIDA".to_string()), "PAULISTA".to_string())
+/// );
+/// assert_eq!(
+///     separar_tipo_logradouro("r.15 de novembro"),
+///     (Some("RUA".to_string()), "15 DE NOVEMBRO".to_string())
+/// );
+/// assert_eq!(
+///     separar_tipo_logradouro("SAO JOAO"),
+///     (None, "SAO JOAO".to_string())
+/// );
+/// ```
+///
+/// # Detalhes
+/// O logradouro é padronizado antes da separação, e a sua primeira palavra é considerada o tipo
+/// do logradouro se, após padronizada com [padronizar_tipo_logradouro], corresponder a um dos
+/// tipos expandidos pelas regras desse padronizador. Tipos que também são usados como nomes,
+/// como "VILA", são sempre separados quando aparecem na primeira posição.
+///
+pub fn separar_tipo_logradouro(valor: &str) -> (Option<String>, String) {
+    let padronizado = padronizar_logradouros(valor);
+    let (primeira, resto) = padronizado
+        .split_once(' ')
+        .unwrap_or((padronizado.as_str(), ""));
+
+    let tipo = padronizar_tipo_logradouro(primeira);
+    if TIPOS_LOGRADOURO.contains(tipo.as_str()) {
+        (Some(tipo), resto.to_string())
+    } else {
+        (None, padronizado)
+    }
+}
+
+/// Junta um tipo de logradouro e o nome do logradouro, operação inversa de
+/// [separar_tipo_logradouro].
+///
+/// O tipo não é repetido quando o nome já começa com ele, o que é comum em bases de dados
+/// com colunas separadas de tipo e nome.
+///
+/// # Exemplo
+/// ```
+/// use enderecobr_rs::tipo_logradouro::juntar_tipo_logradouro;
+/// assert_eq!(juntar_tipo_logradouro(Some("R"), "AZUL"), "RUA AZUL");
+/// assert_eq!(juntar_tipo_logradouro(Some("RUA"), "RUA AZUL"), "RUA AZUL");
+/// assert_eq!(juntar_tipo_logradouro(Some("RUA"), "R. AZUL"), "RUA AZUL");
+/// assert_eq!(juntar_tipo_logradouro(None, "AV PAULISTA"), "AVENIDA PAULISTA");
+/// ```
+///
+pub fn juntar_tipo_logradouro(tipo: Option<&str>, nome: &str) -> String {
+    let tipo = tipo.map(padronizar_tipo_logradouro).unwrap_or_default();
+    let (tipo_nome, resto) = separar_tipo_logradouro(nome);
+
+    let tipo = match tipo_nome {
+        Some(tipo_nome) if tipo.is_empty() || tipo_nome == tipo => tipo_nome,
+        Some(tipo_nome) => format!("{} {}", tipo, tipo_nome),
+        None => tipo,
+    };
+
+    [tipo.as_str(), resto.as_str()]
+        .iter()
+        .filter(|x| !x.is_empty())
+        .join(" ")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -420,4 +607,79 @@ mod tests {
             assert_eq!(&padronizar_tipo_logradouro(input), expected);
         }
     }
+
+    #[test]
+    fn separa_tipo_logradouro() {
+        let casos = [
+            ("AV. PAULISTA", Some("AVENIDA"), "PAULISTA"),
+            ("AV PAULISTA", Some("AVENIDA"), "PAULISTA"),
+            ("avenida paulista", Some("AVENIDA"), "PAULISTA"),
+            ("TV. DOS ANJOS", Some("TRAVESSA"), "DOS ANJOS"),
+            ("PCA DA SE", Some("PRACA"), "DA SE"),
+            ("ROD BR 101", Some("RODOVIA"), "BR-101"),
+            ("RUA", Some("RUA"), ""),
+            ("SAO PAULO", None, "SAO PAULO"),
+            ("", None, ""),
+        ];
+
+        for (entrada, tipo, nome) in casos {
+            assert_eq!(
+                separar_tipo_logradouro(entrada),
+                (tipo.map(str::to_string), nome.to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn junta_tipo_logradouro() {
+        let casos = [
+            (Some("RUA"), "AZUL", "RUA AZUL"),
+            (Some("RUA"), "RUA AZUL", "RUA AZUL"),
+            (Some("R."), "RUA AZUL", "RUA AZUL"),
+            (Some("AV"), "PAULISTA", "AVENIDA PAULISTA"),
+            (Some("TRAVESSA"), "RUA AZUL", "TRAVESSA RUA AZUL"),
+            (Some("RUA"), "", "RUA"),
+            (Some(""), "RUA AZUL", "RUA AZUL"),
+            (None, "AZUL", "AZUL"),
+        ];
+
+        for (tipo, nome, esperado) in casos {
+            assert_eq!(juntar_tipo_logradouro(tipo, nome), esperado);
+        }
+    }
+
+    #[test]
+    fn catalogo_cobre_padronizacao() {
+        // Todas as expansões do padronizador devem fazer parte do vocabulário controlado.
+        for tipo in TIPOS_LOGRADOURO.iter() {
+            assert!(validar_tipo_logradouro(tipo), "{tipo} fora do catálogo");
+        }
+
+        let catalogo = catalogo_tipos_logradouro();
+        assert!(catalogo.iter().map(|t| t.codigo).all_unique());
+        assert!(catalogo.iter().map(|t| t.nome).all_unique());
+        assert!(catalogo
+            .iter()
+            .tuple_windows()
+            .all(|(a, b)| a.codigo < b.codigo));
+    }
+
+    #[test]
+    fn busca_no_catalogo() {
+        let casos = [
+            ("R", Some("R")),
+            ("rua", Some("R")),
+            ("AVE", Some("AV")),
+            ("QDRA", Some("Q")),
+            ("ZIG", Some("ZIG")),
+            ("CHAPADAO", Some("CHA")),
+            ("ETN", Some("ETN")),
+            ("", None),
+            ("OUTROS", None),
+        ];
+
+        for (entrada, esperado) in casos {
+            assert_eq!(codigo_tipo_logradouro(entrada), esperado);
+        }
+    }
 }